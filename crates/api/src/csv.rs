@@ -0,0 +1,140 @@
+use axum::http::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
+use axum::response::{IntoResponse, Response};
+
+pub const CSV_CONTENT_TYPE: &str = "text/csv; charset=utf-8";
+
+pub fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+pub fn csv_row<S: AsRef<str>>(fields: &[S]) -> String {
+    let mut row = fields
+        .iter()
+        .map(|field| csv_field(field.as_ref()))
+        .collect::<Vec<_>>()
+        .join(",");
+    row.push_str("\r\n");
+    row
+}
+
+pub fn csv_response(filename: &str, body: String) -> Response {
+    (
+        [
+            (CONTENT_TYPE, CSV_CONTENT_TYPE.to_string()),
+            (
+                CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{filename}\""),
+            ),
+        ],
+        body,
+    )
+        .into_response()
+}
+
+pub struct CsvRecord {
+    pub line: usize,
+    pub fields: Vec<String>,
+}
+
+// RFC 4180 with either line ending. Blank lines are skipped; an unterminated
+// quote returns the line the broken record started on.
+pub fn parse_csv(text: &str) -> Result<Vec<CsvRecord>, usize> {
+    let mut records = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut line = 1;
+    let mut start = 1;
+    let mut chars = text.trim_start_matches('\u{feff}').chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if quoted {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => quoted = false,
+                _ => {
+                    if c == '\n' {
+                        line += 1;
+                    }
+                    field.push(c);
+                }
+            }
+            continue;
+        }
+        match c {
+            '"' if field.is_empty() => quoted = true,
+            ',' => fields.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\r' | '\n' => {
+                fields.push(std::mem::take(&mut field));
+                if fields.len() > 1 || !fields[0].is_empty() {
+                    records.push(CsvRecord {
+                        line: start,
+                        fields: std::mem::take(&mut fields),
+                    });
+                }
+                fields.clear();
+                line += 1;
+                start = line;
+            }
+            _ => field.push(c),
+        }
+    }
+    if quoted {
+        return Err(start);
+    }
+    if !fields.is_empty() || !field.is_empty() {
+        fields.push(field);
+        records.push(CsvRecord {
+            line: start,
+            fields,
+        });
+    }
+
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{csv_row, parse_csv};
+
+    #[test]
+    fn quotes_fields_that_need_it() {
+        assert_eq!(
+            csv_row(&["plain", "a,b", "say \"hi\""]),
+            "plain,\"a,b\",\"say \"\"hi\"\"\"\r\n"
+        );
+    }
+
+    #[test]
+    fn parses_quoted_fields_across_lines() {
+        let records = parse_csv("content,labels\r\n\"say \"\"hi\"\"\",\"a,b\"\n\n\"two\nlines\",x")
+            .unwrap_or_else(|line| panic!("unterminated quote on line {line}"));
+
+        let rows: Vec<(usize, Vec<&str>)> = records
+            .iter()
+            .map(|record| {
+                (
+                    record.line,
+                    record.fields.iter().map(String::as_str).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                (1, vec!["content", "labels"]),
+                (2, vec!["say \"hi\"", "a,b"]),
+                (4, vec!["two\nlines", "x"]),
+            ]
+        );
+        assert!(matches!(parse_csv("a\n\"open"), Err(2)));
+    }
+}
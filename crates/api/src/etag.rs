@@ -0,0 +1,94 @@
+use axum::http::header::{ETAG, IF_MATCH, IF_NONE_MATCH};
+use axum::http::{HeaderMap, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use domain::error::AppError;
+
+use crate::response::{from_app_error, ErrorResponse};
+
+pub fn version_etag(version: i64) -> String {
+    format!("\"{version}\"")
+}
+
+pub fn timestamp_etag(at: DateTime<Utc>) -> String {
+    version_etag(at.timestamp_micros())
+}
+
+// Changes whenever a member of the collection is added, removed or updated.
+pub fn collection_etag(count: usize, latest: Option<DateTime<Utc>>) -> String {
+    format!(
+        "\"{count}-{}\"",
+        latest.map(|at| at.timestamp_micros()).unwrap_or_default()
+    )
+}
+
+pub fn timestamp_from_token(token: i64) -> Result<DateTime<Utc>, ErrorResponse> {
+    DateTime::from_timestamp_micros(token).ok_or_else(precondition_failed)
+}
+
+pub fn if_match(headers: &HeaderMap) -> Result<Option<i64>, ErrorResponse> {
+    let Some(value) = headers.get(IF_MATCH) else {
+        return Ok(None);
+    };
+    let value = value.to_str().map_err(|_| precondition_failed())?.trim();
+    if value == "*" {
+        return Ok(None);
+    }
+
+    value
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+        .and_then(|token| token.parse::<i64>().ok())
+        .map(Some)
+        .ok_or_else(precondition_failed)
+}
+
+pub fn if_none_match(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get(IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| {
+            value
+                .split(',')
+                .map(str::trim)
+                .any(|candidate| candidate == "*" || candidate.trim_start_matches("W/") == etag)
+        })
+        .unwrap_or(false)
+}
+
+pub fn with_etag(etag: &str, body: impl IntoResponse) -> Response {
+    let mut response = body.into_response();
+    if let Ok(value) = HeaderValue::from_str(etag) {
+        response.headers_mut().insert(ETAG, value);
+    }
+    response
+}
+
+pub fn not_modified(etag: &str) -> Response {
+    with_etag(etag, StatusCode::NOT_MODIFIED)
+}
+
+pub fn precondition_failed() -> ErrorResponse {
+    ErrorResponse::new(
+        StatusCode::PRECONDITION_FAILED,
+        "precondition_failed",
+        "If-Match does not match the current version",
+    )
+}
+
+pub fn precondition_required() -> ErrorResponse {
+    ErrorResponse::new(
+        StatusCode::PRECONDITION_REQUIRED,
+        "precondition_required",
+        "Provide If-Match or a version in the request body",
+    )
+}
+
+pub fn from_conditional_error(error: AppError, used_if_match: bool) -> ErrorResponse {
+    match error {
+        AppError::VersionConflict {
+            current_version, ..
+        } if used_if_match => precondition_failed().with_current_version(current_version),
+        error => from_app_error(error),
+    }
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use application::task_service;
+use application::task_service::repository::TaskRepository;
+use domain::task::inputs::ArchiveCompletedTasksInput;
+
+const DEFAULT_ARCHIVE_AFTER_DAYS: i64 = 30;
+const DEFAULT_INTERVAL_SECONDS: u64 = 3600;
+const BATCH_SIZE: i64 = 500;
+
+#[derive(Debug, Clone)]
+pub struct ArchiveConfig {
+    pub archive_after_days: i64,
+    pub interval: Duration,
+}
+
+impl ArchiveConfig {
+    pub fn from_env() -> Self {
+        Self {
+            archive_after_days: std::env::var("ARCHIVE_AFTER_DAYS")
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .filter(|days| *days > 0)
+                .unwrap_or(DEFAULT_ARCHIVE_AFTER_DAYS),
+            interval: Duration::from_secs(
+                std::env::var("ARCHIVE_INTERVAL_SECONDS")
+                    .ok()
+                    .and_then(|value| value.parse().ok())
+                    .filter(|seconds| *seconds > 0)
+                    .unwrap_or(DEFAULT_INTERVAL_SECONDS),
+            ),
+        }
+    }
+}
+
+pub async fn run(task_repo: Arc<dyn TaskRepository>, config: ArchiveConfig) {
+    let mut interval = tokio::time::interval(config.interval);
+    loop {
+        interval.tick().await;
+
+        let completed_before =
+            chrono::Utc::now() - chrono::Duration::days(config.archive_after_days);
+        match task_service::archive_completed_tasks::archive_completed_tasks(
+            task_repo.as_ref(),
+            ArchiveCompletedTasksInput {
+                completed_before,
+                batch_size: BATCH_SIZE,
+            },
+        )
+        .await
+        {
+            Ok(archived) => tracing::info!(archived, "Archived completed tasks"),
+            Err(error) => tracing::error!(error = %error, "Failed to archive completed tasks"),
+        }
+    }
+}
@@ -1,14 +1,31 @@
 use axum::{routing::get, Router};
 use std::sync::Arc;
 
+use application::board_service::repository::BoardRepository;
+use application::feed_service::repository::FeedRepository;
+use application::share_service::repository::ShareRepository;
 use application::task_service::repository::TaskRepository;
+use application::template_service::repository::TemplateRepository;
+use application::time_entry_service::repository::TimeEntryRepository;
 use application::user_service::repository::UserRepository;
+use application::view_service::repository::ViewRepository;
+use domain::task::validation::DEFAULT_MAX_CONTENT_CHARS;
+use infrastructure::board_repo::repository::BoardRepositoryImpl;
 use infrastructure::db::{build_pool, DbConfig};
+use infrastructure::feed_repo::repository::FeedRepositoryImpl;
+use infrastructure::share_repo::repository::ShareRepositoryImpl;
 use infrastructure::task_repo::repository::TaskRepositoryImpl;
+use infrastructure::template_repo::repository::TemplateRepositoryImpl;
+use infrastructure::time_entry_repo::repository::TimeEntryRepositoryImpl;
 use infrastructure::user_repo::repository::UserRepositoryImpl;
+use infrastructure::view_repo::repository::ViewRepositoryImpl;
 use tracing_subscriber::{fmt, EnvFilter};
 
+mod csv;
+mod etag;
+mod jobs;
 mod middleware;
+mod patch;
 mod response;
 mod routes;
 
@@ -16,6 +33,13 @@ mod routes;
 pub struct AppState {
     pub task_repo: Arc<dyn TaskRepository>,
     pub user_repo: Arc<dyn UserRepository>,
+    pub board_repo: Arc<dyn BoardRepository>,
+    pub time_entry_repo: Arc<dyn TimeEntryRepository>,
+    pub template_repo: Arc<dyn TemplateRepository>,
+    pub view_repo: Arc<dyn ViewRepository>,
+    pub feed_repo: Arc<dyn FeedRepository>,
+    pub share_repo: Arc<dyn ShareRepository>,
+    pub max_content_chars: usize,
 }
 
 #[tokio::main]
@@ -29,9 +53,21 @@ async fn main() {
 
     let state = AppState {
         task_repo: Arc::new(TaskRepositoryImpl::new(pool.clone())),
-        user_repo: Arc::new(UserRepositoryImpl::new(pool)),
+        user_repo: Arc::new(UserRepositoryImpl::new(pool.clone())),
+        board_repo: Arc::new(BoardRepositoryImpl::new(pool.clone())),
+        time_entry_repo: Arc::new(TimeEntryRepositoryImpl::new(pool.clone())),
+        template_repo: Arc::new(TemplateRepositoryImpl::new(pool.clone())),
+        view_repo: Arc::new(ViewRepositoryImpl::new(pool.clone())),
+        feed_repo: Arc::new(FeedRepositoryImpl::new(pool.clone())),
+        share_repo: Arc::new(ShareRepositoryImpl::new(pool)),
+        max_content_chars: max_content_chars(),
     };
 
+    tokio::spawn(jobs::archive_completed::run(
+        state.task_repo.clone(),
+        jobs::archive_completed::ArchiveConfig::from_env(),
+    ));
+
     let app = Router::new()
         .route("/health", get(|| async { "OK" }))
         .nest("/v1", routes::router::router(state))
@@ -50,6 +86,14 @@ async fn main() {
     axum::serve(listener, app).await.expect("Server failed");
 }
 
+fn max_content_chars() -> usize {
+    std::env::var("TASK_CONTENT_MAX_CHARS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .filter(|value| *value > 0)
+        .unwrap_or(DEFAULT_MAX_CONTENT_CHARS)
+}
+
 fn init_tracing() {
     let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
     fmt().with_env_filter(filter).json().init();
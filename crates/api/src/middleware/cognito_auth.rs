@@ -18,6 +18,15 @@ pub struct AuthUser {
     pub groups: Vec<String>,
 }
 
+// Members of this Cognito group may act on other users' tasks.
+pub const ADMIN_GROUP: &str = "admin";
+
+impl AuthUser {
+    pub fn is_admin(&self) -> bool {
+        self.groups.iter().any(|group| group == ADMIN_GROUP)
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 struct JwkSet {
     keys: Vec<Jwk>,
@@ -0,0 +1,33 @@
+use axum::http::header::CONTENT_TYPE;
+use axum::http::{HeaderMap, StatusCode};
+use domain::patch::PatchFormat;
+use serde_json::Value;
+
+use crate::response::{validation_error, ErrorResponse};
+
+pub const MERGE_PATCH_CONTENT_TYPE: &str = "application/merge-patch+json";
+pub const JSON_PATCH_CONTENT_TYPE: &str = "application/json-patch+json";
+
+pub fn patch_format(headers: &HeaderMap) -> Result<PatchFormat, ErrorResponse> {
+    let content_type = headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .map(|value| value.trim().to_ascii_lowercase())
+        .unwrap_or_default();
+
+    match content_type.as_str() {
+        MERGE_PATCH_CONTENT_TYPE => Ok(PatchFormat::MergePatch),
+        JSON_PATCH_CONTENT_TYPE => Ok(PatchFormat::JsonPatch),
+        _ => Err(ErrorResponse::new(
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            "unsupported_media_type",
+            "Content-Type must be application/merge-patch+json or application/json-patch+json",
+        )),
+    }
+}
+
+pub fn parse_patch(body: &[u8]) -> Result<Value, ErrorResponse> {
+    serde_json::from_slice(body)
+        .map_err(|_| validation_error("invalid_patch", "Patch body must be valid JSON"))
+}
@@ -15,6 +15,8 @@ pub struct ErrorDetail {
     pub code: String,
     pub message: String,
     pub field: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_version: Option<i64>,
 }
 
 pub struct ErrorResponse {
@@ -32,6 +34,7 @@ impl ErrorResponse {
                     code: code.into(),
                     message: message.into(),
                     field: None,
+                    current_version: None,
                 },
             },
         }
@@ -51,10 +54,20 @@ impl ErrorResponse {
                     code: code.into(),
                     message: message.into(),
                     field,
+                    current_version: None,
                 },
             },
         }
     }
+
+    pub fn with_current_version(mut self, current_version: i64) -> Self {
+        self.body.error.current_version = Some(current_version);
+        self
+    }
+
+    pub fn into_detail(self) -> ErrorDetail {
+        self.body.error
+    }
 }
 
 impl IntoResponse for ErrorResponse {
@@ -78,6 +91,15 @@ pub fn from_app_error(error: AppError) -> ErrorResponse {
         AppError::Conflict { resource, message } => {
             ErrorResponse::new(StatusCode::CONFLICT, resource, message)
         }
+        AppError::TooManyRequests { resource, message } => {
+            ErrorResponse::new(StatusCode::TOO_MANY_REQUESTS, resource, message)
+        }
+        AppError::VersionConflict {
+            message,
+            current_version,
+            ..
+        } => ErrorResponse::new(StatusCode::CONFLICT, "version_conflict", message)
+            .with_current_version(current_version),
         AppError::Domain { domain, message } => {
             ErrorResponse::new(StatusCode::INTERNAL_SERVER_ERROR, domain, message)
         }
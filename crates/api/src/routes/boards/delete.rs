@@ -0,0 +1,69 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+use application::board_service;
+use domain::board::inputs::DeleteBoardInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(board_id): Path<String>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    board_service::delete_board::delete_board(
+        state.board_repo.as_ref(),
+        DeleteBoardInput {
+            user_id: user.user_id,
+            board_id,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::test_support::{assert_status, auth_user, board_state, MockBoardRepo};
+
+    #[tokio::test]
+    async fn returns_no_content_on_success() {
+        let state = board_state(MockBoardRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("board-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_when_missing() {
+        let state = board_state(MockBoardRepo::with_delete_result(Err(AppError::not_found(
+            "Board",
+            "Board not found",
+        ))));
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("board-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+}
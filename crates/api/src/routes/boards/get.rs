@@ -0,0 +1,83 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::board_service;
+use domain::board::inputs::GetBoardInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::boards::types::BoardViewResponse;
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(board_id): Path<String>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let view = board_service::get_board::get_board(
+        state.board_repo.as_ref(),
+        GetBoardInput {
+            user_id: user.user_id,
+            board_id,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(Json(BoardViewResponse::from(view)))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::test_support::{assert_status, auth_user, board_state, MockBoardRepo};
+
+    #[tokio::test]
+    async fn returns_ok_on_success() {
+        let state = board_state(MockBoardRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("board-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_when_missing() {
+        let state = board_state(MockBoardRepo::with_get_result(Ok(None)));
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("board-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn returns_internal_server_error_on_repo_failure() {
+        let state = board_state(MockBoardRepo::with_list_tasks_result(Err(
+            AppError::database("db error"),
+        )));
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("board-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
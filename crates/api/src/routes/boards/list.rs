@@ -0,0 +1,59 @@
+use axum::extract::State;
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::board_service;
+use domain::board::inputs::ListBoardsInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::boards::types::{BoardListResponse, BoardResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let boards = board_service::list_boards::list_boards(
+        state.board_repo.as_ref(),
+        ListBoardsInput {
+            user_id: user.user_id,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(Json(BoardListResponse {
+        boards: boards.into_iter().map(BoardResponse::from).collect(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, State};
+    use axum::http::StatusCode;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::test_support::{assert_status, auth_user, board_state, MockBoardRepo};
+
+    #[tokio::test]
+    async fn returns_ok_on_success() {
+        let state = board_state(MockBoardRepo::default());
+
+        let result = handler(State(state), Extension(auth_user())).await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_internal_server_error_on_repo_failure() {
+        let state = board_state(MockBoardRepo::with_list_result(Err(AppError::database(
+            "db error",
+        ))));
+
+        let result = handler(State(state), Extension(auth_user())).await;
+
+        assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,7 @@
+pub mod delete;
+pub mod get;
+pub mod list;
+pub mod move_card;
+pub mod post;
+pub mod put;
+pub mod types;
@@ -0,0 +1,143 @@
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::board_service;
+use domain::board::inputs::MoveCardInput;
+
+use crate::etag::{
+    from_conditional_error, if_match, precondition_failed, precondition_required, version_etag,
+    with_etag,
+};
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::ErrorResponse;
+use crate::routes::boards::types::MoveCardRequest;
+use crate::routes::tasks::types::TaskResponse;
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path((board_id, task_id)): Path<(String, String)>,
+    headers: HeaderMap,
+    Json(body): Json<MoveCardRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let if_match = if_match(&headers)?;
+    let version = match (if_match, body.version) {
+        (Some(token), _) => i32::try_from(token).map_err(|_| precondition_failed())?,
+        (None, Some(version)) => version,
+        (None, None) => return Err(precondition_required()),
+    };
+
+    let task = board_service::move_card::move_card(
+        state.board_repo.as_ref(),
+        MoveCardInput {
+            user_id: user.user_id,
+            board_id,
+            task_id,
+            column_id: body.column_id,
+            version,
+            before: body.before,
+            after: body.after,
+        },
+    )
+    .await
+    .map_err(|error| from_conditional_error(error, if_match.is_some()))?;
+
+    let etag = version_etag(i64::from(task.version));
+    Ok(with_etag(&etag, Json(TaskResponse::from(task))))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::Json;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::boards::types::MoveCardRequest;
+    use crate::routes::test_support::{assert_status, auth_user, board_state, MockBoardRepo};
+
+    fn body(version: Option<i32>) -> MoveCardRequest {
+        MoveCardRequest {
+            column_id: "column-2".to_string(),
+            version,
+            before: None,
+            after: None,
+        }
+    }
+
+    fn path() -> Path<(String, String)> {
+        Path(("board-1".to_string(), "task-1".to_string()))
+    }
+
+    #[tokio::test]
+    async fn returns_ok_on_success() {
+        let state = board_state(MockBoardRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            path(),
+            HeaderMap::new(),
+            Json(body(Some(0))),
+        )
+        .await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_precondition_required_without_version() {
+        let state = board_state(MockBoardRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            path(),
+            HeaderMap::new(),
+            Json(body(None)),
+        )
+        .await;
+
+        assert_status(result, StatusCode::PRECONDITION_REQUIRED);
+    }
+
+    #[tokio::test]
+    async fn returns_conflict_when_wip_limit_reached() {
+        let state = board_state(MockBoardRepo::with_move_card_result(Err(
+            AppError::conflict("BoardColumn", "Column Doing is at its WIP limit of 3"),
+        )));
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            path(),
+            HeaderMap::new(),
+            Json(body(Some(0))),
+        )
+        .await;
+
+        assert_status(result, StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_when_moved_relative_to_itself() {
+        let state = board_state(MockBoardRepo::default());
+        let mut request = body(Some(0));
+        request.before = Some("task-1".to_string());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            path(),
+            HeaderMap::new(),
+            Json(request),
+        )
+        .await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,111 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::board_service;
+use domain::board::inputs::CreateBoardInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::boards::types::{parse_columns, BoardRequest, BoardResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Json(body): Json<BoardRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let columns = parse_columns(body.columns)?;
+
+    let board = board_service::create_board::create_board(
+        state.board_repo.as_ref(),
+        CreateBoardInput {
+            user_id: user.user_id,
+            name: body.name,
+            columns,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok((StatusCode::CREATED, Json(BoardResponse::from(board))))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, State};
+    use axum::http::StatusCode;
+    use axum::Json;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::boards::types::{BoardColumnRequest, BoardRequest};
+    use crate::routes::test_support::{assert_status, auth_user, board_state, MockBoardRepo};
+
+    fn body(status: &str) -> BoardRequest {
+        BoardRequest {
+            name: "Sprint".to_string(),
+            columns: vec![
+                BoardColumnRequest {
+                    name: "Todo".to_string(),
+                    status: "PENDING".to_string(),
+                    wip_limit: None,
+                },
+                BoardColumnRequest {
+                    name: "Doing".to_string(),
+                    status: status.to_string(),
+                    wip_limit: Some(3),
+                },
+            ],
+        }
+    }
+
+    #[tokio::test]
+    async fn returns_created_on_success() {
+        let state = board_state(MockBoardRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Json(body("IN_PROGRESS")),
+        )
+        .await;
+
+        assert_status(result, StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_for_duplicate_status() {
+        let state = board_state(MockBoardRepo::default());
+
+        let result = handler(State(state), Extension(auth_user()), Json(body("PENDING"))).await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_for_unknown_status() {
+        let state = board_state(MockBoardRepo::default());
+
+        let result = handler(State(state), Extension(auth_user()), Json(body("BLOCKED"))).await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn returns_internal_server_error_on_repo_failure() {
+        let state = board_state(MockBoardRepo::with_create_result(Err(AppError::database(
+            "db error",
+        ))));
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Json(body("IN_PROGRESS")),
+        )
+        .await;
+
+        assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,90 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::board_service;
+use domain::board::inputs::UpdateBoardInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::boards::types::{parse_columns, BoardRequest, BoardResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(board_id): Path<String>,
+    Json(body): Json<BoardRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let columns = parse_columns(body.columns)?;
+
+    let board = board_service::update_board::update_board(
+        state.board_repo.as_ref(),
+        UpdateBoardInput {
+            user_id: user.user_id,
+            board_id,
+            name: body.name,
+            columns,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(Json(BoardResponse::from(board)))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use axum::Json;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::boards::types::{BoardColumnRequest, BoardRequest};
+    use crate::routes::test_support::{assert_status, auth_user, board_state, MockBoardRepo};
+
+    fn body() -> BoardRequest {
+        BoardRequest {
+            name: "Sprint".to_string(),
+            columns: vec![BoardColumnRequest {
+                name: "Todo".to_string(),
+                status: "PENDING".to_string(),
+                wip_limit: None,
+            }],
+        }
+    }
+
+    #[tokio::test]
+    async fn returns_ok_on_success() {
+        let state = board_state(MockBoardRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("board-1".to_string()),
+            Json(body()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_when_missing() {
+        let state = board_state(MockBoardRepo::with_update_result(Err(AppError::not_found(
+            "Board",
+            "Board not found",
+        ))));
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("board-1".to_string()),
+            Json(body()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+}
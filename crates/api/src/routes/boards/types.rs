@@ -0,0 +1,127 @@
+use serde::{Deserialize, Serialize};
+
+use domain::board::entity::{Board, BoardColumn, BoardView};
+use domain::board::inputs::BoardColumnInput;
+
+use crate::response::ErrorResponse;
+use crate::routes::tasks::types::{parse_status, TaskResponse};
+
+#[derive(Debug, Deserialize)]
+pub struct BoardColumnRequest {
+    pub name: String,
+    pub status: String,
+    pub wip_limit: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BoardRequest {
+    pub name: String,
+    pub columns: Vec<BoardColumnRequest>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MoveCardRequest {
+    pub column_id: String,
+    pub version: Option<i32>,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+pub fn parse_columns(
+    columns: Vec<BoardColumnRequest>,
+) -> Result<Vec<BoardColumnInput>, ErrorResponse> {
+    columns
+        .into_iter()
+        .map(|column| {
+            Ok(BoardColumnInput {
+                name: column.name,
+                status: parse_status(&column.status)?,
+                wip_limit: column.wip_limit,
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug, Serialize)]
+pub struct BoardColumnResponse {
+    pub column_id: String,
+    pub name: String,
+    pub status: String,
+    pub position: i32,
+    pub wip_limit: Option<i32>,
+}
+
+impl From<BoardColumn> for BoardColumnResponse {
+    fn from(column: BoardColumn) -> Self {
+        Self {
+            column_id: column.column_id,
+            name: column.name,
+            status: column.status.as_str().to_string(),
+            position: column.position,
+            wip_limit: column.wip_limit,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct BoardResponse {
+    pub board_id: String,
+    pub name: String,
+    pub columns: Vec<BoardColumnResponse>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<Board> for BoardResponse {
+    fn from(board: Board) -> Self {
+        Self {
+            board_id: board.board_id,
+            name: board.name,
+            columns: board
+                .columns
+                .into_iter()
+                .map(BoardColumnResponse::from)
+                .collect(),
+            created_at: board.created_at.to_rfc3339(),
+            updated_at: board.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct BoardListResponse {
+    pub boards: Vec<BoardResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BoardColumnViewResponse {
+    #[serde(flatten)]
+    pub column: BoardColumnResponse,
+    pub task_count: usize,
+    pub tasks: Vec<TaskResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BoardViewResponse {
+    pub board_id: String,
+    pub name: String,
+    pub columns: Vec<BoardColumnViewResponse>,
+}
+
+impl From<BoardView> for BoardViewResponse {
+    fn from(view: BoardView) -> Self {
+        Self {
+            board_id: view.board.board_id,
+            name: view.board.name,
+            columns: view
+                .columns
+                .into_iter()
+                .map(|column| BoardColumnViewResponse {
+                    column: BoardColumnResponse::from(column.column),
+                    task_count: column.tasks.len(),
+                    tasks: column.tasks.into_iter().map(TaskResponse::from).collect(),
+                })
+                .collect(),
+        }
+    }
+}
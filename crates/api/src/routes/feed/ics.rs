@@ -0,0 +1,105 @@
+use axum::extract::{Query, State};
+use axum::http::header::CONTENT_TYPE;
+use axum::http::HeaderMap;
+use axum::response::Response;
+
+use application::feed_service;
+use domain::feed::inputs::TaskFeedInput;
+use domain::task::ical::format_calendar;
+
+use crate::etag::{collection_etag, if_none_match, not_modified, with_etag};
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::feed::types::{FeedQuery, ICS_CONTENT_TYPE};
+use crate::AppState;
+
+// Served outside cognito_auth: calendar clients authenticate with the feed
+// token in the URL.
+pub async fn handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(params): Query<FeedQuery>,
+) -> Result<Response, ErrorResponse> {
+    let tasks = feed_service::task_feed::task_feed(
+        state.feed_repo.as_ref(),
+        state.task_repo.as_ref(),
+        TaskFeedInput {
+            token: params.token,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    let etag = collection_etag(tasks.len(), tasks.iter().map(|task| task.updated_at).max());
+    if if_none_match(&headers, &etag) {
+        return Ok(not_modified(&etag));
+    }
+
+    Ok(with_etag(
+        &etag,
+        (
+            [(CONTENT_TYPE, ICS_CONTENT_TYPE)],
+            format_calendar("Tasks", &tasks),
+        ),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::to_bytes;
+    use axum::extract::{Query, State};
+    use axum::http::header::{ETAG, IF_NONE_MATCH};
+    use axum::http::{HeaderMap, HeaderValue, StatusCode};
+
+    use super::handler;
+    use crate::routes::feed::types::FeedQuery;
+    use crate::routes::test_support::{
+        app_state, assert_status, feed_state, MockFeedRepo, MockTaskRepo, MockUserRepo,
+    };
+
+    fn query() -> Query<FeedQuery> {
+        Query(FeedQuery {
+            token: "secret".to_string(),
+        })
+    }
+
+    #[tokio::test]
+    async fn returns_calendar_with_etag() {
+        let state = feed_state(MockFeedRepo::default());
+
+        let response = handler(State(state), HeaderMap::new(), query())
+            .await
+            .unwrap_or_else(|_| panic!("feed failed"));
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().contains_key(ETAG));
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        let body = String::from_utf8(body.to_vec()).expect("utf8");
+        assert!(body.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(body.contains("BEGIN:VTODO\r\nUID:task-1\r\n"));
+    }
+
+    #[tokio::test]
+    async fn returns_not_modified_for_matching_etag() {
+        let state = app_state(
+            MockTaskRepo::with_list_result(Ok(Vec::new())),
+            MockUserRepo::default(),
+        );
+        let mut headers = HeaderMap::new();
+        headers.insert(IF_NONE_MATCH, HeaderValue::from_static("\"0-0\""));
+
+        let result = handler(State(state), headers, query()).await;
+
+        assert_status(result, StatusCode::NOT_MODIFIED);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_for_unknown_token() {
+        let state = feed_state(MockFeedRepo::with_owner_result(Ok(None)));
+
+        let result = handler(State(state), HeaderMap::new(), query()).await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+}
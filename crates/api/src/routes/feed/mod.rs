@@ -0,0 +1,4 @@
+pub mod ics;
+pub mod revoke;
+pub mod rotate;
+pub mod types;
@@ -0,0 +1,57 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+use application::feed_service;
+use domain::feed::inputs::RevokeFeedTokenInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    feed_service::revoke_feed_token::revoke_feed_token(
+        state.feed_repo.as_ref(),
+        RevokeFeedTokenInput {
+            user_id: user.user_id,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, State};
+    use axum::http::StatusCode;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::test_support::{assert_status, auth_user, feed_state, MockFeedRepo};
+
+    #[tokio::test]
+    async fn returns_no_content_on_success() {
+        let state = feed_state(MockFeedRepo::default());
+
+        let result = handler(State(state), Extension(auth_user())).await;
+
+        assert_status(result, StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_without_a_token() {
+        let state = feed_state(MockFeedRepo::with_revoke_result(Err(AppError::not_found(
+            "Feed",
+            "Feed not found",
+        ))));
+
+        let result = handler(State(state), Extension(auth_user())).await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+}
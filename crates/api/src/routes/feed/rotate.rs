@@ -0,0 +1,58 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::feed_service;
+use domain::feed::inputs::RotateFeedTokenInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::feed::types::FeedTokenResponse;
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let feed = feed_service::rotate_feed_token::rotate_feed_token(
+        state.feed_repo.as_ref(),
+        RotateFeedTokenInput {
+            user_id: user.user_id,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok((StatusCode::CREATED, Json(FeedTokenResponse::from(feed))))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, State};
+    use axum::http::StatusCode;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::test_support::{assert_status, auth_user, feed_state, MockFeedRepo};
+
+    #[tokio::test]
+    async fn returns_created_on_success() {
+        let state = feed_state(MockFeedRepo::default());
+
+        let result = handler(State(state), Extension(auth_user())).await;
+
+        assert_status(result, StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn returns_internal_server_error_on_repo_failure() {
+        let state = feed_state(MockFeedRepo::with_rotate_result(Err(AppError::database(
+            "db error",
+        ))));
+
+        let result = handler(State(state), Extension(auth_user())).await;
+
+        assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use domain::feed::entity::FeedToken;
+
+pub const ICS_CONTENT_TYPE: &str = "text/calendar; charset=utf-8";
+
+#[derive(Debug, Deserialize)]
+pub struct FeedQuery {
+    pub token: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FeedTokenResponse {
+    pub token: String,
+    pub feed_url: String,
+    pub created_at: String,
+}
+
+impl From<FeedToken> for FeedTokenResponse {
+    fn from(feed: FeedToken) -> Self {
+        Self {
+            feed_url: format!("/v1/tasks.ics?token={}", feed.token),
+            token: feed.token,
+            created_at: feed.created_at.to_rfc3339(),
+        }
+    }
+}
@@ -1,6 +1,14 @@
+pub mod boards;
+pub mod feed;
 pub mod router;
+pub mod shares;
+pub mod stats;
+pub mod sync;
 pub mod tasks;
+pub mod templates;
+pub mod time_entries;
 pub mod users;
+pub mod views;
 
 #[cfg(test)]
 pub mod test_support;
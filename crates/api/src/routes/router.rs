@@ -3,19 +3,185 @@ use axum::{middleware, Router};
 use crate::middleware::cognito_auth::cognito_auth;
 use crate::AppState;
 
-use super::{tasks, users};
+use super::{boards, feed, shares, stats, sync, tasks, templates, time_entries, users, views};
 
 pub fn router(state: AppState) -> Router {
     Router::new()
         .route("/task", axum::routing::post(tasks::post::handler))
+        .route(
+            "/task:action",
+            axum::routing::post(tasks::quick_add::handler),
+        )
+        .route(
+            "/task:action/preview",
+            axum::routing::post(tasks::quick_add_preview::handler),
+        )
         .route("/task/:id", axum::routing::get(tasks::get::handler))
         .route("/task/:id", axum::routing::put(tasks::put::handler))
         .route("/task/:id", axum::routing::delete(tasks::delete::handler))
+        .route("/task/:id", axum::routing::patch(tasks::patch::handler))
+        .route(
+            "/task/:id/archive",
+            axum::routing::post(tasks::archive::handler),
+        )
+        .route(
+            "/task/:id/unarchive",
+            axum::routing::post(tasks::unarchive::handler),
+        )
+        .route(
+            "/task/:id/content",
+            axum::routing::get(tasks::content::handler),
+        )
+        .route(
+            "/task/:id/history",
+            axum::routing::get(tasks::history::handler),
+        )
+        .route(
+            "/task/:id/assignees",
+            axum::routing::put(tasks::reassign::handler),
+        )
+        .route(
+            "/task/:id/members",
+            axum::routing::post(tasks::add_member::handler),
+        )
+        .route(
+            "/task/:id/members/:user_id",
+            axum::routing::delete(tasks::remove_member::handler),
+        )
+        // The caller's own completion of a group task.
+        .route(
+            "/task/:id/completion",
+            axum::routing::post(tasks::complete_member::handler),
+        )
+        .route(
+            "/task/:id/completion",
+            axum::routing::delete(tasks::reopen_member::handler),
+        )
+        .route(
+            "/task/:id/duplicate",
+            axum::routing::post(tasks::duplicate::handler),
+        )
+        .route(
+            "/task/:id/transfer",
+            axum::routing::post(tasks::transfer::handler),
+        )
+        .route(
+            "/task/:id/move",
+            axum::routing::post(tasks::move_task::handler),
+        )
+        .route(
+            "/task/:id/rollup",
+            axum::routing::get(tasks::rollup::handler),
+        )
+        .route(
+            "/task/:id/merge",
+            axum::routing::post(tasks::merge::handler),
+        )
+        .route(
+            "/task/:id/revert",
+            axum::routing::post(tasks::revert::handler),
+        )
+        .route(
+            "/task/:id/time-entries",
+            axum::routing::get(time_entries::list::handler),
+        )
+        .route(
+            "/task/:id/time-entries",
+            axum::routing::post(time_entries::post::handler),
+        )
+        .route(
+            "/task/:id/timer/start",
+            axum::routing::post(time_entries::start::handler),
+        )
         .route("/tasks", axum::routing::get(tasks::list::handler))
+        .route("/tasks/export", axum::routing::get(tasks::export::handler))
+        .route("/tasks/import", axum::routing::post(tasks::import::handler))
+        .route(
+            "/tasks/transfer",
+            axum::routing::post(tasks::transfer_many::handler),
+        )
+        .route("/tasks/next", axum::routing::get(tasks::next::handler))
+        .route(
+            "/tasks/snoozed",
+            axum::routing::get(tasks::list_snoozed::handler),
+        )
+        .route(
+            "/timer/stop",
+            axum::routing::post(time_entries::stop::handler),
+        )
+        .route(
+            "/time-entries/report",
+            axum::routing::get(time_entries::report::handler),
+        )
+        .route(
+            "/time-entries/:id",
+            axum::routing::delete(time_entries::delete::handler),
+        )
+        .route("/boards", axum::routing::post(boards::post::handler))
+        .route("/boards", axum::routing::get(boards::list::handler))
+        .route("/boards/:id", axum::routing::get(boards::get::handler))
+        .route("/boards/:id", axum::routing::put(boards::put::handler))
+        .route(
+            "/boards/:id",
+            axum::routing::delete(boards::delete::handler),
+        )
+        .route(
+            "/boards/:id/cards/:task_id/move",
+            axum::routing::post(boards::move_card::handler),
+        )
+        .route("/templates", axum::routing::post(templates::post::handler))
+        .route("/templates", axum::routing::get(templates::list::handler))
+        .route(
+            "/templates/:id",
+            axum::routing::get(templates::get::handler),
+        )
+        .route(
+            "/templates/:id",
+            axum::routing::put(templates::put::handler),
+        )
+        .route(
+            "/templates/:id",
+            axum::routing::delete(templates::delete::handler),
+        )
+        .route(
+            "/templates/:id/instantiate",
+            axum::routing::post(templates::instantiate::handler),
+        )
+        .route("/views", axum::routing::post(views::post::handler))
+        .route("/views", axum::routing::get(views::list::handler))
+        .route("/views/:id", axum::routing::get(views::get::handler))
+        .route("/views/:id", axum::routing::put(views::put::handler))
+        .route("/views/:id", axum::routing::delete(views::delete::handler))
+        .route(
+            "/views/:id/tasks",
+            axum::routing::get(views::tasks::handler),
+        )
+        .route("/stats/tasks", axum::routing::get(stats::tasks::handler))
+        .route("/sync", axum::routing::get(sync::get::handler))
+        .route("/sync", axum::routing::post(sync::push::handler))
+        .route("/feed-token", axum::routing::post(feed::rotate::handler))
+        .route("/feed-token", axum::routing::delete(feed::revoke::handler))
+        .route(
+            "/task/:id/share-links",
+            axum::routing::post(shares::create::handler),
+        )
+        .route(
+            "/task/:id/share-links",
+            axum::routing::get(shares::list::handler),
+        )
+        .route(
+            "/share-links/:id",
+            axum::routing::delete(shares::revoke::handler),
+        )
+        .route(
+            "/share-links/:id/access",
+            axum::routing::get(shares::access::handler),
+        )
         .route("/user", axum::routing::post(users::post::handler))
         .route("/user/:id", axum::routing::get(users::get::handler))
         .route("/user/:id", axum::routing::put(users::put::handler))
         .route("/user/:id", axum::routing::delete(users::delete::handler))
+        .route("/user/:id", axum::routing::patch(users::patch::handler))
         .route("/users", axum::routing::get(users::list::handler))
         .route(
             "/users/by-email/:email",
@@ -26,5 +192,10 @@ pub fn router(state: AppState) -> Router {
             axum::routing::get(users::get_by_username::handler),
         )
         .layer(middleware::from_fn(cognito_auth))
+        // Calendar clients cannot send a bearer token; the feed token in the
+        // query string is the credential.
+        .route("/tasks.ics", axum::routing::get(feed::ics::handler))
+        // Anyone holding the link may read the shared task.
+        .route("/shared/:token", axum::routing::get(shares::view::handler))
         .with_state(state)
 }
@@ -0,0 +1,72 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::share_service;
+use domain::share::inputs::ListShareAccessInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::shares::types::{ShareAccessListResponse, ShareAccessResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(share_id): Path<String>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let access = share_service::list_share_access::list_share_access(
+        state.share_repo.as_ref(),
+        ListShareAccessInput {
+            user_id: user.user_id,
+            share_id,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(Json(ShareAccessListResponse {
+        access: access.into_iter().map(ShareAccessResponse::from).collect(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::test_support::{assert_status, auth_user, share_state, MockShareRepo};
+
+    #[tokio::test]
+    async fn returns_ok_on_success() {
+        let state = share_state(MockShareRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("share-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_for_unknown_link() {
+        let state = share_state(MockShareRepo::with_access_result(Err(AppError::not_found(
+            "ShareLink",
+            "Share link not found",
+        ))));
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("share-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,111 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Json;
+use chrono::Utc;
+
+use application::share_service;
+use domain::share::inputs::IssueShareLinkInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::shares::types::{CreateShareLinkRequest, IssuedShareLinkResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(task_id): Path<String>,
+    Json(body): Json<CreateShareLinkRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let issued = share_service::create_share_link::create_share_link(
+        state.share_repo.as_ref(),
+        IssueShareLinkInput {
+            user_id: user.user_id,
+            task_id,
+            expires_in_days: body.expires_in_days,
+            password: body.password,
+            now: Utc::now(),
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(IssuedShareLinkResponse::from(issued)),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use axum::Json;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::shares::types::CreateShareLinkRequest;
+    use crate::routes::test_support::{assert_status, auth_user, share_state, MockShareRepo};
+
+    #[tokio::test]
+    async fn returns_created_on_success() {
+        let state = share_state(MockShareRepo::default());
+        let body = CreateShareLinkRequest {
+            expires_in_days: Some(30),
+            password: Some("correct horse".to_string()),
+        };
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            Json(body),
+        )
+        .await;
+
+        assert_status(result, StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_for_invalid_expiry_or_password() {
+        for body in [
+            CreateShareLinkRequest {
+                expires_in_days: Some(365),
+                password: None,
+            },
+            CreateShareLinkRequest {
+                expires_in_days: None,
+                password: Some("short".to_string()),
+            },
+        ] {
+            let result = handler(
+                State(share_state(MockShareRepo::default())),
+                Extension(auth_user()),
+                Path("task-1".to_string()),
+                Json(body),
+            )
+            .await;
+
+            assert_status(result, StatusCode::BAD_REQUEST);
+        }
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_for_unknown_task() {
+        let state = share_state(MockShareRepo::with_create_result(Err(AppError::not_found(
+            "Task",
+            "Task not found",
+        ))));
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            Json(CreateShareLinkRequest::default()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+}
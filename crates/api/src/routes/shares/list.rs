@@ -0,0 +1,71 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::share_service;
+use domain::share::inputs::ListShareLinksInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::shares::types::{ShareLinkListResponse, ShareLinkResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(task_id): Path<String>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let links = share_service::list_share_links::list_share_links(
+        state.share_repo.as_ref(),
+        ListShareLinksInput {
+            user_id: user.user_id,
+            task_id,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(Json(ShareLinkListResponse {
+        share_links: links.into_iter().map(ShareLinkResponse::from).collect(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::test_support::{assert_status, auth_user, share_state, MockShareRepo};
+
+    #[tokio::test]
+    async fn returns_ok_on_success() {
+        let state = share_state(MockShareRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_internal_server_error_on_repo_failure() {
+        let state = share_state(MockShareRepo::with_list_result(Err(AppError::database(
+            "db error",
+        ))));
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,6 @@
+pub mod access;
+pub mod create;
+pub mod list;
+pub mod revoke;
+pub mod types;
+pub mod view;
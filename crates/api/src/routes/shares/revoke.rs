@@ -0,0 +1,69 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+use application::share_service;
+use domain::share::inputs::RevokeShareLinkInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(share_id): Path<String>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    share_service::revoke_share_link::revoke_share_link(
+        state.share_repo.as_ref(),
+        RevokeShareLinkInput {
+            user_id: user.user_id,
+            share_id,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::test_support::{assert_status, auth_user, share_state, MockShareRepo};
+
+    #[tokio::test]
+    async fn returns_no_content_on_success() {
+        let state = share_state(MockShareRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("share-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_for_unknown_link() {
+        let state = share_state(MockShareRepo::with_revoke_result(Err(AppError::not_found(
+            "ShareLink",
+            "Share link not found",
+        ))));
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("share-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,141 @@
+use serde::{Deserialize, Serialize};
+
+use domain::share::entity::{IssuedShareLink, ShareAccess, ShareLink, SharedTask};
+use domain::task::entity::Task;
+use domain::task::markdown::{render_markdown, ChecklistItem};
+
+pub const SHARE_PASSWORD_HEADER: &str = "x-share-password";
+pub const MAX_USER_AGENT_CHARS: usize = 512;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct CreateShareLinkRequest {
+    pub expires_in_days: Option<i64>,
+    pub password: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ShareLinkResponse {
+    pub share_id: String,
+    pub task_id: String,
+    pub created_by: String,
+    pub expires_at: String,
+    pub has_password: bool,
+    pub revoked_at: Option<String>,
+    pub created_at: String,
+}
+
+impl From<ShareLink> for ShareLinkResponse {
+    fn from(link: ShareLink) -> Self {
+        Self {
+            share_id: link.share_id,
+            task_id: link.task_id,
+            created_by: link.created_by,
+            expires_at: link.expires_at.to_rfc3339(),
+            has_password: link.has_password,
+            revoked_at: link.revoked_at.map(|dt| dt.to_rfc3339()),
+            created_at: link.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct IssuedShareLinkResponse {
+    pub token: String,
+    pub url: String,
+    #[serde(flatten)]
+    pub link: ShareLinkResponse,
+}
+
+impl From<IssuedShareLink> for IssuedShareLinkResponse {
+    fn from(issued: IssuedShareLink) -> Self {
+        Self {
+            url: format!("/v1/shared/{}", issued.token),
+            token: issued.token,
+            link: ShareLinkResponse::from(issued.link),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ShareLinkListResponse {
+    pub share_links: Vec<ShareLinkResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ShareAccessResponse {
+    pub accessed_at: String,
+    pub granted: bool,
+    pub ip: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+impl From<ShareAccess> for ShareAccessResponse {
+    fn from(access: ShareAccess) -> Self {
+        Self {
+            accessed_at: access.accessed_at.to_rfc3339(),
+            granted: access.granted,
+            ip: access.ip,
+            user_agent: access.user_agent,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ShareAccessListResponse {
+    pub access: Vec<ShareAccessResponse>,
+}
+
+// The public view leaves out members, assignees, time tracking and anything
+// else that only makes sense to people with an account.
+#[derive(Debug, Serialize)]
+pub struct SharedTaskView {
+    pub task_id: String,
+    pub parent_task_id: Option<String>,
+    pub content: String,
+    pub content_html: String,
+    pub checklist: Vec<ChecklistItem>,
+    pub status: String,
+    pub priority: Option<String>,
+    pub labels: Vec<String>,
+    pub due_at: Option<String>,
+    pub completed_at: Option<String>,
+}
+
+impl From<Task> for SharedTaskView {
+    fn from(task: Task) -> Self {
+        let rendered = render_markdown(&task.content);
+        Self {
+            task_id: task.task_id,
+            parent_task_id: task.parent_task_id,
+            content: task.content,
+            content_html: rendered.html,
+            checklist: rendered.checklist,
+            status: task.status.as_str().to_string(),
+            priority: task.priority.map(|priority| priority.as_str().to_string()),
+            labels: task.labels,
+            due_at: task.due_at.map(|dt| dt.to_rfc3339()),
+            completed_at: task.completed_at.map(|dt| dt.to_rfc3339()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SharedTaskResponse {
+    pub expires_at: String,
+    pub task: SharedTaskView,
+    pub subtasks: Vec<SharedTaskView>,
+}
+
+impl From<SharedTask> for SharedTaskResponse {
+    fn from(shared: SharedTask) -> Self {
+        Self {
+            expires_at: shared.expires_at.to_rfc3339(),
+            task: SharedTaskView::from(shared.task),
+            subtasks: shared
+                .subtasks
+                .into_iter()
+                .map(SharedTaskView::from)
+                .collect(),
+        }
+    }
+}
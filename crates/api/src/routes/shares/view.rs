@@ -0,0 +1,149 @@
+use axum::extract::{Path, State};
+use axum::http::header::{CACHE_CONTROL, USER_AGENT};
+use axum::http::{HeaderMap, HeaderName};
+use axum::response::IntoResponse;
+use axum::Json;
+use chrono::Utc;
+
+use application::share_service;
+use domain::share::inputs::OpenShareLinkInput;
+
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::shares::types::{
+    SharedTaskResponse, MAX_USER_AGENT_CHARS, SHARE_PASSWORD_HEADER,
+};
+use crate::AppState;
+
+// Served outside cognito_auth: the token in the path is the credential, and
+// the password, if the link has one, travels in a header rather than the URL.
+pub async fn handler(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+    };
+    let ip = header("x-forwarded-for")
+        .and_then(|forwarded| forwarded.split(',').next().map(|ip| ip.trim().to_string()));
+    let user_agent =
+        header(USER_AGENT.as_str()).map(|agent| agent.chars().take(MAX_USER_AGENT_CHARS).collect());
+
+    let shared = share_service::open_share_link::open_share_link(
+        state.share_repo.as_ref(),
+        state.task_repo.as_ref(),
+        OpenShareLinkInput {
+            token,
+            password: header(SHARE_PASSWORD_HEADER),
+            ip,
+            user_agent,
+            now: Utc::now(),
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok((
+        [
+            (CACHE_CONTROL, "no-store"),
+            (HeaderName::from_static("x-robots-tag"), "noindex"),
+        ],
+        Json(SharedTaskResponse::from(shared)),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use axum::extract::{Path, State};
+    use axum::http::{HeaderMap, HeaderValue, StatusCode};
+    use chrono::{Duration, Utc};
+    use domain::share::entity::ShareLink;
+    use domain::share::validation::MAX_FAILED_SHARE_ATTEMPTS;
+
+    use super::handler;
+    use crate::routes::shares::types::SHARE_PASSWORD_HEADER;
+    use crate::routes::test_support::{assert_status, share_state, MockShareRepo};
+
+    fn link(has_password: bool) -> ShareLink {
+        ShareLink {
+            share_id: "share-1".to_string(),
+            task_id: "task-1".to_string(),
+            created_by: "user-123".to_string(),
+            expires_at: Utc::now() + Duration::days(1),
+            has_password,
+            revoked_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn returns_ok_for_an_active_link() {
+        let state = share_state(MockShareRepo::default());
+
+        let result = handler(State(state), Path("token".to_string()), HeaderMap::new()).await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_for_expired_or_revoked_links() {
+        let expired = ShareLink {
+            expires_at: Utc::now() - Duration::minutes(1),
+            ..link(false)
+        };
+        let revoked = ShareLink {
+            revoked_at: Some(Utc::now()),
+            ..link(false)
+        };
+        for link in [expired, revoked] {
+            let state = share_state(MockShareRepo::with_find_result(Ok(Some(link))));
+
+            let result = handler(State(state), Path("token".to_string()), HeaderMap::new()).await;
+
+            assert_status(result, StatusCode::NOT_FOUND);
+        }
+    }
+
+    #[tokio::test]
+    async fn requires_the_password_when_set() {
+        let state = share_state(MockShareRepo::with_find_result(Ok(Some(link(true)))));
+
+        let result = handler(State(state), Path("token".to_string()), HeaderMap::new()).await;
+
+        assert_status(result, StatusCode::UNAUTHORIZED);
+
+        let state = share_state(MockShareRepo::with_find_result(Ok(Some(link(true)))));
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            SHARE_PASSWORD_HEADER,
+            HeaderValue::from_static("correct horse"),
+        );
+
+        let result = handler(State(state), Path("token".to_string()), headers).await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn refuses_password_attempts_after_too_many_failures() {
+        let share_repo = MockShareRepo {
+            failed_count_result: Mutex::new(Some(Ok(MAX_FAILED_SHARE_ATTEMPTS))),
+            ..MockShareRepo::with_find_result(Ok(Some(link(true))))
+        };
+        let state = share_state(share_repo);
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            SHARE_PASSWORD_HEADER,
+            HeaderValue::from_static("correct horse"),
+        );
+
+        let result = handler(State(state), Path("token".to_string()), headers).await;
+
+        assert_status(result, StatusCode::TOO_MANY_REQUESTS);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod tasks;
+pub mod types;
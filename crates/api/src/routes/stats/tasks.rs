@@ -0,0 +1,122 @@
+use axum::extract::{Query, State};
+use axum::response::IntoResponse;
+use axum::Json;
+use chrono::Utc;
+
+use application::task_service;
+use domain::task::inputs::TaskStatsInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::stats::types::{parse_bucket, TaskStatsQuery, TaskStatsResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Query(params): Query<TaskStatsQuery>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let bucket = params
+        .bucket
+        .as_deref()
+        .map(parse_bucket)
+        .transpose()?
+        .unwrap_or_default();
+
+    let stats = task_service::task_stats::task_stats(
+        state.task_repo.as_ref(),
+        state.user_repo.as_ref(),
+        TaskStatsInput {
+            user_id: user.user_id,
+            from: params.from,
+            to: params.to,
+            bucket,
+            now: Utc::now(),
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(Json(TaskStatsResponse::from(stats)))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Query, State};
+    use axum::http::StatusCode;
+    use chrono::NaiveDate;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::stats::types::TaskStatsQuery;
+    use crate::routes::test_support::{
+        app_state, assert_status, auth_user, MockTaskRepo, MockUserRepo,
+    };
+
+    fn query(to: &str, bucket: Option<&str>) -> Query<TaskStatsQuery> {
+        Query(TaskStatsQuery {
+            from: NaiveDate::from_ymd_opt(2025, 3, 1).expect("date"),
+            to: to.parse().expect("date"),
+            bucket: bucket.map(str::to_string),
+        })
+    }
+
+    #[tokio::test]
+    async fn returns_ok_on_success() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            query("2025-03-31", Some("week")),
+        )
+        .await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_for_unknown_bucket() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            query("2025-03-31", Some("month")),
+        )
+        .await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_for_inverted_range() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            query("2025-02-01", None),
+        )
+        .await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn returns_internal_server_error_on_repo_failure() {
+        let state = app_state(
+            MockTaskRepo::with_stats_result(Err(AppError::database("db error"))),
+            MockUserRepo::default(),
+        );
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            query("2025-03-31", None),
+        )
+        .await;
+
+        assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
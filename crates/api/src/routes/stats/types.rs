@@ -0,0 +1,51 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use domain::task::stats::{StatsBucket, StatusCount, TaskStats, ThroughputBucket};
+
+use crate::response::{validation_error, ErrorResponse};
+
+#[derive(Debug, Deserialize)]
+pub struct TaskStatsQuery {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub bucket: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TaskStatsResponse {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub timezone: String,
+    pub bucket: StatsBucket,
+    pub by_status: Vec<StatusCount>,
+    pub throughput: Vec<ThroughputBucket>,
+    pub created: i64,
+    pub completed: i64,
+    pub average_lead_time_seconds: Option<i64>,
+    pub overdue: i64,
+    pub completed_late: i64,
+}
+
+impl From<TaskStats> for TaskStatsResponse {
+    fn from(stats: TaskStats) -> Self {
+        Self {
+            from: stats.from,
+            to: stats.to,
+            timezone: stats.timezone,
+            bucket: stats.bucket,
+            by_status: stats.by_status,
+            throughput: stats.throughput,
+            created: stats.created,
+            completed: stats.completed,
+            average_lead_time_seconds: stats.average_lead_time_seconds,
+            overdue: stats.overdue,
+            completed_late: stats.completed_late,
+        }
+    }
+}
+
+pub fn parse_bucket(value: &str) -> Result<StatsBucket, ErrorResponse> {
+    StatsBucket::parse(value)
+        .ok_or_else(|| validation_error("invalid_bucket", "Bucket must be day or week"))
+}
@@ -0,0 +1,86 @@
+use axum::extract::{Query, State};
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::task_service;
+use domain::task::inputs::SyncTasksInput;
+use domain::task::sync::{DEFAULT_SYNC_LIMIT, MAX_SYNC_LIMIT};
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::sync::types::{SyncQuery, SyncResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Query(params): Query<SyncQuery>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_SYNC_LIMIT)
+        .clamp(1, MAX_SYNC_LIMIT);
+
+    let changes = task_service::sync_tasks::sync_tasks(
+        state.task_repo.as_ref(),
+        SyncTasksInput {
+            user_id: user.user_id,
+            since: params.since,
+            limit,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(Json(SyncResponse::from(changes)))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Query, State};
+    use axum::http::StatusCode;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::sync::types::SyncQuery;
+    use crate::routes::test_support::{
+        app_state, assert_status, auth_user, MockTaskRepo, MockUserRepo,
+    };
+
+    fn query(since: Option<&str>) -> Query<SyncQuery> {
+        Query(SyncQuery {
+            since: since.map(str::to_string),
+            limit: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn returns_ok_on_success() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+
+        let result = handler(State(state), Extension(auth_user()), query(Some("42"))).await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_for_invalid_token() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+
+        let result = handler(State(state), Extension(auth_user()), query(Some("abc"))).await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn returns_internal_server_error_on_repo_failure() {
+        let state = app_state(
+            MockTaskRepo::with_changes_result(Err(AppError::database("db error"))),
+            MockUserRepo::default(),
+        );
+
+        let result = handler(State(state), Extension(auth_user()), query(None)).await;
+
+        assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,204 @@
+use axum::extract::State;
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::task_service;
+use domain::error::AppError;
+use domain::task::inputs::PushTaskChangesInput;
+use domain::task::sync::MAX_SYNC_MUTATIONS;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::sync::types::{MutationResponse, PushRequest, PushResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Json(body): Json<PushRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    // Checked before any mutation is validated, and against the whole request
+    // rather than only the mutations that pass validation.
+    if body.mutations.len() > MAX_SYNC_MUTATIONS {
+        return Err(from_app_error(AppError::validation(
+            "too_many_mutations",
+            format!("A push can contain at most {MAX_SYNC_MUTATIONS} mutations"),
+            Some("mutations".to_string()),
+        )));
+    }
+
+    // Invalid mutations are answered here; the rest keep their slot so results
+    // come back in request order.
+    let mut mutations = Vec::new();
+    let mut slots = Vec::new();
+    for mutation in body.mutations {
+        let mutation_id = mutation.mutation_id.clone();
+        match mutation.into_mutation(&user.user_id, state.max_content_chars) {
+            Ok(mutation) => {
+                mutations.push(mutation);
+                slots.push(None);
+            }
+            Err(error) => slots.push(Some(MutationResponse::rejected(mutation_id, error))),
+        }
+    }
+
+    let applied = task_service::push_task_changes::push_task_changes(
+        state.task_repo.as_ref(),
+        PushTaskChangesInput {
+            user_id: user.user_id,
+            mutations,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    let mut applied = applied.into_iter().map(MutationResponse::from);
+    let results = slots
+        .into_iter()
+        .filter_map(|slot| slot.or_else(|| applied.next()))
+        .collect();
+
+    Ok(Json(PushResponse { results }))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::to_bytes;
+    use axum::extract::{Extension, State};
+    use axum::http::StatusCode;
+    use axum::response::IntoResponse;
+    use axum::Json;
+    use domain::error::AppError;
+    use domain::task::sync::MAX_SYNC_MUTATIONS;
+    use serde_json::json;
+
+    use super::handler;
+    use crate::routes::sync::types::PushRequest;
+    use crate::routes::test_support::{
+        app_state, assert_status, auth_user, MockTaskRepo, MockUserRepo,
+    };
+
+    fn body(value: serde_json::Value) -> Json<PushRequest> {
+        Json(serde_json::from_value(value).expect("push request"))
+    }
+
+    #[tokio::test]
+    async fn reports_each_mutation_in_request_order() {
+        let state = app_state(
+            MockTaskRepo::with_update_result(Err(AppError::version_conflict("Task", 4))),
+            MockUserRepo::default(),
+        );
+
+        let response = handler(
+            State(state),
+            Extension(auth_user()),
+            body(json!({
+                "mutations": [
+                    {"mutation_id": "m1", "op": "create", "task": {"content": " "}},
+                    {"mutation_id": "m2", "op": "update", "task_id": "task-1", "version": 3,
+                     "task": {"content": "Offline edit", "due_at": null}},
+                    {"mutation_id": "m3", "op": "delete", "task_id": "task-2", "version": 1}
+                ]
+            })),
+        )
+        .await
+        .unwrap_or_else(|_| panic!("push failed"))
+        .into_response();
+
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        let body: serde_json::Value = serde_json::from_slice(&body).expect("json");
+        let statuses: Vec<_> = body["results"]
+            .as_array()
+            .expect("results")
+            .iter()
+            .map(|result| (result["mutation_id"].clone(), result["status"].clone()))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                (json!("m1"), json!("rejected")),
+                (json!("m2"), json!("conflict")),
+                (json!("m3"), json!("applied")),
+            ]
+        );
+        assert_eq!(body["results"][0]["error"]["code"], "invalid_content");
+        assert_eq!(body["results"][1]["task"]["task_id"], "task-1");
+    }
+
+    #[tokio::test]
+    async fn replays_a_retried_create_without_creating_it_again() {
+        let task_repo = MockTaskRepo::default();
+        let created_count = task_repo.created_count.clone();
+        let state = app_state(task_repo, MockUserRepo::default());
+        let push = || {
+            body(json!({
+                "mutations": [{"mutation_id": "m1", "op": "create", "task": {"content": "New"}}]
+            }))
+        };
+
+        for _ in 0..2 {
+            let response = handler(State(state.clone()), Extension(auth_user()), push())
+                .await
+                .unwrap_or_else(|_| panic!("push failed"))
+                .into_response();
+
+            let body = to_bytes(response.into_body(), usize::MAX)
+                .await
+                .expect("body");
+            let body: serde_json::Value = serde_json::from_slice(&body).expect("json");
+            assert_eq!(body["results"][0]["status"], "applied");
+            assert_eq!(body["results"][0]["task"]["task_id"], "task-1");
+        }
+
+        assert_eq!(*created_count.lock().expect("mutex"), 1);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_when_too_many_mutations_counting_invalid_ones() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let mut mutations =
+            vec![json!({"mutation_id": "valid", "op": "create", "task": {"content": "New"}})];
+        mutations.extend((0..MAX_SYNC_MUTATIONS).map(|index| {
+            json!({"mutation_id": format!("m{index}"), "op": "create", "task": {"content": " "}})
+        }));
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            body(json!({ "mutations": mutations })),
+        )
+        .await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn rejects_unknown_operations() {
+        let result = serde_json::from_value::<PushRequest>(json!({
+            "mutations": [{"mutation_id": "m1", "op": "archive", "task_id": "task-1"}]
+        }));
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn returns_internal_server_error_on_repo_failure() {
+        let state = app_state(
+            MockTaskRepo::with_create_result(Err(AppError::database("db error"))),
+            MockUserRepo::default(),
+        );
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            body(json!({
+                "mutations": [{"mutation_id": "m1", "op": "create", "task": {"content": "New"}}]
+            })),
+        )
+        .await;
+
+        assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
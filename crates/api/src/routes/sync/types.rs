@@ -0,0 +1,149 @@
+use serde::{Deserialize, Serialize};
+
+use domain::error::AppError;
+use domain::task::inputs::DeleteTaskInput;
+use domain::task::sync::{
+    format_sync_token, SyncMutation, SyncMutationResult, SyncOperation, TaskChanges,
+};
+
+use crate::response::{from_app_error, ErrorDetail, ErrorResponse};
+use crate::routes::tasks::types::{CreateTaskRequest, TaskResponse, UpdateTaskRequest};
+
+#[derive(Debug, Deserialize)]
+pub struct SyncQuery {
+    pub since: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SyncResponse {
+    pub tasks: Vec<TaskResponse>,
+    pub deleted: Vec<String>,
+    pub next_since: String,
+    pub has_more: bool,
+}
+
+impl From<TaskChanges> for SyncResponse {
+    fn from(changes: TaskChanges) -> Self {
+        Self {
+            tasks: changes.tasks.into_iter().map(TaskResponse::from).collect(),
+            deleted: changes.deleted,
+            next_since: format_sync_token(changes.next_since),
+            has_more: changes.has_more,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PushRequest {
+    pub mutations: Vec<MutationRequest>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MutationRequest {
+    pub mutation_id: String,
+    #[serde(flatten)]
+    pub operation: MutationOperation,
+}
+
+// `version` is the version the client last saw; the mutation is rejected as a
+// conflict when the server copy has moved on.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum MutationOperation {
+    Create {
+        task: CreateTaskRequest,
+    },
+    Update {
+        task_id: String,
+        version: i32,
+        task: UpdateTaskRequest,
+    },
+    Delete {
+        task_id: String,
+        version: i32,
+    },
+}
+
+impl MutationRequest {
+    pub fn into_mutation(
+        self,
+        user_id: &str,
+        max_content_chars: usize,
+    ) -> Result<SyncMutation, ErrorResponse> {
+        let operation = match self.operation {
+            MutationOperation::Create { task } => task
+                .into_input(user_id.to_string(), max_content_chars)
+                .map(SyncOperation::Create),
+            MutationOperation::Update {
+                task_id,
+                version,
+                task,
+            } => task
+                .into_input(user_id.to_string(), task_id, version, max_content_chars)
+                .map(SyncOperation::Update),
+            MutationOperation::Delete { task_id, version } => {
+                Ok(SyncOperation::Delete(DeleteTaskInput {
+                    user_id: user_id.to_string(),
+                    task_id,
+                    version: Some(version),
+                }))
+            }
+        };
+
+        Ok(SyncMutation {
+            mutation_id: self.mutation_id,
+            operation: operation?,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct MutationResponse {
+    pub mutation_id: String,
+    pub status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task: Option<TaskResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ErrorDetail>,
+}
+
+impl MutationResponse {
+    pub fn rejected(mutation_id: String, error: ErrorResponse) -> Self {
+        Self {
+            mutation_id,
+            status: "rejected",
+            task: None,
+            error: Some(error.into_detail()),
+        }
+    }
+}
+
+impl From<SyncMutationResult> for MutationResponse {
+    fn from(result: SyncMutationResult) -> Self {
+        let task = result.task.map(TaskResponse::from);
+        match result.error {
+            None => Self {
+                mutation_id: result.mutation_id,
+                status: "applied",
+                task,
+                error: None,
+            },
+            Some(error) => Self {
+                mutation_id: result.mutation_id,
+                status: if matches!(error, AppError::VersionConflict { .. }) {
+                    "conflict"
+                } else {
+                    "rejected"
+                },
+                task,
+                error: Some(from_app_error(error).into_detail()),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PushResponse {
+    pub results: Vec<MutationResponse>,
+}
@@ -0,0 +1,85 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::task_service;
+use domain::task::inputs::AddTaskMemberInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::tasks::types::AddTaskMemberRequest;
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(task_id): Path<String>,
+    Json(body): Json<AddTaskMemberRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    task_service::add_task_member::add_task_member(
+        state.task_repo.as_ref(),
+        AddTaskMemberInput {
+            user_id: user.user_id,
+            task_id,
+            member_id: body.user_id,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use axum::Json;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::tasks::types::AddTaskMemberRequest;
+    use crate::routes::test_support::{
+        app_state, assert_status, auth_user, MockTaskRepo, MockUserRepo,
+    };
+
+    #[tokio::test]
+    async fn returns_no_content_on_success() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let body = AddTaskMemberRequest {
+            user_id: "user-2".to_string(),
+        };
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            Json(body),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_for_unknown_user() {
+        let state = app_state(
+            MockTaskRepo::with_member_result(Err(AppError::not_found("User", "User not found"))),
+            MockUserRepo::default(),
+        );
+        let body = AddTaskMemberRequest {
+            user_id: "missing".to_string(),
+        };
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            Json(body),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+}
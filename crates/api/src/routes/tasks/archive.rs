@@ -0,0 +1,90 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::task_service;
+use domain::task::inputs::SetTaskArchivedInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::tasks::types::TaskResponse;
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(task_id): Path<String>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let task = task_service::set_task_archived::set_task_archived(
+        state.task_repo.as_ref(),
+        SetTaskArchivedInput {
+            user_id: user.user_id,
+            task_id,
+            archived: true,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(Json(TaskResponse::from(task)))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::test_support::{
+        app_state, assert_status, auth_user, MockTaskRepo, MockUserRepo,
+    };
+
+    #[tokio::test]
+    async fn returns_ok_on_success() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_when_missing() {
+        let state = app_state(
+            MockTaskRepo::with_archive_result(Err(AppError::not_found("Task", "Task not found"))),
+            MockUserRepo::default(),
+        );
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn returns_internal_server_error_on_repo_failure() {
+        let state = app_state(
+            MockTaskRepo::with_archive_result(Err(AppError::database("db error"))),
+            MockUserRepo::default(),
+        );
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
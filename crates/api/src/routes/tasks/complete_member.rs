@@ -0,0 +1,85 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::task_service;
+use domain::task::inputs::SetMemberCompletionInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::tasks::types::TaskResponse;
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(task_id): Path<String>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let task = task_service::set_member_completion::set_member_completion(
+        state.task_repo.as_ref(),
+        SetMemberCompletionInput {
+            user_id: user.user_id,
+            task_id,
+            completed: true,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(Json(TaskResponse::from(task)))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use axum::response::IntoResponse;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::test_support::{
+        app_state, assert_status, auth_user, MockTaskRepo, MockUserRepo,
+    };
+
+    #[tokio::test]
+    async fn returns_the_group_progress() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+
+        let response = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+        )
+        .await
+        .unwrap_or_else(|_| panic!("complete failed"))
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json");
+        assert_eq!(json["group"]["completed"], 1);
+        assert_eq!(json["group"]["total"], 2);
+    }
+
+    #[tokio::test]
+    async fn returns_conflict_for_a_task_that_is_not_a_group_task() {
+        let state = app_state(
+            MockTaskRepo::with_completion_result(Err(AppError::conflict(
+                "Task",
+                "Task is not a group task",
+            ))),
+            MockUserRepo::default(),
+        );
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::CONFLICT);
+    }
+}
@@ -1,38 +1,45 @@
 use axum::extract::{Path, State};
-use axum::http::StatusCode;
+use axum::http::{HeaderMap, StatusCode};
 use axum::response::IntoResponse;
-use axum::Json;
 
 use application::task_service;
 use domain::task::inputs::DeleteTaskInput;
 
+use crate::etag::{from_conditional_error, if_match, precondition_failed};
 use crate::middleware::cognito_auth::AuthUser;
-use crate::response::{from_app_error, ErrorResponse};
-use crate::routes::tasks::types::CountResponse;
+use crate::response::ErrorResponse;
 use crate::AppState;
 
 pub async fn handler(
     State(state): State<AppState>,
     axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
     Path(task_id): Path<String>,
+    headers: HeaderMap,
 ) -> Result<impl IntoResponse, ErrorResponse> {
-    let count = task_service::delete_task::delete_task(
+    let if_match = if_match(&headers)?;
+    let version = if_match
+        .map(|token| i32::try_from(token).map_err(|_| precondition_failed()))
+        .transpose()?;
+
+    task_service::delete_task::delete_task(
         state.task_repo.as_ref(),
         DeleteTaskInput {
             user_id: user.user_id,
             task_id,
+            version,
         },
     )
     .await
-    .map_err(from_app_error)?;
+    .map_err(|error| from_conditional_error(error, if_match.is_some()))?;
 
-    Ok((StatusCode::OK, Json(CountResponse { count })))
+    Ok(StatusCode::NO_CONTENT)
 }
 
 #[cfg(test)]
 mod tests {
     use axum::extract::{Extension, Path, State};
-    use axum::http::StatusCode;
+    use axum::http::header::IF_MATCH;
+    use axum::http::{HeaderMap, HeaderValue, StatusCode};
     use domain::error::AppError;
 
     use super::handler;
@@ -41,17 +48,36 @@ mod tests {
     };
 
     #[tokio::test]
-    async fn returns_ok_on_success() {
+    async fn returns_no_content_on_success() {
         let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
 
         let result = handler(
             State(state),
             Extension(auth_user()),
             Path("task-1".to_string()),
+            HeaderMap::new(),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_when_missing() {
+        let state = app_state(
+            MockTaskRepo::with_delete_result(Err(AppError::not_found("Task", "Task not found"))),
+            MockUserRepo::default(),
+        );
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            HeaderMap::new(),
         )
         .await;
 
-        assert_status(result, StatusCode::OK);
+        assert_status(result, StatusCode::NOT_FOUND);
     }
 
     #[tokio::test]
@@ -65,9 +91,30 @@ mod tests {
             State(state),
             Extension(auth_user()),
             Path("task-1".to_string()),
+            HeaderMap::new(),
         )
         .await;
 
         assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
     }
+
+    #[tokio::test]
+    async fn returns_precondition_failed_when_if_match_is_stale() {
+        let state = app_state(
+            MockTaskRepo::with_delete_result(Err(AppError::version_conflict("Task", 3))),
+            MockUserRepo::default(),
+        );
+        let mut headers = HeaderMap::new();
+        headers.insert(IF_MATCH, HeaderValue::from_static("\"1\""));
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            headers,
+        )
+        .await;
+
+        assert_status(result, StatusCode::PRECONDITION_FAILED);
+    }
 }
@@ -0,0 +1,131 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::task_service;
+use domain::error::AppError;
+use domain::task::inputs::DuplicateTaskInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::tasks::types::{DuplicateTaskRequest, DuplicateTaskResponse, TaskResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(task_id): Path<String>,
+    Json(body): Json<DuplicateTaskRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let mut tasks = task_service::duplicate_task::duplicate_task(
+        state.task_repo.as_ref(),
+        DuplicateTaskInput {
+            user_id: user.user_id,
+            task_id,
+            options: body.options()?,
+        },
+    )
+    .await
+    .map_err(from_app_error)?
+    .into_iter()
+    .map(TaskResponse::from);
+
+    let task = tasks
+        .next()
+        .ok_or_else(|| from_app_error(AppError::database("Copy was not created")))?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(DuplicateTaskResponse {
+            task,
+            subtasks: tasks.collect(),
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use axum::Json;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::tasks::types::DuplicateTaskRequest;
+    use crate::routes::test_support::{
+        app_state, assert_status, auth_user, MockTaskRepo, MockUserRepo,
+    };
+
+    #[tokio::test]
+    async fn returns_created_on_success() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            Json(DuplicateTaskRequest {
+                include_subtasks: Some(false),
+                ..Default::default()
+            }),
+        )
+        .await;
+
+        assert_status(result, StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_for_a_due_date_out_of_range() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            Json(DuplicateTaskRequest {
+                due_at: Some("+262142-12-01T00:00:00Z".parse().expect("date")),
+                ..Default::default()
+            }),
+        )
+        .await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_when_missing() {
+        let state = app_state(
+            MockTaskRepo::with_subtree_result(Ok(Vec::new())),
+            MockUserRepo::default(),
+        );
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            Json(DuplicateTaskRequest::default()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn returns_internal_server_error_on_repo_failure() {
+        let state = app_state(
+            MockTaskRepo::with_subtree_result(Err(AppError::database("db error"))),
+            MockUserRepo::default(),
+        );
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            Json(DuplicateTaskRequest::default()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
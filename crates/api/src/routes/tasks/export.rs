@@ -0,0 +1,230 @@
+use axum::body::Body;
+use axum::extract::{Query, State};
+use axum::http::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
+use axum::response::{IntoResponse, Response};
+use futures_util::stream::{self, StreamExt};
+
+use application::task_service;
+use application::task_service::repository::TaskRepository;
+use domain::error::AppError;
+use domain::task::entity::Task;
+use domain::task::filter::TaskQuery;
+use domain::task::inputs::{ArchiveFilter, ListTasksInput, SnoozeFilter};
+use domain::task::todo_txt::format_todo_txt;
+
+use crate::csv::{csv_row, CSV_CONTENT_TYPE};
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::tasks::types::{
+    parse_transfer_format, ExportQuery, TaskRecord, TransferFormat, TASK_RECORD_COLUMNS,
+};
+use crate::AppState;
+
+const EXPORT_PAGE_SIZE: i64 = 500;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Query(params): Query<ExportQuery>,
+) -> Result<Response, ErrorResponse> {
+    let format = parse_transfer_format(params.format.as_deref())?;
+
+    // The first page is read up front so a failing query still gets a proper
+    // error response; later pages are fetched as the client reads the body.
+    let first = export_page(state.task_repo.as_ref(), &user.user_id, 1)
+        .await
+        .map_err(from_app_error)?;
+    let next = next_page(1, &first);
+    let head = encode_page(format, &first, true, next.is_none());
+
+    let repo = state.task_repo.clone();
+    let user_id = user.user_id;
+    let rest = stream::try_unfold(next, move |page| {
+        let repo = repo.clone();
+        let user_id = user_id.clone();
+        async move {
+            let Some(page) = page else {
+                return Ok(None);
+            };
+            let tasks = export_page(repo.as_ref(), &user_id, page).await?;
+            let next = next_page(page, &tasks);
+            Ok::<_, AppError>(Some((
+                encode_page(format, &tasks, false, next.is_none()),
+                next,
+            )))
+        }
+    });
+    let body = Body::from_stream(stream::once(async { Ok(head) }).chain(rest));
+
+    let (content_type, filename) = match format {
+        TransferFormat::Csv => (CSV_CONTENT_TYPE, "tasks.csv"),
+        TransferFormat::Json => ("application/json", "tasks.json"),
+        TransferFormat::TodoTxt => ("text/plain; charset=utf-8", "todo.txt"),
+    };
+    Ok((
+        [
+            (CONTENT_TYPE, content_type.to_string()),
+            (
+                CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{filename}\""),
+            ),
+        ],
+        body,
+    )
+        .into_response())
+}
+
+async fn export_page(
+    repo: &dyn TaskRepository,
+    user_id: &str,
+    page: i64,
+) -> Result<Vec<Task>, AppError> {
+    task_service::list_tasks::list_tasks(
+        repo,
+        ListTasksInput {
+            user_id: user_id.to_string(),
+            page,
+            limit: EXPORT_PAGE_SIZE,
+            query: TaskQuery {
+                snoozed: SnoozeFilter::Include,
+                archived: ArchiveFilter::Include,
+                ..Default::default()
+            },
+        },
+    )
+    .await
+}
+
+fn next_page(page: i64, tasks: &[Task]) -> Option<i64> {
+    (tasks.len() as i64 == EXPORT_PAGE_SIZE).then_some(page + 1)
+}
+
+fn encode_page(format: TransferFormat, tasks: &[Task], first: bool, last: bool) -> String {
+    let mut body = String::new();
+    match format {
+        TransferFormat::Csv => {
+            if first {
+                body.push_str(&csv_row(&TASK_RECORD_COLUMNS));
+            }
+            for task in tasks {
+                let timestamp = |at: Option<chrono::DateTime<chrono::Utc>>| {
+                    at.map(|at| at.to_rfc3339()).unwrap_or_default()
+                };
+                body.push_str(&csv_row(&[
+                    task.task_id.clone(),
+                    task.content.clone(),
+                    task.status.as_str().to_string(),
+                    task.priority
+                        .map(|priority| priority.as_str().to_string())
+                        .unwrap_or_default(),
+                    task.labels.join(","),
+                    timestamp(task.due_at),
+                    timestamp(task.completed_at),
+                    task.waiting_on.clone().unwrap_or_default(),
+                    task.created_at.to_rfc3339(),
+                ]));
+            }
+        }
+        TransferFormat::Json => {
+            if first {
+                body.push('[');
+            }
+            for (index, task) in tasks.iter().enumerate() {
+                if !(first && index == 0) {
+                    body.push(',');
+                }
+                body.push('\n');
+                body.push_str(&serde_json::to_string(&TaskRecord::from(task)).unwrap_or_default());
+            }
+            if last {
+                body.push_str("\n]\n");
+            }
+        }
+        TransferFormat::TodoTxt => {
+            for task in tasks {
+                body.push_str(&format_todo_txt(task));
+                body.push('\n');
+            }
+        }
+    }
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::to_bytes;
+    use axum::extract::{Extension, Query, State};
+    use axum::http::header::CONTENT_TYPE;
+    use axum::http::StatusCode;
+    use axum::response::IntoResponse;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::csv::CSV_CONTENT_TYPE;
+    use crate::routes::tasks::types::ExportQuery;
+    use crate::routes::test_support::{
+        app_state, assert_status, auth_user, MockTaskRepo, MockUserRepo,
+    };
+
+    fn query(format: Option<&str>) -> Query<ExportQuery> {
+        Query(ExportQuery {
+            format: format.map(str::to_string),
+        })
+    }
+
+    #[tokio::test]
+    async fn streams_csv_with_header() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+
+        let response = handler(State(state), Extension(auth_user()), query(Some("csv")))
+            .await
+            .unwrap_or_else(|_| panic!("export failed"))
+            .into_response();
+
+        assert_eq!(response.headers()[CONTENT_TYPE], CSV_CONTENT_TYPE);
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        let body = String::from_utf8(body.to_vec()).expect("utf8");
+        assert!(body.starts_with("task_id,content,status,"));
+        assert!(body.contains("task-1,sample task,PENDING,"));
+    }
+
+    #[tokio::test]
+    async fn streams_a_json_array() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+
+        let response = handler(State(state), Extension(auth_user()), query(None))
+            .await
+            .unwrap_or_else(|_| panic!("export failed"))
+            .into_response();
+
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        let tasks: Vec<serde_json::Value> = serde_json::from_slice(&body).expect("json array");
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0]["content"], "sample task");
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_for_unknown_format() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+
+        let result = handler(State(state), Extension(auth_user()), query(Some("xml"))).await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn returns_internal_server_error_on_repo_failure() {
+        let state = app_state(
+            MockTaskRepo::with_list_result(Err(AppError::database("db error"))),
+            MockUserRepo::default(),
+        );
+
+        let result = handler(State(state), Extension(auth_user()), query(Some("todotxt"))).await;
+
+        assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -1,4 +1,5 @@
 use axum::extract::{Path, State};
+use axum::http::HeaderMap;
 use axum::response::IntoResponse;
 use axum::Json;
 
@@ -6,6 +7,7 @@ use application::task_service;
 use domain::error::AppError;
 use domain::task::inputs::GetTaskInput;
 
+use crate::etag::{if_none_match, not_modified, version_etag, with_etag};
 use crate::middleware::cognito_auth::AuthUser;
 use crate::response::{from_app_error, ErrorResponse};
 use crate::routes::tasks::types::TaskResponse;
@@ -15,6 +17,7 @@ pub async fn handler(
     State(state): State<AppState>,
     axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
     Path(task_id): Path<String>,
+    headers: HeaderMap,
 ) -> Result<impl IntoResponse, ErrorResponse> {
     let task = task_service::get_task::get_task(
         state.task_repo.as_ref(),
@@ -27,13 +30,19 @@ pub async fn handler(
     .map_err(from_app_error)?
     .ok_or_else(|| from_app_error(AppError::not_found("Task", "Task not found")))?;
 
-    Ok(Json(TaskResponse::from(task)))
+    let etag = version_etag(i64::from(task.version));
+    if if_none_match(&headers, &etag) {
+        return Ok(not_modified(&etag));
+    }
+
+    Ok(with_etag(&etag, Json(TaskResponse::from(task))))
 }
 
 #[cfg(test)]
 mod tests {
     use axum::extract::{Extension, Path, State};
-    use axum::http::StatusCode;
+    use axum::http::header::IF_NONE_MATCH;
+    use axum::http::{HeaderMap, HeaderValue, StatusCode};
     use domain::error::AppError;
 
     use super::handler;
@@ -49,12 +58,30 @@ mod tests {
             State(state),
             Extension(auth_user()),
             Path("task-1".to_string()),
+            HeaderMap::new(),
         )
         .await;
 
         assert_status(result, StatusCode::OK);
     }
 
+    #[tokio::test]
+    async fn returns_not_modified_when_etag_matches() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let mut headers = HeaderMap::new();
+        headers.insert(IF_NONE_MATCH, HeaderValue::from_static("\"0\""));
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            headers,
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_MODIFIED);
+    }
+
     #[tokio::test]
     async fn returns_not_found_when_missing() {
         let state = app_state(
@@ -66,6 +93,7 @@ mod tests {
             State(state),
             Extension(auth_user()),
             Path("task-1".to_string()),
+            HeaderMap::new(),
         )
         .await;
 
@@ -83,6 +111,7 @@ mod tests {
             State(state),
             Extension(auth_user()),
             Path("task-1".to_string()),
+            HeaderMap::new(),
         )
         .await;
 
@@ -0,0 +1,306 @@
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Json;
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+
+use application::task_service;
+use domain::task::import::{ImportLineError, ImportTask};
+use domain::task::inputs::ImportTasksInput;
+use domain::task::todo_txt::parse_todo_txt;
+
+use crate::csv::parse_csv;
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, validation_error, ErrorResponse};
+use crate::routes::tasks::types::{
+    parse_transfer_format, ImportQuery, ImportResponse, TaskRecord, TransferFormat,
+};
+use crate::AppState;
+
+type ImportRow = Result<ImportTask, ImportLineError>;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Query(params): Query<ImportQuery>,
+    body: String,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let rows = match parse_transfer_format(params.format.as_deref())? {
+        TransferFormat::Csv => csv_rows(&body)?,
+        TransferFormat::Json => json_rows(&body)?,
+        TransferFormat::TodoTxt => todo_txt_rows(&body),
+    };
+
+    let summary = task_service::import_tasks::import_tasks(
+        state.task_repo.as_ref(),
+        ImportTasksInput {
+            user_id: user.user_id,
+            rows,
+            dry_run: params.dry_run.unwrap_or(false),
+            max_content_chars: state.max_content_chars,
+            now: Utc::now(),
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    // Batches committed before a failure stay imported, so the summary is
+    // returned with the error status rather than replaced by it.
+    let status = if summary.failure.is_some() {
+        StatusCode::INTERNAL_SERVER_ERROR
+    } else {
+        StatusCode::OK
+    };
+
+    Ok((status, Json(ImportResponse::from(summary))))
+}
+
+fn csv_rows(body: &str) -> Result<Vec<ImportRow>, ErrorResponse> {
+    let records = parse_csv(body).map_err(|line| {
+        validation_error("invalid_csv", format!("Unterminated quote on line {line}"))
+    })?;
+    let mut records = records.into_iter();
+    let header: Vec<String> = records
+        .next()
+        .map(|record| {
+            record
+                .fields
+                .iter()
+                .map(|name| name.trim().to_lowercase())
+                .collect()
+        })
+        .unwrap_or_default();
+    if !header.iter().any(|name| name == "content") {
+        return Err(validation_error(
+            "invalid_csv",
+            "The first CSV row must be a header with a content column",
+        ));
+    }
+
+    Ok(records
+        .map(|record| {
+            let field = |name: &str| {
+                header
+                    .iter()
+                    .position(|column| column == name)
+                    .and_then(|index| record.fields.get(index))
+                    .map(|value| value.trim())
+                    .filter(|value| !value.is_empty())
+                    .map(str::to_string)
+            };
+            let date = |name: &str| {
+                field(name)
+                    .map(|value| {
+                        parse_import_date(&value).ok_or_else(|| {
+                            ImportLineError::new(
+                                record.line,
+                                &format!("invalid_{name}"),
+                                format!("{name} must be an RFC 3339 timestamp or YYYY-MM-DD"),
+                            )
+                        })
+                    })
+                    .transpose()
+            };
+
+            Ok(TaskRecord {
+                content: field("content").unwrap_or_default(),
+                status: field("status"),
+                priority: field("priority"),
+                labels: field("labels")
+                    .map(|labels| {
+                        labels
+                            .split(',')
+                            .map(str::trim)
+                            .filter(|label| !label.is_empty())
+                            .map(str::to_string)
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                due_at: date("due_at")?,
+                completed_at: date("completed_at")?,
+                waiting_on: field("waiting_on"),
+                ..Default::default()
+            }
+            .into_import(record.line))
+        })
+        .collect())
+}
+
+// JSON rows are numbered by their position in the array.
+fn json_rows(body: &str) -> Result<Vec<ImportRow>, ErrorResponse> {
+    let values: Vec<serde_json::Value> = serde_json::from_str(body)
+        .map_err(|_| validation_error("invalid_json", "Body must be a JSON array of tasks"))?;
+
+    Ok(values
+        .into_iter()
+        .enumerate()
+        .map(|(index, value)| {
+            serde_json::from_value::<TaskRecord>(value)
+                .map(|record| record.into_import(index + 1))
+                .map_err(|error| ImportLineError::new(index + 1, "invalid_row", error.to_string()))
+        })
+        .collect())
+}
+
+fn todo_txt_rows(body: &str) -> Vec<ImportRow> {
+    body.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            parse_todo_txt(index + 1, line)
+                .map_err(|error| ImportLineError::from_error(index + 1, error))
+        })
+        .collect()
+}
+
+fn parse_import_date(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|at| at.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .map(|date| date.and_time(NaiveTime::MIN).and_utc())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::to_bytes;
+    use axum::extract::{Extension, Query, State};
+    use axum::http::StatusCode;
+    use axum::response::IntoResponse;
+    use domain::error::AppError;
+    use domain::task::import::IMPORT_BATCH_SIZE;
+
+    use super::handler;
+    use crate::routes::tasks::types::ImportQuery;
+    use crate::routes::test_support::{
+        app_state, assert_status, auth_user, MockTaskRepo, MockUserRepo,
+    };
+
+    fn query(format: &str, dry_run: bool) -> Query<ImportQuery> {
+        Query(ImportQuery {
+            format: Some(format.to_string()),
+            dry_run: Some(dry_run),
+        })
+    }
+
+    async fn summary(format: &str, dry_run: bool, body: &str) -> serde_json::Value {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let response = handler(
+            State(state),
+            Extension(auth_user()),
+            query(format, dry_run),
+            body.to_string(),
+        )
+        .await
+        .unwrap_or_else(|_| panic!("import failed"))
+        .into_response();
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        serde_json::from_slice(&body).expect("json")
+    }
+
+    #[tokio::test]
+    async fn reports_csv_errors_by_line() {
+        let body = "content,status,due_at\nPay rent,pending,2025-03-05\n,pending,\nFile taxes,done,\nCall bank,,soon\n";
+
+        let summary = summary("csv", false, body).await;
+
+        assert_eq!(summary["total"], 4);
+        assert_eq!(summary["imported"], 1);
+        let errors: Vec<(u64, &str)> = summary["errors"]
+            .as_array()
+            .expect("errors")
+            .iter()
+            .map(|error| {
+                (
+                    error["line"].as_u64().expect("line"),
+                    error["code"].as_str().expect("code"),
+                )
+            })
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                (3, "invalid_content"),
+                (4, "invalid_status"),
+                (5, "invalid_due_at")
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn dry_run_validates_without_importing() {
+        let summary = summary("todotxt", true, "(A) Pay rent +home\n\nx File taxes\n").await;
+
+        assert_eq!(summary["dry_run"], true);
+        assert_eq!(summary["valid"], 2);
+        assert_eq!(summary["imported"], 0);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_for_malformed_json() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            query("json", false),
+            "{\"content\": \"not an array\"}".to_string(),
+        )
+        .await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn reports_batches_imported_before_a_failure() {
+        let rows: Vec<serde_json::Value> = (0..IMPORT_BATCH_SIZE + 1)
+            .map(|index| serde_json::json!({ "content": format!("Task {index}") }))
+            .collect();
+        let state = app_state(
+            MockTaskRepo::with_create_many_result_after(1, Err(AppError::database("db error"))),
+            MockUserRepo::default(),
+        );
+
+        let response = handler(
+            State(state),
+            Extension(auth_user()),
+            query("json", false),
+            serde_json::Value::from(rows).to_string(),
+        )
+        .await
+        .unwrap_or_else(|_| panic!("import failed"))
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        let summary: serde_json::Value = serde_json::from_slice(&body).expect("json");
+        assert_eq!(summary["valid"], IMPORT_BATCH_SIZE + 1);
+        assert_eq!(summary["imported"], IMPORT_BATCH_SIZE);
+        assert_eq!(summary["failure"]["code"], "import_failed");
+    }
+
+    #[tokio::test]
+    async fn returns_internal_server_error_on_repo_failure() {
+        let state = app_state(
+            MockTaskRepo::with_create_many_result(Err(AppError::database("db error"))),
+            MockUserRepo::default(),
+        );
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            query("json", false),
+            "[{\"content\": \"Pay rent\"}]".to_string(),
+        )
+        .await;
+
+        assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -1,9 +1,10 @@
 use axum::extract::{Query, State};
 use axum::response::IntoResponse;
 use axum::Json;
+use chrono::Utc;
 
 use application::task_service;
-use domain::task::inputs::ListTasksInput;
+use domain::task::inputs::FilterTasksInput;
 
 use crate::middleware::cognito_auth::AuthUser;
 use crate::response::{from_app_error, ErrorResponse};
@@ -18,12 +19,15 @@ pub async fn handler(
     let page = params.page.unwrap_or(1).max(1);
     let limit = params.limit.unwrap_or(20).clamp(1, 100);
 
-    let tasks = task_service::list_tasks::list_tasks(
+    let tasks = task_service::filter_tasks::filter_tasks(
         state.task_repo.as_ref(),
-        ListTasksInput {
+        state.user_repo.as_ref(),
+        FilterTasksInput {
             user_id: user.user_id,
             page,
             limit,
+            filter: params.filter(),
+            now: Utc::now(),
         },
     )
     .await
@@ -56,6 +60,14 @@ mod tests {
         let params = Pagination {
             page: Some(1),
             limit: Some(10),
+            sort: None,
+            assigned_to: None,
+            include_snoozed: None,
+            archived: None,
+            q: None,
+            status: None,
+            labels: None,
+            due: None,
         };
 
         let result = handler(State(state), Extension(auth_user()), Query(params)).await;
@@ -63,6 +75,132 @@ mod tests {
         assert_status(result, StatusCode::OK);
     }
 
+    #[tokio::test]
+    async fn returns_ok_for_manual_sort() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let params = Pagination {
+            page: None,
+            limit: None,
+            sort: Some("manual".to_string()),
+            assigned_to: None,
+            include_snoozed: None,
+            archived: None,
+            q: None,
+            status: None,
+            labels: None,
+            due: None,
+        };
+
+        let result = handler(State(state), Extension(auth_user()), Query(params)).await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_ok_for_assigned_to_me() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let params = Pagination {
+            page: None,
+            limit: None,
+            sort: None,
+            assigned_to: Some("me".to_string()),
+            include_snoozed: None,
+            archived: None,
+            q: None,
+            status: None,
+            labels: None,
+            due: None,
+        };
+
+        let result = handler(State(state), Extension(auth_user()), Query(params)).await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_ok_when_including_snoozed() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let params = Pagination {
+            page: None,
+            limit: None,
+            sort: None,
+            assigned_to: None,
+            include_snoozed: Some(true),
+            archived: None,
+            q: None,
+            status: None,
+            labels: None,
+            due: None,
+        };
+
+        let result = handler(State(state), Extension(auth_user()), Query(params)).await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_ok_when_searching_archived() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let params = Pagination {
+            page: None,
+            limit: None,
+            sort: None,
+            assigned_to: None,
+            include_snoozed: None,
+            archived: Some("only".to_string()),
+            q: Some("rent".to_string()),
+            status: None,
+            labels: None,
+            due: None,
+        };
+
+        let result = handler(State(state), Extension(auth_user()), Query(params)).await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_for_unknown_archived_filter() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let params = Pagination {
+            page: None,
+            limit: None,
+            sort: None,
+            assigned_to: None,
+            include_snoozed: None,
+            archived: Some("sometimes".to_string()),
+            q: None,
+            status: None,
+            labels: None,
+            due: None,
+        };
+
+        let result = handler(State(state), Extension(auth_user()), Query(params)).await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_for_unknown_sort() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let params = Pagination {
+            page: None,
+            limit: None,
+            sort: Some("priority".to_string()),
+            assigned_to: None,
+            include_snoozed: None,
+            archived: None,
+            q: None,
+            status: None,
+            labels: None,
+            due: None,
+        };
+
+        let result = handler(State(state), Extension(auth_user()), Query(params)).await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
     #[tokio::test]
     async fn returns_internal_server_error_on_repo_failure() {
         let state = app_state(
@@ -72,10 +210,60 @@ mod tests {
         let params = Pagination {
             page: None,
             limit: None,
+            sort: None,
+            assigned_to: None,
+            include_snoozed: None,
+            archived: None,
+            q: None,
+            status: None,
+            labels: None,
+            due: None,
         };
 
         let result = handler(State(state), Extension(auth_user()), Query(params)).await;
 
         assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
     }
+
+    #[tokio::test]
+    async fn returns_ok_for_labels_status_and_due_filters() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let params = Pagination {
+            page: None,
+            limit: None,
+            sort: None,
+            assigned_to: None,
+            include_snoozed: None,
+            archived: None,
+            q: None,
+            status: Some("pending,in_progress".to_string()),
+            labels: Some("home".to_string()),
+            due: Some("next 7 days".to_string()),
+        };
+
+        let result = handler(State(state), Extension(auth_user()), Query(params)).await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_for_unknown_due_filter() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let params = Pagination {
+            page: None,
+            limit: None,
+            sort: None,
+            assigned_to: None,
+            include_snoozed: None,
+            archived: None,
+            q: None,
+            status: None,
+            labels: None,
+            due: Some("someday".to_string()),
+        };
+
+        let result = handler(State(state), Extension(auth_user()), Query(params)).await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
 }
@@ -0,0 +1,100 @@
+use axum::extract::{Query, State};
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::task_service;
+use domain::task::filter::TaskQuery;
+use domain::task::inputs::{ListTasksInput, SnoozeFilter};
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::tasks::types::{Pagination, TaskListResponse, TaskResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Query(params): Query<Pagination>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let page = params.page.unwrap_or(1).max(1);
+    let limit = params.limit.unwrap_or(20).clamp(1, 100);
+
+    let tasks = task_service::list_tasks::list_tasks(
+        state.task_repo.as_ref(),
+        ListTasksInput {
+            user_id: user.user_id.clone(),
+            page,
+            limit,
+            query: TaskQuery {
+                assigned_to: params.assigned_to.map(|assignee| {
+                    if assignee == "me" {
+                        user.user_id.clone()
+                    } else {
+                        assignee
+                    }
+                }),
+                snoozed: SnoozeFilter::Only,
+                ..Default::default()
+            },
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    let response = TaskListResponse {
+        tasks: tasks.into_iter().map(TaskResponse::from).collect(),
+        page,
+        limit,
+    };
+
+    Ok(Json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Query, State};
+    use axum::http::StatusCode;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::tasks::types::Pagination;
+    use crate::routes::test_support::{
+        app_state, assert_status, auth_user, MockTaskRepo, MockUserRepo,
+    };
+
+    fn params() -> Pagination {
+        Pagination {
+            page: None,
+            limit: None,
+            sort: None,
+            assigned_to: None,
+            include_snoozed: None,
+            archived: None,
+            q: None,
+            status: None,
+            labels: None,
+            due: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn returns_ok_on_success() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+
+        let result = handler(State(state), Extension(auth_user()), Query(params())).await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_internal_server_error_on_repo_failure() {
+        let state = app_state(
+            MockTaskRepo::with_list_result(Err(AppError::database("db error"))),
+            MockUserRepo::default(),
+        );
+
+        let result = handler(State(state), Extension(auth_user()), Query(params())).await;
+
+        assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,159 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+
+use application::task_service;
+use domain::task::inputs::MergeTaskInput;
+use domain::task::merge::TaskMerge;
+
+use crate::etag::{version_etag, with_etag};
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::tasks::types::{
+    parse_granularity, MergeConflictResponse, MergeTaskRequest, TaskResponse,
+};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(task_id): Path<String>,
+    Json(body): Json<MergeTaskRequest>,
+) -> Result<Response, ErrorResponse> {
+    let granularity = parse_granularity(body.granularity.as_deref())?;
+
+    let merge = task_service::merge_task::merge_task(
+        state.task_repo.as_ref(),
+        MergeTaskInput {
+            user_id: user.user_id,
+            task_id,
+            base_version: body.base_version,
+            content: body.content,
+            granularity,
+            max_content_chars: state.max_content_chars,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    match merge {
+        TaskMerge::Merged(task) => {
+            let etag = version_etag(i64::from(task.version));
+            Ok(with_etag(&etag, Json(TaskResponse::from(*task))))
+        }
+        TaskMerge::Conflict(conflict) => Ok((
+            StatusCode::CONFLICT,
+            Json(MergeConflictResponse::from(conflict)),
+        )
+            .into_response()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use axum::Json;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::tasks::types::MergeTaskRequest;
+    use crate::routes::test_support::{
+        app_state, assert_status, auth_user, sample_revision, MockTaskRepo, MockUserRepo,
+    };
+
+    fn body(base_version: i32, content: &str, granularity: Option<&str>) -> Json<MergeTaskRequest> {
+        Json(MergeTaskRequest {
+            base_version,
+            content: content.to_string(),
+            granularity: granularity.map(str::to_string),
+        })
+    }
+
+    #[tokio::test]
+    async fn applies_a_clean_merge() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            body(3, "sample task, edited offline", Some("word")),
+        )
+        .await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_conflict_with_hunks() {
+        let mut revision = sample_revision("user-123", "task-1", 3);
+        revision.snapshot.content = "Pay rent\n".to_string();
+        let state = app_state(
+            MockTaskRepo::with_get_revision_result(Ok(Some(revision))),
+            MockUserRepo::default(),
+        );
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            body(3, "Pay rent today\n", None),
+        )
+        .await;
+
+        assert_status(result, StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_for_unknown_granularity() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            body(0, "sample task", Some("char")),
+        )
+        .await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_for_unknown_base_version() {
+        let state = app_state(
+            MockTaskRepo::with_get_revision_result(Ok(None)),
+            MockUserRepo::default(),
+        );
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            body(7, "edited", None),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn returns_internal_server_error_on_repo_failure() {
+        let state = app_state(
+            MockTaskRepo::with_get_result(Err(AppError::database("db error"))),
+            MockUserRepo::default(),
+        );
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            body(0, "edited", None),
+        )
+        .await;
+
+        assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
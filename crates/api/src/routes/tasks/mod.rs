@@ -1,6 +1,29 @@
+pub mod add_member;
+pub mod archive;
+pub mod complete_member;
+pub mod content;
 pub mod delete;
+pub mod duplicate;
+pub mod export;
 pub mod get;
+pub mod history;
+pub mod import;
 pub mod list;
+pub mod list_snoozed;
+pub mod merge;
+pub mod move_task;
+pub mod next;
+pub mod patch;
 pub mod post;
 pub mod put;
+pub mod quick_add;
+pub mod quick_add_preview;
+pub mod reassign;
+pub mod remove_member;
+pub mod reopen_member;
+pub mod revert;
+pub mod rollup;
+pub mod transfer;
+pub mod transfer_many;
 pub mod types;
+pub mod unarchive;
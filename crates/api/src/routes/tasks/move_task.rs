@@ -0,0 +1,109 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::task_service;
+use domain::task::inputs::MoveTaskInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::tasks::types::{MoveTaskRequest, TaskResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(task_id): Path<String>,
+    Json(body): Json<MoveTaskRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let task = task_service::move_task::move_task(
+        state.task_repo.as_ref(),
+        MoveTaskInput {
+            user_id: user.user_id,
+            task_id,
+            before: body.before,
+            after: body.after,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(Json(TaskResponse::from(task)))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use axum::Json;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::tasks::types::MoveTaskRequest;
+    use crate::routes::test_support::{
+        app_state, assert_status, auth_user, MockTaskRepo, MockUserRepo,
+    };
+
+    #[tokio::test]
+    async fn returns_bad_request_without_neighbors() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let body = MoveTaskRequest {
+            before: None,
+            after: None,
+        };
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            Json(body),
+        )
+        .await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn returns_ok_on_success() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let body = MoveTaskRequest {
+            before: Some("task-2".to_string()),
+            after: None,
+        };
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            Json(body),
+        )
+        .await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_when_neighbor_missing() {
+        let state = app_state(
+            MockTaskRepo::with_move_result(Err(AppError::not_found(
+                "Task",
+                "Neighbor task not found",
+            ))),
+            MockUserRepo::default(),
+        );
+        let body = MoveTaskRequest {
+            before: None,
+            after: Some("task-9".to_string()),
+        };
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            Json(body),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,211 @@
+use axum::extract::{Query, State};
+use axum::response::IntoResponse;
+use axum::Json;
+use chrono::Utc;
+
+use application::task_service;
+use domain::error::AppError;
+use domain::task::inputs::NextTasksInput;
+use domain::task::next::{DEFAULT_NEXT_LIMIT, MAX_NEXT_LIMIT};
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::tasks::types::{NextTaskResponse, NextTasksQuery, NextTasksResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Query(params): Query<NextTasksQuery>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let limit = params.limit.unwrap_or(DEFAULT_NEXT_LIMIT);
+    if !(1..=MAX_NEXT_LIMIT).contains(&limit) {
+        return Err(from_app_error(AppError::validation(
+            "invalid_limit",
+            format!("Limit must be between 1 and {}", MAX_NEXT_LIMIT),
+            Some("limit".to_string()),
+        )));
+    }
+
+    let tasks = task_service::next_tasks::next_tasks(
+        state.task_repo.as_ref(),
+        NextTasksInput {
+            user_id: user.user_id,
+            limit,
+            weights: params.weights(),
+            now: Utc::now(),
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(Json(NextTasksResponse {
+        tasks: tasks.into_iter().map(NextTaskResponse::from).collect(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use application::task_service::next_tasks::rank_tasks;
+    use axum::extract::{Extension, Query, State};
+    use axum::http::StatusCode;
+    use axum::response::IntoResponse;
+    use chrono::{Duration, Utc};
+    use domain::error::AppError;
+    use domain::task::next::{ScoreFactor, ScoreWeights};
+    use domain::task::priority::TaskPriority;
+    use domain::task::status::TaskStatus;
+
+    use super::handler;
+    use crate::routes::tasks::types::NextTasksQuery;
+    use crate::routes::test_support::{
+        app_state, assert_status, auth_user, sample_task, MockTaskRepo, MockUserRepo,
+    };
+
+    #[test]
+    fn ranks_overdue_urgent_work_above_snoozed_and_blocked_tasks() {
+        let now = Utc::now();
+        let mut urgent = sample_task("user-1", "urgent");
+        urgent.priority = Some(TaskPriority::Urgent);
+        urgent.due_at = Some(now - Duration::days(2));
+        let mut snoozed = sample_task("user-1", "snoozed");
+        snoozed.priority = Some(TaskPriority::Urgent);
+        snoozed.snoozed_until = Some(now + Duration::days(1));
+        let mut parent = sample_task("user-1", "parent");
+        parent.priority = Some(TaskPriority::High);
+        let mut child = sample_task("user-1", "child");
+        child.parent_task_id = Some("parent".to_string());
+        let mut done = sample_task("user-1", "done-child");
+        done.parent_task_id = Some("child".to_string());
+        done.status = TaskStatus::Completed;
+
+        let ranked = rank_tasks(
+            vec![snoozed, parent, child, urgent, done],
+            &ScoreWeights::default(),
+            now,
+        );
+
+        let order: Vec<&str> = ranked
+            .iter()
+            .map(|scored| scored.task.task_id.as_str())
+            .collect();
+        assert_eq!(
+            order,
+            vec!["urgent", "child", "done-child", "parent", "snoozed"]
+        );
+        let urgent = &ranked[0];
+        assert_eq!(urgent.score, 7.0);
+        assert_eq!(urgent.reasons[1].factor, ScoreFactor::Due);
+        assert_eq!(urgent.reasons[1].detail, "Overdue by 2 days");
+        let parent = &ranked[3];
+        assert_eq!(parent.reasons[1].factor, ScoreFactor::Blocked);
+        assert_eq!(parent.reasons[1].detail, "Blocked by 1 open subtask");
+        assert_eq!(ranked[4].reasons[1].factor, ScoreFactor::Snoozed);
+    }
+
+    #[test]
+    fn zero_weights_drop_their_reasons() {
+        let now = Utc::now();
+        let mut task = sample_task("user-1", "task-1");
+        task.priority = Some(TaskPriority::High);
+        task.waiting_on = Some("legal".to_string());
+        task.created_at = now - Duration::days(60);
+        let weights = ScoreWeights {
+            blocked: 0.0,
+            ..ScoreWeights::default()
+        };
+
+        let ranked = rank_tasks(vec![task], &weights, now);
+
+        let factors: Vec<ScoreFactor> = ranked[0]
+            .reasons
+            .iter()
+            .map(|reason| reason.factor)
+            .collect();
+        assert_eq!(factors, vec![ScoreFactor::Priority, ScoreFactor::Age]);
+        assert_eq!(ranked[0].score, 3.25);
+    }
+
+    #[tokio::test]
+    async fn returns_scored_tasks_with_reasons() {
+        let mut urgent = sample_task("user-1", "task-2");
+        urgent.priority = Some(TaskPriority::Urgent);
+        let state = app_state(
+            MockTaskRepo::with_list_result(Ok(vec![sample_task("user-1", "task-1"), urgent])),
+            MockUserRepo::default(),
+        );
+
+        let response = handler(
+            State(state),
+            Extension(auth_user()),
+            Query(NextTasksQuery {
+                limit: Some(1),
+                ..Default::default()
+            }),
+        )
+        .await
+        .unwrap_or_else(|_| panic!("next failed"))
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json");
+        assert_eq!(json["tasks"].as_array().map(Vec::len), Some(1));
+        assert_eq!(json["tasks"][0]["task"]["task_id"], "task-2");
+        assert_eq!(json["tasks"][0]["reasons"][0]["factor"], "priority");
+        assert_eq!(json["tasks"][0]["reasons"][0]["detail"], "Urgent priority");
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_for_out_of_range_limit() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Query(NextTasksQuery {
+                limit: Some(0),
+                ..Default::default()
+            }),
+        )
+        .await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_for_negative_weight() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Query(NextTasksQuery {
+                snoozed_weight: Some(-1.0),
+                ..Default::default()
+            }),
+        )
+        .await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn returns_internal_server_error_on_repo_failure() {
+        let state = app_state(
+            MockTaskRepo::with_list_result(Err(AppError::database("db error"))),
+            MockUserRepo::default(),
+        );
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Query(NextTasksQuery::default()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,192 @@
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::task_service;
+use domain::task::inputs::PatchTaskInput;
+
+use crate::etag::{from_conditional_error, if_match, precondition_failed, version_etag, with_etag};
+use crate::middleware::cognito_auth::AuthUser;
+use crate::patch::{parse_patch, patch_format};
+use crate::response::ErrorResponse;
+use crate::routes::tasks::types::TaskResponse;
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(task_id): Path<String>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let format = patch_format(&headers)?;
+    let patch = parse_patch(&body)?;
+    let if_match = if_match(&headers)?;
+    let version = if_match
+        .map(|token| i32::try_from(token).map_err(|_| precondition_failed()))
+        .transpose()?;
+
+    let task = task_service::patch_task::patch_task(
+        state.task_repo.as_ref(),
+        PatchTaskInput {
+            user_id: user.user_id,
+            task_id,
+            format,
+            patch,
+            version,
+            max_content_chars: state.max_content_chars,
+        },
+    )
+    .await
+    .map_err(|error| from_conditional_error(error, if_match.is_some()))?;
+
+    let etag = version_etag(i64::from(task.version));
+    Ok(with_etag(&etag, Json(TaskResponse::from(task))))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Bytes;
+    use axum::extract::{Extension, Path, State};
+    use axum::http::header::{CONTENT_TYPE, IF_MATCH};
+    use axum::http::{HeaderMap, HeaderValue, StatusCode};
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::patch::{JSON_PATCH_CONTENT_TYPE, MERGE_PATCH_CONTENT_TYPE};
+    use crate::routes::test_support::{
+        app_state, assert_status, auth_user, MockTaskRepo, MockUserRepo,
+    };
+
+    fn headers(content_type: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+        headers
+    }
+
+    #[tokio::test]
+    async fn returns_ok_for_merge_patch() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let body = Bytes::from_static(br#"{"content":"patched","completed_at":null}"#);
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            headers(MERGE_PATCH_CONTENT_TYPE),
+            body,
+        )
+        .await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_ok_for_json_patch() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let body = Bytes::from_static(br#"[{"op":"replace","path":"/content","value":"patched"}]"#);
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            headers(JSON_PATCH_CONTENT_TYPE),
+            body,
+        )
+        .await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_unsupported_media_type_for_plain_json() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let body = Bytes::from_static(br#"{"content":"patched"}"#);
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            headers("application/json"),
+            body,
+        )
+        .await;
+
+        assert_status(result, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_when_patched_task_is_invalid() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let body = Bytes::from_static(br#"[{"op":"remove","path":"/content"}]"#);
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            headers(JSON_PATCH_CONTENT_TYPE),
+            body,
+        )
+        .await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_for_unknown_field() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let body = Bytes::from_static(br#"{"title":"patched"}"#);
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            headers(MERGE_PATCH_CONTENT_TYPE),
+            body,
+        )
+        .await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn returns_precondition_failed_when_if_match_is_stale() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let mut headers = headers(MERGE_PATCH_CONTENT_TYPE);
+        headers.insert(IF_MATCH, HeaderValue::from_static("\"5\""));
+        let body = Bytes::from_static(br#"{"content":"patched"}"#);
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            headers,
+            body,
+        )
+        .await;
+
+        assert_status(result, StatusCode::PRECONDITION_FAILED);
+    }
+
+    #[tokio::test]
+    async fn returns_internal_server_error_on_repo_failure() {
+        let state = app_state(
+            MockTaskRepo::with_update_result(Err(AppError::database("db error"))),
+            MockUserRepo::default(),
+        );
+        let body = Bytes::from_static(br#"{"content":"patched"}"#);
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            headers(MERGE_PATCH_CONTENT_TYPE),
+            body,
+        )
+        .await;
+
+        assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -3,11 +3,10 @@ use axum::response::IntoResponse;
 use axum::Json;
 
 use application::task_service;
-use domain::task::inputs::CreateTaskInput;
 
 use crate::middleware::cognito_auth::AuthUser;
-use crate::response::{from_app_error, validation_error, ErrorResponse};
-use crate::routes::tasks::types::{parse_status, CreateTaskRequest, TaskResponse};
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::tasks::types::{CreateTaskRequest, TaskResponse};
 use crate::AppState;
 
 pub async fn handler(
@@ -15,31 +14,10 @@ pub async fn handler(
     axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
     Json(body): Json<CreateTaskRequest>,
 ) -> Result<impl IntoResponse, ErrorResponse> {
-    if body.content.trim().is_empty() || body.content.len() > 1000 {
-        return Err(validation_error(
-            "invalid_content",
-            "Content must be 1-1000 characters",
-        ));
-    }
-
-    if let Some(status) = &body.status {
-        if parse_status(status).is_err() {
-            return Err(validation_error(
-                "invalid_status",
-                "Status must be PENDING, IN_PROGRESS, or COMPLETED",
-            ));
-        }
-    }
-
-    let task = task_service::create_task::create_task(
-        state.task_repo.as_ref(),
-        CreateTaskInput {
-            user_id: user.user_id,
-            content: body.content,
-        },
-    )
-    .await
-    .map_err(from_app_error)?;
+    let input = body.into_input(user.user_id, state.max_content_chars)?;
+    let task = task_service::create_task::create_task(state.task_repo.as_ref(), input)
+        .await
+        .map_err(from_app_error)?;
 
     Ok(Json(TaskResponse::from(task)))
 }
@@ -56,6 +34,7 @@ mod tests {
     use crate::routes::test_support::{
         app_state, assert_status, auth_user, MockTaskRepo, MockUserRepo,
     };
+    use crate::AppState;
 
     #[tokio::test]
     async fn returns_bad_request_for_invalid_content() {
@@ -63,6 +42,7 @@ mod tests {
         let body = CreateTaskRequest {
             content: " ".to_string(),
             status: None,
+            ..Default::default()
         };
 
         let result = handler(State(state), Extension(auth_user()), Json(body)).await;
@@ -70,12 +50,41 @@ mod tests {
         assert_status(result, StatusCode::BAD_REQUEST);
     }
 
+    #[tokio::test]
+    async fn counts_content_length_in_characters() {
+        let state = AppState {
+            max_content_chars: 10,
+            ..app_state(MockTaskRepo::default(), MockUserRepo::default())
+        };
+        let body = |content: &str| CreateTaskRequest {
+            content: content.to_string(),
+            ..Default::default()
+        };
+
+        let result = handler(
+            State(state.clone()),
+            Extension(auth_user()),
+            Json(body(&"é".repeat(10))),
+        )
+        .await;
+        assert_status(result, StatusCode::OK);
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Json(body(&"é".repeat(11))),
+        )
+        .await;
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
     #[tokio::test]
     async fn returns_bad_request_for_invalid_status() {
         let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
         let body = CreateTaskRequest {
             content: "task".to_string(),
             status: Some("INVALID".to_string()),
+            ..Default::default()
         };
 
         let result = handler(State(state), Extension(auth_user()), Json(body)).await;
@@ -89,6 +98,7 @@ mod tests {
         let body = CreateTaskRequest {
             content: "task".to_string(),
             status: None,
+            ..Default::default()
         };
 
         let result = handler(State(state), Extension(auth_user()), Json(body)).await;
@@ -105,6 +115,7 @@ mod tests {
         let body = CreateTaskRequest {
             content: "task".to_string(),
             status: None,
+            ..Default::default()
         };
 
         let result = handler(State(state), Extension(auth_user()), Json(body)).await;
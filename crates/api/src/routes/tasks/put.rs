@@ -1,72 +1,47 @@
 use axum::extract::{Path, State};
-use axum::http::StatusCode;
+use axum::http::HeaderMap;
 use axum::response::IntoResponse;
 use axum::Json;
 
 use application::task_service;
-use domain::task::inputs::UpdateTaskInput;
 
+use crate::etag::{
+    from_conditional_error, if_match, precondition_failed, precondition_required, version_etag,
+    with_etag,
+};
 use crate::middleware::cognito_auth::AuthUser;
-use crate::response::{from_app_error, validation_error, ErrorResponse};
-use crate::routes::tasks::types::{parse_status, CountResponse, TaskStatus, UpdateTaskRequest};
+use crate::response::ErrorResponse;
+use crate::routes::tasks::types::{TaskResponse, UpdateTaskRequest};
 use crate::AppState;
 
 pub async fn handler(
     State(state): State<AppState>,
     axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
     Path(task_id): Path<String>,
+    headers: HeaderMap,
     Json(body): Json<UpdateTaskRequest>,
 ) -> Result<impl IntoResponse, ErrorResponse> {
-    if body.content.is_none() && body.status.is_none() {
-        return Err(validation_error(
-            "invalid_body",
-            "At least one field (content or status) must be provided",
-        ));
-    }
-
-    if let Some(content) = &body.content {
-        if content.trim().is_empty() || content.len() > 1000 {
-            return Err(validation_error(
-                "invalid_content",
-                "Content must be 1-1000 characters",
-            ));
-        }
-    }
-
-    let completed_at = match body.status.as_deref() {
-        Some(status) => match parse_status(status) {
-            Ok(TaskStatus::Completed) => Some(Some(chrono::Utc::now())),
-            Ok(_) => Some(None),
-            Err(_) => {
-                return Err(validation_error(
-                    "invalid_status",
-                    "Status must be PENDING, IN_PROGRESS, or COMPLETED",
-                ));
-            }
-        },
-        None => None,
+    let if_match = if_match(&headers)?;
+    let version = match (if_match, body.version) {
+        (Some(token), _) => i32::try_from(token).map_err(|_| precondition_failed())?,
+        (None, Some(version)) => version,
+        (None, None) => return Err(precondition_required()),
     };
 
-    let count = task_service::update_task::update_task(
-        state.task_repo.as_ref(),
-        UpdateTaskInput {
-            user_id: user.user_id,
-            task_id,
-            content: body.content,
-            completed_at,
-            version: body.version,
-        },
-    )
-    .await
-    .map_err(from_app_error)?;
-
-    Ok((StatusCode::OK, Json(CountResponse { count })))
+    let input = body.into_input(user.user_id, task_id, version, state.max_content_chars)?;
+    let task = task_service::update_task::update_task(state.task_repo.as_ref(), input)
+        .await
+        .map_err(|error| from_conditional_error(error, if_match.is_some()))?;
+
+    let etag = version_etag(i64::from(task.version));
+    Ok(with_etag(&etag, Json(TaskResponse::from(task))))
 }
 
 #[cfg(test)]
 mod tests {
     use axum::extract::{Extension, Path, State};
-    use axum::http::StatusCode;
+    use axum::http::header::IF_MATCH;
+    use axum::http::{HeaderMap, HeaderValue, StatusCode};
     use axum::Json;
     use domain::error::AppError;
 
@@ -82,13 +57,36 @@ mod tests {
         let body = UpdateTaskRequest {
             content: None,
             status: None,
-            version: 0,
+            version: Some(0),
+            ..Default::default()
+        };
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            HeaderMap::new(),
+            Json(body),
+        )
+        .await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_for_negative_estimate() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let body = UpdateTaskRequest {
+            estimate_minutes: Some(Some(-5)),
+            version: Some(0),
+            ..Default::default()
         };
 
         let result = handler(
             State(state),
             Extension(auth_user()),
             Path("task-1".to_string()),
+            HeaderMap::new(),
             Json(body),
         )
         .await;
@@ -102,13 +100,15 @@ mod tests {
         let body = UpdateTaskRequest {
             content: Some("updated".to_string()),
             status: None,
-            version: 1,
+            version: Some(1),
+            ..Default::default()
         };
 
         let result = handler(
             State(state),
             Extension(auth_user()),
             Path("task-1".to_string()),
+            HeaderMap::new(),
             Json(body),
         )
         .await;
@@ -116,6 +116,56 @@ mod tests {
         assert_status(result, StatusCode::OK);
     }
 
+    #[tokio::test]
+    async fn returns_not_found_when_missing() {
+        let state = app_state(
+            MockTaskRepo::with_update_result(Err(AppError::not_found("Task", "Task not found"))),
+            MockUserRepo::default(),
+        );
+        let body = UpdateTaskRequest {
+            content: Some("updated".to_string()),
+            status: None,
+            version: Some(1),
+            ..Default::default()
+        };
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            HeaderMap::new(),
+            Json(body),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn returns_conflict_on_version_mismatch() {
+        let state = app_state(
+            MockTaskRepo::with_update_result(Err(AppError::version_conflict("Task", 3))),
+            MockUserRepo::default(),
+        );
+        let body = UpdateTaskRequest {
+            content: Some("updated".to_string()),
+            status: None,
+            version: Some(1),
+            ..Default::default()
+        };
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            HeaderMap::new(),
+            Json(body),
+        )
+        .await;
+
+        assert_status(result, StatusCode::CONFLICT);
+    }
+
     #[tokio::test]
     async fn returns_internal_server_error_on_repo_failure() {
         let state = app_state(
@@ -125,17 +175,68 @@ mod tests {
         let body = UpdateTaskRequest {
             content: Some("updated".to_string()),
             status: None,
-            version: 1,
+            version: Some(1),
+            ..Default::default()
         };
 
         let result = handler(
             State(state),
             Extension(auth_user()),
             Path("task-1".to_string()),
+            HeaderMap::new(),
             Json(body),
         )
         .await;
 
         assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
     }
+
+    #[tokio::test]
+    async fn returns_precondition_required_without_version() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let body = UpdateTaskRequest {
+            content: Some("updated".to_string()),
+            status: None,
+            version: None,
+            ..Default::default()
+        };
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            HeaderMap::new(),
+            Json(body),
+        )
+        .await;
+
+        assert_status(result, StatusCode::PRECONDITION_REQUIRED);
+    }
+
+    #[tokio::test]
+    async fn returns_precondition_failed_when_if_match_is_stale() {
+        let state = app_state(
+            MockTaskRepo::with_update_result(Err(AppError::version_conflict("Task", 3))),
+            MockUserRepo::default(),
+        );
+        let mut headers = HeaderMap::new();
+        headers.insert(IF_MATCH, HeaderValue::from_static("\"1\""));
+        let body = UpdateTaskRequest {
+            content: Some("updated".to_string()),
+            status: None,
+            version: None,
+            ..Default::default()
+        };
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            headers,
+            Json(body),
+        )
+        .await;
+
+        assert_status(result, StatusCode::PRECONDITION_FAILED);
+    }
 }
@@ -0,0 +1,105 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+use chrono::Utc;
+
+use application::task_service;
+use domain::task::inputs::QuickAddTaskInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::tasks::types::{require_quick_action, QuickAddPreviewResponse, QuickAddRequest};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(action): Path<String>,
+    Json(body): Json<QuickAddRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    require_quick_action(&action)?;
+
+    let parsed = task_service::preview_quick_add::preview_quick_add(
+        state.user_repo.as_ref(),
+        &QuickAddTaskInput {
+            user_id: user.user_id,
+            text: body.text,
+            timezone: body.timezone,
+            now: Utc::now(),
+            max_content_chars: state.max_content_chars,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(Json(QuickAddPreviewResponse::from(parsed)))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use axum::Json;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::tasks::types::QuickAddRequest;
+    use crate::routes::test_support::{
+        app_state, assert_status, auth_user, MockTaskRepo, MockUserRepo,
+    };
+
+    fn body(text: &str) -> QuickAddRequest {
+        QuickAddRequest {
+            text: text.to_string(),
+            timezone: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn returns_ok_on_success() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path(":quick".to_string()),
+            Json(body("Pay rent tomorrow 9am #finance !high every month")),
+        )
+        .await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_for_empty_content() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path(":quick".to_string()),
+            Json(body("tomorrow #finance")),
+        )
+        .await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn returns_internal_server_error_on_repo_failure() {
+        let state = app_state(
+            MockTaskRepo::default(),
+            MockUserRepo::with_get_result(Err(AppError::database("db error"))),
+        );
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path(":quick".to_string()),
+            Json(body("Pay rent")),
+        )
+        .await;
+
+        assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,147 @@
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::task_service;
+use domain::task::inputs::ReassignTaskInput;
+
+use crate::etag::{
+    from_conditional_error, if_match, precondition_failed, precondition_required, version_etag,
+    with_etag,
+};
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::ErrorResponse;
+use crate::routes::tasks::types::{ReassignTaskRequest, TaskResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(task_id): Path<String>,
+    headers: HeaderMap,
+    Json(body): Json<ReassignTaskRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let if_match = if_match(&headers)?;
+    let version = match (if_match, body.version) {
+        (Some(token), _) => i32::try_from(token).map_err(|_| precondition_failed())?,
+        (None, Some(version)) => version,
+        (None, None) => return Err(precondition_required()),
+    };
+
+    let task = task_service::reassign_task::reassign_task(
+        state.task_repo.as_ref(),
+        ReassignTaskInput {
+            user_id: user.user_id,
+            task_id,
+            assignee_ids: body.assignee_ids,
+            version,
+        },
+    )
+    .await
+    .map_err(|error| from_conditional_error(error, if_match.is_some()))?;
+
+    let etag = version_etag(i64::from(task.version));
+    Ok(with_etag(&etag, Json(TaskResponse::from(task))))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::Json;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::tasks::types::ReassignTaskRequest;
+    use crate::routes::test_support::{
+        app_state, assert_status, auth_user, MockTaskRepo, MockUserRepo,
+    };
+
+    #[tokio::test]
+    async fn returns_ok_on_success() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let body = ReassignTaskRequest {
+            assignee_ids: vec!["user-2".to_string()],
+            version: Some(1),
+        };
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            HeaderMap::new(),
+            Json(body),
+        )
+        .await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_for_duplicate_assignees() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let body = ReassignTaskRequest {
+            assignee_ids: vec!["user-2".to_string(), "user-2".to_string()],
+            version: Some(1),
+        };
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            HeaderMap::new(),
+            Json(body),
+        )
+        .await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_when_assignee_is_not_a_member() {
+        let state = app_state(
+            MockTaskRepo::with_update_result(Err(AppError::validation(
+                "invalid_assignee",
+                "Assignees must be members of the task",
+                Some("assignee_ids".to_string()),
+            ))),
+            MockUserRepo::default(),
+        );
+        let body = ReassignTaskRequest {
+            assignee_ids: vec!["stranger".to_string()],
+            version: Some(1),
+        };
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            HeaderMap::new(),
+            Json(body),
+        )
+        .await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn returns_precondition_required_without_version() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let body = ReassignTaskRequest {
+            assignee_ids: Vec::new(),
+            version: None,
+        };
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            HeaderMap::new(),
+            Json(body),
+        )
+        .await;
+
+        assert_status(result, StatusCode::PRECONDITION_REQUIRED);
+    }
+}
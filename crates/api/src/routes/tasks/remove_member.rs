@@ -0,0 +1,75 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+use application::task_service;
+use domain::task::inputs::RemoveTaskMemberInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path((task_id, member_id)): Path<(String, String)>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    task_service::remove_task_member::remove_task_member(
+        state.task_repo.as_ref(),
+        RemoveTaskMemberInput {
+            user_id: user.user_id,
+            task_id,
+            member_id,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::test_support::{
+        app_state, assert_status, auth_user, MockTaskRepo, MockUserRepo,
+    };
+
+    #[tokio::test]
+    async fn returns_no_content_on_success() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path(("task-1".to_string(), "user-2".to_string())),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn returns_conflict_when_removing_last_member() {
+        let state = app_state(
+            MockTaskRepo::with_member_result(Err(AppError::conflict(
+                "Task",
+                "A task must keep at least one member",
+            ))),
+            MockUserRepo::default(),
+        );
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path(("task-1".to_string(), "user-1".to_string())),
+        )
+        .await;
+
+        assert_status(result, StatusCode::CONFLICT);
+    }
+}
@@ -0,0 +1,150 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::task_service;
+use domain::task::inputs::RevertTaskInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::tasks::types::{RevertTaskRequest, RevertTaskResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(task_id): Path<String>,
+    Json(body): Json<RevertTaskRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let reverted = task_service::revert_task::revert_task(
+        state.task_repo.as_ref(),
+        RevertTaskInput {
+            user_id: user.user_id,
+            task_id,
+            target_version: body.target_version,
+            version: body.version,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(Json(RevertTaskResponse::from(reverted)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use axum::response::IntoResponse;
+    use axum::Json;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::tasks::types::RevertTaskRequest;
+    use crate::routes::test_support::{
+        app_state, assert_status, auth_user, sample_revision, MockTaskRepo, MockUserRepo,
+    };
+
+    #[tokio::test]
+    async fn returns_ok_on_success() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let body = RevertTaskRequest {
+            target_version: 0,
+            version: 2,
+        };
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            Json(body),
+        )
+        .await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn skips_a_parent_that_was_deleted_since() {
+        let mut revision = sample_revision("user-123", "task-1", 0);
+        revision.snapshot.parent_task_id = Some("deleted-parent".to_string());
+        let state = app_state(
+            MockTaskRepo {
+                get_result: Mutex::new(Some(Ok(None))),
+                ..MockTaskRepo::with_get_revision_result(Ok(Some(revision)))
+            },
+            MockUserRepo::default(),
+        );
+        let body = RevertTaskRequest {
+            target_version: 0,
+            version: 2,
+        };
+
+        let response = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            Json(body),
+        )
+        .await
+        .unwrap_or_else(|_| panic!("revert failed"))
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json");
+        assert_eq!(json["task_id"], "task-1");
+        assert_eq!(
+            json["skipped_fields"],
+            serde_json::json!(["parent_task_id"])
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_when_revision_missing() {
+        let state = app_state(
+            MockTaskRepo::with_get_revision_result(Ok(None)),
+            MockUserRepo::default(),
+        );
+        let body = RevertTaskRequest {
+            target_version: 7,
+            version: 2,
+        };
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            Json(body),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn returns_internal_server_error_on_repo_failure() {
+        let state = app_state(
+            MockTaskRepo::with_update_result(Err(AppError::database("db error"))),
+            MockUserRepo::default(),
+        );
+        let body = RevertTaskRequest {
+            target_version: 0,
+            version: 2,
+        };
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            Json(body),
+        )
+        .await;
+
+        assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
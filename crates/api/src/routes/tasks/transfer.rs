@@ -0,0 +1,118 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+use chrono::Utc;
+
+use application::task_service;
+use domain::task::filter::TaskFilter;
+use domain::task::inputs::TransferTasksInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::tasks::types::{TaskTransferResponse, TransferTaskRequest};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(task_id): Path<String>,
+    Json(body): Json<TransferTaskRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let is_admin = user.is_admin();
+    let transfer = task_service::transfer_tasks::transfer_tasks(
+        state.task_repo.as_ref(),
+        state.user_repo.as_ref(),
+        TransferTasksInput {
+            from_user_id: body.from_user_id.unwrap_or_else(|| user.user_id.clone()),
+            actor_id: user.user_id,
+            is_admin,
+            to_user_id: body.to_user_id,
+            task_id: Some(task_id),
+            filter: TaskFilter::default(),
+            now: Utc::now(),
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(Json(TaskTransferResponse::from(transfer)))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use axum::Json;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::tasks::types::TransferTaskRequest;
+    use crate::routes::test_support::{
+        app_state, assert_status, auth_user, MockTaskRepo, MockUserRepo,
+    };
+
+    fn body(from_user_id: Option<&str>) -> Json<TransferTaskRequest> {
+        Json(TransferTaskRequest {
+            from_user_id: from_user_id.map(str::to_string),
+            to_user_id: "user-456".to_string(),
+        })
+    }
+
+    #[tokio::test]
+    async fn transfers_the_callers_own_task() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            body(None),
+        )
+        .await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn requires_admin_for_someone_elses_task() {
+        let result = handler(
+            State(app_state(MockTaskRepo::default(), MockUserRepo::default())),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            body(Some("user-789")),
+        )
+        .await;
+
+        assert_status(result, StatusCode::FORBIDDEN);
+
+        let mut admin = auth_user();
+        admin.groups.push("admin".to_string());
+        let result = handler(
+            State(app_state(MockTaskRepo::default(), MockUserRepo::default())),
+            Extension(admin),
+            Path("task-1".to_string()),
+            body(Some("user-789")),
+        )
+        .await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_for_unknown_receiver() {
+        let state = app_state(
+            MockTaskRepo::with_transfer_result(Err(AppError::not_found("User", "User not found"))),
+            MockUserRepo::default(),
+        );
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            body(None),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,132 @@
+use axum::extract::State;
+use axum::response::IntoResponse;
+use axum::Json;
+use chrono::Utc;
+
+use application::task_service;
+use domain::task::inputs::TransferTasksInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::tasks::types::{TaskTransferResponse, TransferTasksRequest};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Json(body): Json<TransferTasksRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let is_admin = user.is_admin();
+    let transfer = task_service::transfer_tasks::transfer_tasks(
+        state.task_repo.as_ref(),
+        state.user_repo.as_ref(),
+        TransferTasksInput {
+            actor_id: user.user_id,
+            is_admin,
+            from_user_id: body.from_user_id,
+            to_user_id: body.to_user_id,
+            task_id: None,
+            filter: body.filter,
+            now: Utc::now(),
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(Json(TaskTransferResponse::from(transfer)))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, State};
+    use axum::http::StatusCode;
+    use axum::response::IntoResponse;
+    use axum::Json;
+    use chrono::{Duration, Utc};
+    use domain::task::filter::TaskFilter;
+
+    use super::handler;
+    use crate::routes::tasks::types::TransferTasksRequest;
+    use crate::routes::test_support::{
+        app_state, assert_status, auth_user, sample_task, MockTaskRepo, MockUserRepo,
+    };
+
+    #[tokio::test]
+    async fn transfers_matching_tasks() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Json(TransferTasksRequest {
+                from_user_id: "user-123".to_string(),
+                to_user_id: "user-456".to_string(),
+                filter: TaskFilter {
+                    labels: Some("handover".to_string()),
+                    ..Default::default()
+                },
+            }),
+        )
+        .await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn transfers_snoozed_and_archived_tasks_that_match_the_filter() {
+        let mut snoozed = sample_task("user-123", "task-snoozed");
+        snoozed.snoozed_until = Some(Utc::now() + Duration::days(3));
+        let mut archived = sample_task("user-123", "task-archived");
+        archived.archived_at = Some(Utc::now());
+        let state = app_state(
+            MockTaskRepo::with_list_result(Ok(vec![snoozed, archived])),
+            MockUserRepo::default(),
+        );
+
+        let response = handler(
+            State(state),
+            Extension(auth_user()),
+            Json(TransferTasksRequest {
+                from_user_id: "user-123".to_string(),
+                to_user_id: "user-456".to_string(),
+                filter: TaskFilter {
+                    labels: Some("handover".to_string()),
+                    ..Default::default()
+                },
+            }),
+        )
+        .await
+        .unwrap_or_else(|_| panic!("transfer failed"))
+        .into_response();
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json");
+        assert_eq!(
+            json["task_ids"],
+            serde_json::json!(["task-snoozed", "task-archived"])
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_for_invalid_filter() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Json(TransferTasksRequest {
+                from_user_id: "user-123".to_string(),
+                to_user_id: "user-456".to_string(),
+                filter: TaskFilter {
+                    status: Some("DONE".to_string()),
+                    ..Default::default()
+                },
+            }),
+        )
+        .await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+}
@@ -1,24 +1,235 @@
-use serde::{Deserialize, Serialize};
+use axum::http::StatusCode;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
 
+use domain::error::AppError;
+use domain::task::duplicate::DuplicateOptions;
 use domain::task::entity::Task;
+use domain::task::filter::TaskFilter;
+use domain::task::group::GroupProgress;
+use domain::task::import::{ImportFailure, ImportLineError, ImportSummary, ImportTask};
+use domain::task::inputs::{CreateTaskInput, UpdateTaskInput};
+use domain::task::markdown::{render_markdown, ChecklistItem, TaskContent, TaskMention};
+use domain::task::merge::{ContentConflict, MergeGranularity, MergeHunk};
+use domain::task::next::{ScoreReason, ScoreWeights, ScoredTask};
+use domain::task::priority::TaskPriority;
+use domain::task::quick_add::QuickAdd;
+use domain::task::revision::{RevertedTask, TaskFieldChange, TaskRevision};
+use domain::task::rollup::TaskRollup;
+use domain::task::status::TaskStatus;
+use domain::task::transfer::TaskTransfer;
+use domain::task::validation::{
+    validate_content, validate_date_range, validate_estimates, validate_labels,
+    validate_recurrence, validate_waiting_on,
+};
+
+use crate::response::{from_app_error, validation_error, ErrorDetail, ErrorResponse};
 
 #[derive(Debug, Deserialize)]
 pub struct Pagination {
     pub page: Option<i64>,
     pub limit: Option<i64>,
+    pub sort: Option<String>,
+    pub assigned_to: Option<String>,
+    pub include_snoozed: Option<bool>,
+    pub archived: Option<String>,
+    pub q: Option<String>,
+    pub status: Option<String>,
+    pub labels: Option<String>,
+    pub due: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+impl Pagination {
+    pub fn filter(&self) -> TaskFilter {
+        TaskFilter {
+            status: self.status.clone(),
+            labels: self.labels.clone(),
+            due: self.due.clone(),
+            q: self.q.clone(),
+            sort: self.sort.clone(),
+            assigned_to: self.assigned_to.clone(),
+            include_snoozed: self.include_snoozed,
+            archived: self.archived.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
 pub struct CreateTaskRequest {
     pub content: String,
     pub status: Option<String>,
+    pub parent_task_id: Option<String>,
+    pub estimate_minutes: Option<i32>,
+    pub remaining_minutes: Option<i32>,
+    pub story_points: Option<i32>,
+    pub due_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    pub priority: Option<String>,
+    pub recurrence: Option<String>,
+    pub snoozed_until: Option<DateTime<Utc>>,
+    pub waiting_on: Option<String>,
+    #[serde(default)]
+    pub group: bool,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Deserialize)]
 pub struct UpdateTaskRequest {
     pub content: Option<String>,
     pub status: Option<String>,
-    pub version: i32,
+    #[serde(default, deserialize_with = "nullable")]
+    pub parent_task_id: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub estimate_minutes: Option<Option<i32>>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub remaining_minutes: Option<Option<i32>>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub story_points: Option<Option<i32>>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub due_at: Option<Option<DateTime<Utc>>>,
+    pub labels: Option<Vec<String>>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub priority: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub recurrence: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub snoozed_until: Option<Option<DateTime<Utc>>>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub waiting_on: Option<Option<String>>,
+    pub group: Option<bool>,
+    pub version: Option<i32>,
+}
+
+impl CreateTaskRequest {
+    pub fn into_input(
+        self,
+        user_id: String,
+        max_content_chars: usize,
+    ) -> Result<CreateTaskInput, ErrorResponse> {
+        validate_content(&self.content, max_content_chars).map_err(from_app_error)?;
+        validate_estimates(
+            self.estimate_minutes,
+            self.remaining_minutes,
+            self.story_points,
+        )
+        .map_err(from_app_error)?;
+        validate_labels(&self.labels).map_err(from_app_error)?;
+        validate_recurrence(self.recurrence.as_deref()).map_err(from_app_error)?;
+        validate_waiting_on(self.waiting_on.as_deref()).map_err(from_app_error)?;
+
+        let status = self
+            .status
+            .as_deref()
+            .map(parse_status)
+            .transpose()?
+            .unwrap_or_default();
+        let priority = self.priority.as_deref().map(parse_priority).transpose()?;
+
+        Ok(CreateTaskInput {
+            user_id,
+            content: self.content,
+            status,
+            completed_at: None,
+            parent_task_id: self.parent_task_id,
+            estimate_minutes: self.estimate_minutes,
+            remaining_minutes: self.remaining_minutes,
+            story_points: self.story_points,
+            due_at: self.due_at,
+            labels: self.labels,
+            priority,
+            recurrence: self.recurrence,
+            snoozed_until: self.snoozed_until,
+            waiting_on: self.waiting_on,
+            group: self.group,
+        })
+    }
+}
+
+impl UpdateTaskRequest {
+    pub fn into_input(
+        self,
+        user_id: String,
+        task_id: String,
+        version: i32,
+        max_content_chars: usize,
+    ) -> Result<UpdateTaskInput, ErrorResponse> {
+        if self.is_empty() {
+            return Err(validation_error(
+                "invalid_body",
+                "At least one field must be provided",
+            ));
+        }
+
+        if let Some(content) = &self.content {
+            validate_content(content, max_content_chars).map_err(from_app_error)?;
+        }
+        validate_estimates(
+            self.estimate_minutes.flatten(),
+            self.remaining_minutes.flatten(),
+            self.story_points.flatten(),
+        )
+        .map_err(from_app_error)?;
+        if let Some(labels) = &self.labels {
+            validate_labels(labels).map_err(from_app_error)?;
+        }
+        validate_recurrence(self.recurrence.clone().flatten().as_deref())
+            .map_err(from_app_error)?;
+        validate_waiting_on(self.waiting_on.clone().flatten().as_deref())
+            .map_err(from_app_error)?;
+
+        let status = self.status.as_deref().map(parse_status).transpose()?;
+        let priority = match self.priority.as_ref() {
+            Some(Some(priority)) => Some(Some(parse_priority(priority)?)),
+            Some(None) => Some(None),
+            None => None,
+        };
+
+        Ok(UpdateTaskInput {
+            user_id,
+            task_id,
+            content: self.content,
+            status,
+            completed_at: None,
+            parent_task_id: self.parent_task_id,
+            estimate_minutes: self.estimate_minutes,
+            remaining_minutes: self.remaining_minutes,
+            story_points: self.story_points,
+            due_at: self.due_at,
+            labels: self.labels,
+            priority,
+            recurrence: self.recurrence,
+            snoozed_until: self.snoozed_until,
+            waiting_on: self.waiting_on,
+            assignee_ids: None,
+            group: self.group,
+            version,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.content.is_none()
+            && self.status.is_none()
+            && self.parent_task_id.is_none()
+            && self.estimate_minutes.is_none()
+            && self.remaining_minutes.is_none()
+            && self.story_points.is_none()
+            && self.due_at.is_none()
+            && self.labels.is_none()
+            && self.priority.is_none()
+            && self.recurrence.is_none()
+            && self.snoozed_until.is_none()
+            && self.waiting_on.is_none()
+            && self.group.is_none()
+    }
+}
+
+// Distinguishes an explicit null (clear the field) from an absent field.
+fn nullable<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
 }
 
 #[derive(Debug, Serialize)]
@@ -26,18 +237,91 @@ pub struct TaskResponse {
     pub task_id: String,
     pub user_id: String,
     pub content: String,
+    pub content_html: String,
+    pub checklist: Vec<ChecklistItem>,
+    pub status: String,
     pub completed_at: Option<String>,
+    pub parent_task_id: Option<String>,
+    pub estimate_minutes: Option<i32>,
+    pub remaining_minutes: Option<i32>,
+    pub story_points: Option<i32>,
+    pub due_at: Option<String>,
+    pub labels: Vec<String>,
+    pub priority: Option<String>,
+    pub recurrence: Option<String>,
+    pub snoozed_until: Option<String>,
+    pub waiting_on: Option<String>,
+    pub archived_at: Option<String>,
+    pub assignee_ids: Vec<String>,
+    pub group: Option<GroupProgressResponse>,
     pub version: i32,
+    pub rank: String,
+    pub tracked_seconds: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GroupProgressResponse {
+    pub completed: i64,
+    pub total: i64,
+    pub member_completed_at: Option<String>,
+}
+
+impl From<GroupProgress> for GroupProgressResponse {
+    fn from(progress: GroupProgress) -> Self {
+        Self {
+            completed: progress.completed,
+            total: progress.total,
+            member_completed_at: progress.member_completed_at.map(|dt| dt.to_rfc3339()),
+        }
+    }
 }
 
 impl From<Task> for TaskResponse {
     fn from(task: Task) -> Self {
+        let rendered = render_markdown(&task.content);
         Self {
             task_id: task.task_id,
             user_id: task.user_id,
             content: task.content,
+            content_html: rendered.html,
+            checklist: rendered.checklist,
+            status: task.status.as_str().to_string(),
             completed_at: task.completed_at.map(|dt| dt.to_rfc3339()),
+            parent_task_id: task.parent_task_id,
+            estimate_minutes: task.estimate_minutes,
+            remaining_minutes: task.remaining_minutes,
+            story_points: task.story_points,
+            due_at: task.due_at.map(|dt| dt.to_rfc3339()),
+            labels: task.labels,
+            priority: task.priority.map(|priority| priority.as_str().to_string()),
+            recurrence: task.recurrence,
+            snoozed_until: task.snoozed_until.map(|dt| dt.to_rfc3339()),
+            waiting_on: task.waiting_on,
+            archived_at: task.archived_at.map(|dt| dt.to_rfc3339()),
+            assignee_ids: task.assignee_ids,
+            group: task.group.map(GroupProgressResponse::from),
             version: task.version,
+            rank: task.rank,
+            tracked_seconds: task.tracked_seconds,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TaskContentResponse {
+    pub content: String,
+    pub html: String,
+    pub mentions: Vec<TaskMention>,
+    pub checklist: Vec<ChecklistItem>,
+}
+
+impl From<TaskContent> for TaskContentResponse {
+    fn from(content: TaskContent) -> Self {
+        Self {
+            content: content.content,
+            html: content.html,
+            mentions: content.mentions,
+            checklist: content.checklist,
         }
     }
 }
@@ -49,24 +333,476 @@ pub struct TaskListResponse {
     pub limit: i64,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct RevertTaskRequest {
+    pub target_version: i32,
+    pub version: i32,
+}
+
+// The reverted task, plus the fields that could not be restored.
 #[derive(Debug, Serialize)]
-pub struct CountResponse {
-    pub count: i64,
+pub struct RevertTaskResponse {
+    #[serde(flatten)]
+    pub task: TaskResponse,
+    pub skipped_fields: Vec<String>,
+}
+
+impl From<RevertedTask> for RevertTaskResponse {
+    fn from(reverted: RevertedTask) -> Self {
+        Self {
+            task: TaskResponse::from(reverted.task),
+            skipped_fields: reverted.skipped_fields,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReassignTaskRequest {
+    pub assignee_ids: Vec<String>,
+    pub version: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AddTaskMemberRequest {
+    pub user_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QuickAddRequest {
+    pub text: String,
+    pub timezone: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct QuickAddPreviewResponse {
+    pub content: String,
+    pub due_at: Option<String>,
+    pub priority: Option<String>,
+    pub labels: Vec<String>,
+    pub recurrence: Option<String>,
+}
+
+impl From<QuickAdd> for QuickAddPreviewResponse {
+    fn from(parsed: QuickAdd) -> Self {
+        Self {
+            content: parsed.content,
+            due_at: parsed.due_at.map(|dt| dt.to_rfc3339()),
+            priority: parsed
+                .priority
+                .map(|priority| priority.as_str().to_string()),
+            labels: parsed.labels,
+            recurrence: parsed.recurrence,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum TaskStatus {
-    Pending,
-    InProgress,
-    Completed,
+pub struct MergeTaskRequest {
+    pub base_version: i32,
+    pub content: String,
+    pub granularity: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MergeConflictResponse {
+    pub success: bool,
+    pub error: ErrorDetail,
+    pub base: String,
+    pub ours: String,
+    pub theirs: String,
+    pub conflicts: Vec<MergeHunk>,
 }
 
-pub fn parse_status(value: &str) -> Result<TaskStatus, ()> {
+impl From<ContentConflict> for MergeConflictResponse {
+    fn from(conflict: ContentConflict) -> Self {
+        Self {
+            success: false,
+            error: ErrorResponse::new(
+                StatusCode::CONFLICT,
+                "merge_conflict",
+                "Both versions changed the same part of the content",
+            )
+            .with_current_version(i64::from(conflict.current_version))
+            .into_detail(),
+            base: conflict.base,
+            ours: conflict.ours,
+            theirs: conflict.theirs,
+            conflicts: conflict.hunks,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MoveTaskRequest {
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TaskFieldChangeResponse {
+    pub field: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+}
+
+impl From<TaskFieldChange> for TaskFieldChangeResponse {
+    fn from(change: TaskFieldChange) -> Self {
+        Self {
+            field: change.field,
+            old_value: change.old_value,
+            new_value: change.new_value,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TaskRevisionResponse {
+    pub version: i32,
+    pub changed_by: String,
+    pub changed_at: String,
+    pub changes: Vec<TaskFieldChangeResponse>,
+}
+
+impl From<TaskRevision> for TaskRevisionResponse {
+    fn from(revision: TaskRevision) -> Self {
+        Self {
+            version: revision.version,
+            changed_by: revision.changed_by,
+            changed_at: revision.created_at.to_rfc3339(),
+            changes: revision
+                .changes
+                .into_iter()
+                .map(TaskFieldChangeResponse::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TaskHistoryResponse {
+    pub task_id: String,
+    pub revisions: Vec<TaskRevisionResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TaskRollupResponse {
+    pub task_id: String,
+    pub task_count: i64,
+    pub completed_count: i64,
+    pub estimate_minutes: i64,
+    pub remaining_minutes: i64,
+    pub story_points: i64,
+    pub completed_story_points: i64,
+}
+
+impl From<TaskRollup> for TaskRollupResponse {
+    fn from(rollup: TaskRollup) -> Self {
+        Self {
+            task_id: rollup.task_id,
+            task_count: rollup.task_count,
+            completed_count: rollup.completed_count,
+            estimate_minutes: rollup.estimate_minutes,
+            remaining_minutes: rollup.remaining_minutes,
+            story_points: rollup.story_points,
+            completed_story_points: rollup.completed_story_points,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferFormat {
+    Csv,
+    Json,
+    TodoTxt,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ExportQuery {
+    pub format: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ImportQuery {
+    pub format: Option<String>,
+    pub dry_run: Option<bool>,
+}
+
+pub const TASK_RECORD_COLUMNS: [&str; 9] = [
+    "task_id",
+    "content",
+    "status",
+    "priority",
+    "labels",
+    "due_at",
+    "completed_at",
+    "waiting_on",
+    "created_at",
+];
+
+// One task in the CSV and JSON transfer formats. task_id and created_at are
+// informational and ignored on import.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct TaskRecord {
+    #[serde(default)]
+    pub task_id: Option<String>,
+    pub content: String,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub priority: Option<String>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub due_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub completed_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub waiting_on: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl From<&Task> for TaskRecord {
+    fn from(task: &Task) -> Self {
+        Self {
+            task_id: Some(task.task_id.clone()),
+            content: task.content.clone(),
+            status: Some(task.status.as_str().to_string()),
+            priority: task.priority.map(|priority| priority.as_str().to_string()),
+            labels: task.labels.clone(),
+            due_at: task.due_at,
+            completed_at: task.completed_at,
+            waiting_on: task.waiting_on.clone(),
+            created_at: Some(task.created_at),
+        }
+    }
+}
+
+impl TaskRecord {
+    pub fn into_import(self, line: usize) -> ImportTask {
+        ImportTask {
+            line,
+            content: self.content,
+            status: self.status,
+            priority: self.priority,
+            labels: self.labels,
+            due_at: self.due_at,
+            completed_at: self.completed_at,
+            waiting_on: self.waiting_on,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ImportResponse {
+    pub dry_run: bool,
+    pub total: usize,
+    pub valid: usize,
+    pub imported: usize,
+    pub errors: Vec<ImportLineError>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure: Option<ImportFailure>,
+}
+
+impl From<ImportSummary> for ImportResponse {
+    fn from(summary: ImportSummary) -> Self {
+        Self {
+            dry_run: summary.dry_run,
+            total: summary.total,
+            valid: summary.valid,
+            imported: summary.imported,
+            errors: summary.errors,
+            failure: summary.failure,
+        }
+    }
+}
+
+pub fn parse_transfer_format(value: Option<&str>) -> Result<TransferFormat, ErrorResponse> {
+    match value {
+        None | Some("json") => Ok(TransferFormat::Json),
+        Some("csv") => Ok(TransferFormat::Csv),
+        Some("todotxt") => Ok(TransferFormat::TodoTxt),
+        Some(_) => Err(validation_error(
+            "invalid_format",
+            "Format must be csv, json, or todotxt",
+        )),
+    }
+}
+
+pub fn parse_granularity(value: Option<&str>) -> Result<MergeGranularity, ErrorResponse> {
     match value {
-        "PENDING" => Ok(TaskStatus::Pending),
-        "IN_PROGRESS" => Ok(TaskStatus::InProgress),
-        "COMPLETED" => Ok(TaskStatus::Completed),
-        _ => Err(()),
+        None => Ok(MergeGranularity::default()),
+        Some(value) => MergeGranularity::parse(value).ok_or_else(|| {
+            validation_error("invalid_granularity", "Granularity must be line or word")
+        }),
+    }
+}
+
+pub fn parse_status(value: &str) -> Result<TaskStatus, ErrorResponse> {
+    TaskStatus::parse(value).ok_or_else(|| {
+        validation_error(
+            "invalid_status",
+            "Status must be PENDING, IN_PROGRESS, or COMPLETED",
+        )
+    })
+}
+
+pub fn parse_priority(value: &str) -> Result<TaskPriority, ErrorResponse> {
+    TaskPriority::parse(value).ok_or_else(|| {
+        validation_error(
+            "invalid_priority",
+            "Priority must be LOW, MEDIUM, HIGH, or URGENT",
+        )
+    })
+}
+
+// axum treats ":quick" in "/task:quick" as a parameter, so the route is
+// registered as "/task:action" and anything but ":quick" is rejected here.
+pub fn require_quick_action(action: &str) -> Result<(), ErrorResponse> {
+    if action == ":quick" {
+        Ok(())
+    } else {
+        Err(from_app_error(AppError::not_found(
+            "Route",
+            format!("No route for /task{}", action),
+        )))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TransferTaskRequest {
+    // Defaults to the caller handing over their own task.
+    pub from_user_id: Option<String>,
+    pub to_user_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TransferTasksRequest {
+    pub from_user_id: String,
+    pub to_user_id: String,
+    #[serde(default)]
+    pub filter: TaskFilter,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TaskTransferResponse {
+    pub transfer_id: String,
+    pub actor_id: String,
+    pub from_user_id: String,
+    pub to_user_id: String,
+    pub task_ids: Vec<String>,
+    pub count: usize,
+    pub created_at: String,
+}
+
+impl From<TaskTransfer> for TaskTransferResponse {
+    fn from(transfer: TaskTransfer) -> Self {
+        Self {
+            transfer_id: transfer.transfer_id,
+            actor_id: transfer.actor_id,
+            from_user_id: transfer.from_user_id,
+            to_user_id: transfer.to_user_id,
+            count: transfer.task_ids.len(),
+            task_ids: transfer.task_ids,
+            created_at: transfer.created_at.to_rfc3339(),
+        }
     }
 }
+
+// Every part of the task is copied unless switched off.
+#[derive(Debug, Default, Deserialize)]
+pub struct DuplicateTaskRequest {
+    pub include_labels: Option<bool>,
+    pub include_subtasks: Option<bool>,
+    pub include_due_dates: Option<bool>,
+    pub due_at: Option<DateTime<Utc>>,
+}
+
+impl DuplicateTaskRequest {
+    pub fn options(&self) -> Result<DuplicateOptions, ErrorResponse> {
+        if let Some(due_at) = self.due_at {
+            validate_date_range(due_at, "due_at").map_err(from_app_error)?;
+        }
+
+        let defaults = DuplicateOptions::default();
+        Ok(DuplicateOptions {
+            include_labels: self.include_labels.unwrap_or(defaults.include_labels),
+            include_subtasks: self.include_subtasks.unwrap_or(defaults.include_subtasks),
+            include_due_dates: self.include_due_dates.unwrap_or(defaults.include_due_dates),
+            due_at: self.due_at,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct DuplicateTaskResponse {
+    pub task: TaskResponse,
+    pub subtasks: Vec<TaskResponse>,
+}
+
+// Weights left out fall back to the defaults.
+#[derive(Debug, Default, Deserialize)]
+pub struct NextTasksQuery {
+    pub limit: Option<i64>,
+    pub priority_weight: Option<f64>,
+    pub due_weight: Option<f64>,
+    pub blocked_weight: Option<f64>,
+    pub snoozed_weight: Option<f64>,
+    pub age_weight: Option<f64>,
+}
+
+impl NextTasksQuery {
+    pub fn weights(&self) -> ScoreWeights {
+        let defaults = ScoreWeights::default();
+        ScoreWeights {
+            priority: self.priority_weight.unwrap_or(defaults.priority),
+            due: self.due_weight.unwrap_or(defaults.due),
+            blocked: self.blocked_weight.unwrap_or(defaults.blocked),
+            snoozed: self.snoozed_weight.unwrap_or(defaults.snoozed),
+            age: self.age_weight.unwrap_or(defaults.age),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScoreReasonResponse {
+    pub factor: String,
+    pub contribution: f64,
+    pub detail: String,
+}
+
+impl From<ScoreReason> for ScoreReasonResponse {
+    fn from(reason: ScoreReason) -> Self {
+        Self {
+            factor: reason.factor.as_str().to_string(),
+            contribution: reason.contribution,
+            detail: reason.detail,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct NextTaskResponse {
+    pub task: TaskResponse,
+    pub score: f64,
+    pub reasons: Vec<ScoreReasonResponse>,
+}
+
+impl From<ScoredTask> for NextTaskResponse {
+    fn from(scored: ScoredTask) -> Self {
+        Self {
+            task: TaskResponse::from(scored.task),
+            score: scored.score,
+            reasons: scored
+                .reasons
+                .into_iter()
+                .map(ScoreReasonResponse::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct NextTasksResponse {
+    pub tasks: Vec<NextTaskResponse>,
+}
@@ -0,0 +1,68 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+use application::template_service;
+use domain::template::inputs::DeleteTemplateInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(template_id): Path<String>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    template_service::delete_template::delete_template(
+        state.template_repo.as_ref(),
+        DeleteTemplateInput {
+            user_id: user.user_id,
+            template_id,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::test_support::{assert_status, auth_user, template_state, MockTemplateRepo};
+
+    #[tokio::test]
+    async fn returns_no_content_on_success() {
+        let state = template_state(MockTemplateRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("template-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_when_missing() {
+        let state = template_state(MockTemplateRepo::with_delete_result(Err(
+            AppError::not_found("Template", "Template not found"),
+        )));
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("template-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,66 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::template_service;
+use domain::template::inputs::GetTemplateInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::templates::types::TemplateResponse;
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(template_id): Path<String>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let template = template_service::get_template::get_template(
+        state.template_repo.as_ref(),
+        GetTemplateInput {
+            user_id: user.user_id,
+            template_id,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(Json(TemplateResponse::from(template)))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+
+    use super::handler;
+    use crate::routes::test_support::{assert_status, auth_user, template_state, MockTemplateRepo};
+
+    #[tokio::test]
+    async fn returns_ok_on_success() {
+        let state = template_state(MockTemplateRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("template-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_when_missing() {
+        let state = template_state(MockTemplateRepo::with_get_result(Ok(None)));
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("template-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+}
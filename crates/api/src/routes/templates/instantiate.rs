@@ -0,0 +1,141 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Json;
+use chrono::Utc;
+
+use application::template_service;
+use domain::task::validation::validate_date_range;
+use domain::template::inputs::InstantiateTemplateInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::tasks::types::TaskResponse;
+use crate::routes::templates::types::{InstantiateTemplateRequest, InstantiateTemplateResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(template_id): Path<String>,
+    Json(body): Json<InstantiateTemplateRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    if let Some(start_at) = body.start_at {
+        validate_date_range(start_at, "start_at").map_err(from_app_error)?;
+    }
+
+    let tasks = template_service::instantiate_template::instantiate_template(
+        state.template_repo.as_ref(),
+        state.task_repo.as_ref(),
+        InstantiateTemplateInput {
+            user_id: user.user_id,
+            template_id,
+            variables: body.variables,
+            start_at: body.start_at.unwrap_or_else(Utc::now),
+            parent_task_id: body.parent_task_id,
+            max_content_chars: state.max_content_chars,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(InstantiateTemplateResponse {
+            tasks: tasks.into_iter().map(TaskResponse::from).collect(),
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use axum::Json;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::templates::types::InstantiateTemplateRequest;
+    use crate::routes::test_support::{
+        assert_status, auth_user, template_state, MockTaskRepo, MockTemplateRepo,
+    };
+    use crate::AppState;
+
+    fn body(variables: &[(&str, &str)]) -> InstantiateTemplateRequest {
+        InstantiateTemplateRequest {
+            variables: variables
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect::<HashMap<_, _>>(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn returns_created_on_success() {
+        let state = template_state(MockTemplateRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("template-1".to_string()),
+            Json(body(&[("name", "Ada")])),
+        )
+        .await;
+
+        assert_status(result, StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_for_missing_variable() {
+        let state = template_state(MockTemplateRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("template-1".to_string()),
+            Json(body(&[])),
+        )
+        .await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_when_missing() {
+        let state = template_state(MockTemplateRepo::with_get_result(Ok(None)));
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("template-1".to_string()),
+            Json(body(&[("name", "Ada")])),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn returns_internal_server_error_on_repo_failure() {
+        let state = AppState {
+            task_repo: Arc::new(MockTaskRepo::with_create_tree_result(Err(
+                AppError::database("db error"),
+            ))),
+            ..template_state(MockTemplateRepo::default())
+        };
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("template-1".to_string()),
+            Json(body(&[("name", "Ada")])),
+        )
+        .await;
+
+        assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,59 @@
+use axum::extract::State;
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::template_service;
+use domain::template::inputs::ListTemplatesInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::templates::types::{TemplateListResponse, TemplateResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let templates = template_service::list_templates::list_templates(
+        state.template_repo.as_ref(),
+        ListTemplatesInput {
+            user_id: user.user_id,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(Json(TemplateListResponse {
+        templates: templates.into_iter().map(TemplateResponse::from).collect(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, State};
+    use axum::http::StatusCode;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::test_support::{assert_status, auth_user, template_state, MockTemplateRepo};
+
+    #[tokio::test]
+    async fn returns_ok_on_success() {
+        let state = template_state(MockTemplateRepo::default());
+
+        let result = handler(State(state), Extension(auth_user())).await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_internal_server_error_on_repo_failure() {
+        let state = template_state(MockTemplateRepo::with_list_result(Err(AppError::database(
+            "db error",
+        ))));
+
+        let result = handler(State(state), Extension(auth_user())).await;
+
+        assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
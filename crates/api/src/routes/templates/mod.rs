@@ -0,0 +1,7 @@
+pub mod delete;
+pub mod get;
+pub mod instantiate;
+pub mod list;
+pub mod post;
+pub mod put;
+pub mod types;
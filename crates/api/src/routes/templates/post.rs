@@ -0,0 +1,95 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::template_service;
+use domain::template::inputs::CreateTemplateInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::templates::types::{TemplateRequest, TemplateResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Json(body): Json<TemplateRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let template = template_service::create_template::create_template(
+        state.template_repo.as_ref(),
+        CreateTemplateInput {
+            user_id: user.user_id,
+            name: body.name,
+            tasks: body.tasks,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok((StatusCode::CREATED, Json(TemplateResponse::from(template))))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, State};
+    use axum::http::StatusCode;
+    use axum::Json;
+    use domain::error::AppError;
+    use domain::template::entity::TemplateTask;
+
+    use super::handler;
+    use crate::routes::templates::types::TemplateRequest;
+    use crate::routes::test_support::{assert_status, auth_user, template_state, MockTemplateRepo};
+
+    fn body(content: &str) -> TemplateRequest {
+        TemplateRequest {
+            name: "Release".to_string(),
+            tasks: vec![TemplateTask {
+                content: content.to_string(),
+                labels: Vec::new(),
+                due_offset_days: Some(3),
+                subtasks: Vec::new(),
+            }],
+        }
+    }
+
+    #[tokio::test]
+    async fn returns_created_on_success() {
+        let state = template_state(MockTemplateRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Json(body("Tag {{version}}")),
+        )
+        .await;
+
+        assert_status(result, StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_for_malformed_placeholder() {
+        let state = template_state(MockTemplateRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Json(body("Tag {{version")),
+        )
+        .await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn returns_internal_server_error_on_repo_failure() {
+        let state = template_state(MockTemplateRepo::with_create_result(Err(
+            AppError::database("db error"),
+        )));
+
+        let result = handler(State(state), Extension(auth_user()), Json(body("Tag"))).await;
+
+        assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,90 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::template_service;
+use domain::template::inputs::UpdateTemplateInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::templates::types::{TemplateRequest, TemplateResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(template_id): Path<String>,
+    Json(body): Json<TemplateRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let template = template_service::update_template::update_template(
+        state.template_repo.as_ref(),
+        UpdateTemplateInput {
+            user_id: user.user_id,
+            template_id,
+            name: body.name,
+            tasks: body.tasks,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(Json(TemplateResponse::from(template)))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use axum::Json;
+    use domain::error::AppError;
+    use domain::template::entity::TemplateTask;
+
+    use super::handler;
+    use crate::routes::templates::types::TemplateRequest;
+    use crate::routes::test_support::{assert_status, auth_user, template_state, MockTemplateRepo};
+
+    #[tokio::test]
+    async fn returns_bad_request_without_tasks() {
+        let state = template_state(MockTemplateRepo::default());
+        let body = TemplateRequest {
+            name: "Release".to_string(),
+            tasks: Vec::new(),
+        };
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("template-1".to_string()),
+            Json(body),
+        )
+        .await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_when_missing() {
+        let state = template_state(MockTemplateRepo::with_update_result(Err(
+            AppError::not_found("Template", "Template not found"),
+        )));
+        let body = TemplateRequest {
+            name: "Release".to_string(),
+            tasks: vec![TemplateTask {
+                content: "Tag".to_string(),
+                labels: Vec::new(),
+                due_offset_days: None,
+                subtasks: Vec::new(),
+            }],
+        };
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("template-1".to_string()),
+            Json(body),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+}
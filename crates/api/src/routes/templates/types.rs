@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use domain::template::entity::{TaskTemplate, TemplateTask};
+
+use crate::routes::tasks::types::TaskResponse;
+
+#[derive(Debug, Deserialize)]
+pub struct TemplateRequest {
+    pub name: String,
+    pub tasks: Vec<TemplateTask>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct InstantiateTemplateRequest {
+    #[serde(default)]
+    pub variables: HashMap<String, String>,
+    pub start_at: Option<DateTime<Utc>>,
+    pub parent_task_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TemplateResponse {
+    pub template_id: String,
+    pub name: String,
+    pub tasks: Vec<TemplateTask>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<TaskTemplate> for TemplateResponse {
+    fn from(template: TaskTemplate) -> Self {
+        Self {
+            template_id: template.template_id,
+            name: template.name,
+            tasks: template.tasks,
+            created_at: template.created_at.to_rfc3339(),
+            updated_at: template.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TemplateListResponse {
+    pub templates: Vec<TemplateResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct InstantiateTemplateResponse {
+    pub tasks: Vec<TaskResponse>,
+}
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
 use async_trait::async_trait;
@@ -5,18 +6,63 @@ use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use chrono::Utc;
 
+use application::board_service::repository::BoardRepository;
+use application::feed_service::repository::FeedRepository;
+use application::share_service::repository::ShareRepository;
 use application::task_service::repository::TaskRepository;
+use application::template_service::repository::TemplateRepository;
+use application::time_entry_service::repository::TimeEntryRepository;
 use application::user_service::repository::UserRepository;
+use application::view_service::repository::ViewRepository;
+use domain::board::entity::{Board, BoardColumn};
+use domain::board::inputs::{
+    CreateBoardInput, DeleteBoardInput, GetBoardInput, ListBoardTasksInput, ListBoardsInput,
+    MoveCardInput, UpdateBoardInput,
+};
 use domain::error::AppError;
+use domain::feed::entity::FeedToken;
+use domain::feed::inputs::{GetFeedOwnerInput, RevokeFeedTokenInput, RotateFeedTokenInput};
+use domain::share::entity::{IssuedShareLink, ShareAccess, ShareLink};
+use domain::share::inputs::{
+    CheckSharePasswordInput, CountFailedShareAccessInput, CreateShareLinkInput, FindShareLinkInput,
+    ListShareAccessInput, ListShareLinksInput, RecordShareAccessInput, RevokeShareLinkInput,
+};
 use domain::task::entity::Task;
+use domain::task::filter::TaskFilter;
+use domain::task::group::GroupProgress;
 use domain::task::inputs::{
-    CreateTaskInput, DeleteTaskInput, GetTaskInput, ListTasksInput, UpdateTaskInput,
+    AddTaskMemberInput, ApplySyncMutationInput, ArchiveCompletedTasksInput, ArchiveFilter,
+    CreateTaskInput, CreateTaskTreeInput, CreateTasksInput, DeleteTaskInput, GetTaskInput,
+    GetTaskRevisionInput, ListTaskChangesInput, ListTaskRevisionsInput, ListTaskSubtreeInput,
+    ListTasksInput, MoveTaskInput, RemoveTaskMemberInput, SetMemberCompletionInput,
+    SetTaskArchivedInput, SnoozeFilter, TaskStatsQuery, TransferTaskLinksInput, UpdateTaskInput,
+};
+use domain::task::revision::{TaskRevision, TaskSnapshot};
+use domain::task::stats::TaskStats;
+use domain::task::status::TaskStatus;
+use domain::task::sync::{SyncOperation, TaskChanges};
+use domain::task::transfer::TaskTransfer;
+use domain::task::validation::DEFAULT_MAX_CONTENT_CHARS;
+use domain::template::entity::{TaskTemplate, TemplateTask};
+use domain::template::inputs::{
+    CreateTemplateInput, DeleteTemplateInput, GetTemplateInput, ListTemplatesInput,
+    UpdateTemplateInput,
+};
+use domain::time_entry::entity::TimeEntry;
+use domain::time_entry::inputs::{
+    CreateTimeEntryInput, DeleteTimeEntryInput, ListTimeEntriesInput, StartTimerInput,
+    StopTimerInput, TimeReportQuery,
 };
+use domain::time_entry::report::TimeReportRow;
 use domain::user::entity::User;
 use domain::user::inputs::{
     CreateUserInput, DeleteUserInput, GetUserByEmailInput, GetUserByUsernameInput, GetUserInput,
     ListUsersInput, UpdateUserInput,
 };
+use domain::view::entity::SavedView;
+use domain::view::inputs::{
+    CreateViewInput, DeleteViewInput, GetViewInput, ListViewsInput, UpdateViewInput,
+};
 
 use crate::middleware::cognito_auth::AuthUser;
 use crate::response::ErrorResponse;
@@ -26,6 +72,55 @@ pub fn app_state(task_repo: MockTaskRepo, user_repo: MockUserRepo) -> AppState {
     AppState {
         task_repo: Arc::new(task_repo),
         user_repo: Arc::new(user_repo),
+        board_repo: Arc::new(MockBoardRepo::default()),
+        time_entry_repo: Arc::new(MockTimeEntryRepo::default()),
+        template_repo: Arc::new(MockTemplateRepo::default()),
+        view_repo: Arc::new(MockViewRepo::default()),
+        feed_repo: Arc::new(MockFeedRepo::default()),
+        share_repo: Arc::new(MockShareRepo::default()),
+        max_content_chars: DEFAULT_MAX_CONTENT_CHARS,
+    }
+}
+
+pub fn time_entry_state(time_entry_repo: MockTimeEntryRepo) -> AppState {
+    AppState {
+        time_entry_repo: Arc::new(time_entry_repo),
+        ..app_state(MockTaskRepo::default(), MockUserRepo::default())
+    }
+}
+
+pub fn board_state(board_repo: MockBoardRepo) -> AppState {
+    AppState {
+        board_repo: Arc::new(board_repo),
+        ..app_state(MockTaskRepo::default(), MockUserRepo::default())
+    }
+}
+
+pub fn template_state(template_repo: MockTemplateRepo) -> AppState {
+    AppState {
+        template_repo: Arc::new(template_repo),
+        ..app_state(MockTaskRepo::default(), MockUserRepo::default())
+    }
+}
+
+pub fn view_state(view_repo: MockViewRepo) -> AppState {
+    AppState {
+        view_repo: Arc::new(view_repo),
+        ..app_state(MockTaskRepo::default(), MockUserRepo::default())
+    }
+}
+
+pub fn feed_state(feed_repo: MockFeedRepo) -> AppState {
+    AppState {
+        feed_repo: Arc::new(feed_repo),
+        ..app_state(MockTaskRepo::default(), MockUserRepo::default())
+    }
+}
+
+pub fn share_state(share_repo: MockShareRepo) -> AppState {
+    AppState {
+        share_repo: Arc::new(share_repo),
+        ..app_state(MockTaskRepo::default(), MockUserRepo::default())
     }
 }
 
@@ -46,18 +141,130 @@ pub fn assert_status<T: IntoResponse>(result: Result<T, ErrorResponse>, expected
     assert_eq!(status, expected);
 }
 
-fn sample_task(user_id: &str, task_id: &str) -> Task {
+pub fn sample_task(user_id: &str, task_id: &str) -> Task {
     Task {
         user_id: user_id.to_string(),
         task_id: task_id.to_string(),
         content: "sample task".to_string(),
+        status: TaskStatus::Pending,
         completed_at: None,
+        parent_task_id: None,
+        estimate_minutes: None,
+        remaining_minutes: None,
+        story_points: None,
+        due_at: None,
+        labels: Vec::new(),
+        priority: None,
+        recurrence: None,
+        snoozed_until: None,
+        waiting_on: None,
+        archived_at: None,
+        assignee_ids: Vec::new(),
+        group: None,
         version: 0,
+        rank: "i".to_string(),
+        tracked_seconds: 0,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+pub fn sample_revision(user_id: &str, task_id: &str, version: i32) -> TaskRevision {
+    TaskRevision {
+        task_id: task_id.to_string(),
+        version,
+        changed_by: user_id.to_string(),
+        changes: Vec::new(),
+        snapshot: TaskSnapshot {
+            content: "sample task".to_string(),
+            status: TaskStatus::Pending,
+            completed_at: None,
+            parent_task_id: None,
+            estimate_minutes: None,
+            remaining_minutes: None,
+            story_points: None,
+            due_at: None,
+            labels: Vec::new(),
+            priority: None,
+            recurrence: None,
+            snoozed_until: None,
+            waiting_on: None,
+            assignee_ids: Vec::new(),
+            group: false,
+        },
+        created_at: Utc::now(),
+    }
+}
+
+fn sample_board(user_id: &str, board_id: &str) -> Board {
+    Board {
+        board_id: board_id.to_string(),
+        user_id: user_id.to_string(),
+        name: "Sprint".to_string(),
+        columns: vec![
+            BoardColumn {
+                column_id: "column-1".to_string(),
+                name: "Todo".to_string(),
+                status: TaskStatus::Pending,
+                position: 0,
+                wip_limit: None,
+            },
+            BoardColumn {
+                column_id: "column-2".to_string(),
+                name: "Doing".to_string(),
+                status: TaskStatus::InProgress,
+                position: 1,
+                wip_limit: Some(3),
+            },
+        ],
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+fn sample_template(user_id: &str, template_id: &str) -> TaskTemplate {
+    TaskTemplate {
+        template_id: template_id.to_string(),
+        user_id: user_id.to_string(),
+        name: "Onboarding".to_string(),
+        tasks: vec![TemplateTask {
+            content: "Welcome {{name}}".to_string(),
+            labels: vec!["onboarding".to_string()],
+            due_offset_days: Some(1),
+            subtasks: Vec::new(),
+        }],
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+fn sample_view(user_id: &str, view_id: &str) -> SavedView {
+    SavedView {
+        view_id: view_id.to_string(),
+        user_id: user_id.to_string(),
+        name: "Due soon".to_string(),
+        filter: TaskFilter {
+            due: Some("next 7 days".to_string()),
+            ..Default::default()
+        },
         created_at: Utc::now(),
         updated_at: Utc::now(),
     }
 }
 
+fn sample_time_entry(user_id: &str, task_id: &str) -> TimeEntry {
+    let ended_at = Utc::now();
+    TimeEntry {
+        entry_id: "entry-1".to_string(),
+        task_id: task_id.to_string(),
+        user_id: user_id.to_string(),
+        started_at: ended_at - chrono::Duration::minutes(30),
+        ended_at: Some(ended_at),
+        note: None,
+        created_at: ended_at,
+    }
+}
+
 fn sample_user(user_id: &str, client_id: &str) -> User {
     User {
         user_id: user_id.to_string(),
@@ -66,6 +273,7 @@ fn sample_user(user_id: &str, client_id: &str) -> User {
         email: "user@example.com".to_string(),
         name: None,
         picture: None,
+        timezone: "UTC".to_string(),
         created_at: Utc::now(),
         updated_at: Utc::now(),
     }
@@ -80,20 +288,51 @@ fn take_or_default<T>(
 
 pub struct MockTaskRepo {
     pub create_result: Mutex<Option<Result<Task, AppError>>>,
-    pub update_result: Mutex<Option<Result<i64, AppError>>>,
-    pub delete_result: Mutex<Option<Result<i64, AppError>>>,
+    pub create_tree_result: Mutex<Option<Result<Vec<Task>, AppError>>>,
+    pub create_many_result: Mutex<Option<Result<Vec<Task>, AppError>>>,
+    pub create_many_skip: Mutex<usize>,
+    pub update_result: Mutex<Option<Result<Task, AppError>>>,
+    pub delete_result: Mutex<Option<Result<(), AppError>>>,
     pub get_result: Mutex<Option<Result<Option<Task>, AppError>>>,
     pub list_result: Mutex<Option<Result<Vec<Task>, AppError>>>,
+    pub move_result: Mutex<Option<Result<Task, AppError>>>,
+    pub subtree_result: Mutex<Option<Result<Vec<Task>, AppError>>>,
+    pub member_result: Mutex<Option<Result<(), AppError>>>,
+    pub list_revisions_result: Mutex<Option<Result<Vec<TaskRevision>, AppError>>>,
+    pub get_revision_result: Mutex<Option<Result<Option<TaskRevision>, AppError>>>,
+    pub archive_result: Mutex<Option<Result<Task, AppError>>>,
+    pub completion_result: Mutex<Option<Result<Task, AppError>>>,
+    pub transfer_result: Mutex<Option<Result<TaskTransfer, AppError>>>,
+    pub stats_result: Mutex<Option<Result<TaskStats, AppError>>>,
+    pub changes_result: Mutex<Option<Result<TaskChanges, AppError>>>,
+    // Shared so a test can keep a handle after the repo moves into the state.
+    pub created_count: Arc<Mutex<usize>>,
+    pub applied_mutations: Mutex<HashMap<(String, String), Option<Task>>>,
 }
 
 impl Default for MockTaskRepo {
     fn default() -> Self {
         Self {
             create_result: Mutex::new(None),
+            create_tree_result: Mutex::new(None),
+            create_many_result: Mutex::new(None),
+            create_many_skip: Mutex::new(0),
             update_result: Mutex::new(None),
             delete_result: Mutex::new(None),
             get_result: Mutex::new(None),
             list_result: Mutex::new(None),
+            move_result: Mutex::new(None),
+            subtree_result: Mutex::new(None),
+            member_result: Mutex::new(None),
+            list_revisions_result: Mutex::new(None),
+            get_revision_result: Mutex::new(None),
+            archive_result: Mutex::new(None),
+            completion_result: Mutex::new(None),
+            transfer_result: Mutex::new(None),
+            stats_result: Mutex::new(None),
+            changes_result: Mutex::new(None),
+            created_count: Arc::new(Mutex::new(0)),
+            applied_mutations: Mutex::new(HashMap::new()),
         }
     }
 }
@@ -106,14 +345,40 @@ impl MockTaskRepo {
         }
     }
 
-    pub fn with_update_result(result: Result<i64, AppError>) -> Self {
+    pub fn with_create_tree_result(result: Result<Vec<Task>, AppError>) -> Self {
+        Self {
+            create_tree_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_create_many_result(result: Result<Vec<Task>, AppError>) -> Self {
+        Self {
+            create_many_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    // Lets the first `calls` batches succeed before `result` is returned.
+    pub fn with_create_many_result_after(
+        calls: usize,
+        result: Result<Vec<Task>, AppError>,
+    ) -> Self {
+        Self {
+            create_many_result: Mutex::new(Some(result)),
+            create_many_skip: Mutex::new(calls),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_update_result(result: Result<Task, AppError>) -> Self {
         Self {
             update_result: Mutex::new(Some(result)),
             ..Default::default()
         }
     }
 
-    pub fn with_delete_result(result: Result<i64, AppError>) -> Self {
+    pub fn with_delete_result(result: Result<(), AppError>) -> Self {
         Self {
             delete_result: Mutex::new(Some(result)),
             ..Default::default()
@@ -133,22 +398,121 @@ impl MockTaskRepo {
             ..Default::default()
         }
     }
+
+    pub fn with_move_result(result: Result<Task, AppError>) -> Self {
+        Self {
+            move_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_subtree_result(result: Result<Vec<Task>, AppError>) -> Self {
+        Self {
+            subtree_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_member_result(result: Result<(), AppError>) -> Self {
+        Self {
+            member_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_list_revisions_result(result: Result<Vec<TaskRevision>, AppError>) -> Self {
+        Self {
+            list_revisions_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_get_revision_result(result: Result<Option<TaskRevision>, AppError>) -> Self {
+        Self {
+            get_revision_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_archive_result(result: Result<Task, AppError>) -> Self {
+        Self {
+            archive_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_completion_result(result: Result<Task, AppError>) -> Self {
+        Self {
+            completion_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_transfer_result(result: Result<TaskTransfer, AppError>) -> Self {
+        Self {
+            transfer_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_stats_result(result: Result<TaskStats, AppError>) -> Self {
+        Self {
+            stats_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_changes_result(result: Result<TaskChanges, AppError>) -> Self {
+        Self {
+            changes_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
 }
 
 #[async_trait]
 impl TaskRepository for MockTaskRepo {
     async fn create_task(&self, input: CreateTaskInput) -> Result<Task, AppError> {
-        take_or_default(&self.create_result, || {
+        let task = take_or_default(&self.create_result, || {
             Ok(sample_task(&input.user_id, "task-1"))
+        })?;
+        *self.created_count.lock().expect("mutex") += 1;
+        Ok(task)
+    }
+
+    async fn create_task_tree(&self, input: CreateTaskTreeInput) -> Result<Vec<Task>, AppError> {
+        take_or_default(&self.create_tree_result, || {
+            Ok(vec![sample_task(&input.user_id, "task-1")])
         })
     }
 
-    async fn update_task(&self, _input: UpdateTaskInput) -> Result<i64, AppError> {
-        take_or_default(&self.update_result, || Ok(1))
+    async fn create_tasks(&self, input: CreateTasksInput) -> Result<Vec<Task>, AppError> {
+        let default = || {
+            Ok(input
+                .tasks
+                .iter()
+                .map(|task| sample_task(&task.user_id, "task-1"))
+                .collect())
+        };
+        let mut skip = self.create_many_skip.lock().expect("mutex");
+        if *skip > 0 {
+            *skip -= 1;
+            return default();
+        }
+        drop(skip);
+        take_or_default(&self.create_many_result, default)
     }
 
-    async fn delete_task(&self, _input: DeleteTaskInput) -> Result<i64, AppError> {
-        take_or_default(&self.delete_result, || Ok(1))
+    async fn update_task(&self, input: UpdateTaskInput) -> Result<Task, AppError> {
+        take_or_default(&self.update_result, || {
+            let mut task = sample_task(&input.user_id, &input.task_id);
+            task.version = input.version + 1;
+            Ok(task)
+        })
+    }
+
+    async fn delete_task(&self, _input: DeleteTaskInput) -> Result<(), AppError> {
+        take_or_default(&self.delete_result, || Ok(()))
     }
 
     async fn get_task(&self, input: GetTaskInput) -> Result<Option<Task>, AppError> {
@@ -157,9 +521,173 @@ impl TaskRepository for MockTaskRepo {
         })
     }
 
+    // Applies the snoozed and archived filters the way the real repo does.
     async fn list_tasks(&self, input: ListTasksInput) -> Result<Vec<Task>, AppError> {
-        take_or_default(&self.list_result, || {
+        let now = Utc::now();
+        let tasks = take_or_default(&self.list_result, || {
             Ok(vec![sample_task(&input.user_id, "task-1")])
+        })?;
+        Ok(tasks
+            .into_iter()
+            .filter(|task| {
+                let snoozed = task.snoozed_until.is_some_and(|until| until > now);
+                let archived = task.archived_at.is_some();
+                let snooze_ok = match input.query.snoozed {
+                    SnoozeFilter::Awake => !snoozed,
+                    SnoozeFilter::Include => true,
+                    SnoozeFilter::Only => snoozed,
+                };
+                let archive_ok = match input.query.archived {
+                    ArchiveFilter::Active => !archived,
+                    ArchiveFilter::Include => true,
+                    ArchiveFilter::Only => archived,
+                };
+                snooze_ok && archive_ok
+            })
+            .collect())
+    }
+
+    async fn move_task(&self, input: MoveTaskInput) -> Result<Task, AppError> {
+        take_or_default(&self.move_result, || {
+            Ok(sample_task(&input.user_id, &input.task_id))
+        })
+    }
+
+    async fn list_task_subtree(&self, input: ListTaskSubtreeInput) -> Result<Vec<Task>, AppError> {
+        take_or_default(&self.subtree_result, || {
+            Ok(vec![sample_task(&input.user_id, &input.task_id)])
+        })
+    }
+
+    async fn add_task_member(&self, _input: AddTaskMemberInput) -> Result<(), AppError> {
+        take_or_default(&self.member_result, || Ok(()))
+    }
+
+    async fn remove_task_member(&self, _input: RemoveTaskMemberInput) -> Result<(), AppError> {
+        take_or_default(&self.member_result, || Ok(()))
+    }
+
+    async fn list_task_revisions(
+        &self,
+        input: ListTaskRevisionsInput,
+    ) -> Result<Vec<TaskRevision>, AppError> {
+        take_or_default(&self.list_revisions_result, || {
+            Ok(vec![sample_revision(&input.user_id, &input.task_id, 0)])
+        })
+    }
+
+    async fn get_task_revision(
+        &self,
+        input: GetTaskRevisionInput,
+    ) -> Result<Option<TaskRevision>, AppError> {
+        take_or_default(&self.get_revision_result, || {
+            Ok(Some(sample_revision(
+                &input.user_id,
+                &input.task_id,
+                input.version,
+            )))
+        })
+    }
+
+    async fn set_task_archived(&self, input: SetTaskArchivedInput) -> Result<Task, AppError> {
+        take_or_default(&self.archive_result, || {
+            let mut task = sample_task(&input.user_id, &input.task_id);
+            task.archived_at = input.archived.then(Utc::now);
+            Ok(task)
+        })
+    }
+
+    async fn transfer_tasks(
+        &self,
+        input: TransferTaskLinksInput,
+    ) -> Result<TaskTransfer, AppError> {
+        take_or_default(&self.transfer_result, || {
+            Ok(TaskTransfer {
+                transfer_id: "transfer-1".to_string(),
+                actor_id: input.actor_id,
+                from_user_id: input.from_user_id,
+                to_user_id: input.to_user_id,
+                task_ids: input.task_ids,
+                created_at: Utc::now(),
+            })
+        })
+    }
+
+    async fn set_member_completion(
+        &self,
+        input: SetMemberCompletionInput,
+    ) -> Result<Task, AppError> {
+        take_or_default(&self.completion_result, || {
+            let mut task = sample_task(&input.user_id, &input.task_id);
+            task.group = Some(GroupProgress {
+                completed: i64::from(input.completed),
+                total: 2,
+                member_completed_at: input.completed.then(Utc::now),
+            });
+            Ok(task)
+        })
+    }
+
+    async fn archive_completed_tasks(
+        &self,
+        _input: ArchiveCompletedTasksInput,
+    ) -> Result<u64, AppError> {
+        Ok(0)
+    }
+
+    async fn task_stats(&self, input: TaskStatsQuery) -> Result<TaskStats, AppError> {
+        take_or_default(&self.stats_result, || {
+            Ok(TaskStats {
+                from: input.from,
+                to: input.to,
+                timezone: input.timezone,
+                bucket: input.bucket,
+                by_status: Vec::new(),
+                throughput: Vec::new(),
+                created: 0,
+                completed: 0,
+                average_lead_time_seconds: None,
+                overdue: 0,
+                completed_late: 0,
+            })
+        })
+    }
+
+    async fn apply_sync_mutation(
+        &self,
+        input: ApplySyncMutationInput,
+    ) -> Result<Option<Task>, AppError> {
+        let key = (input.user_id, input.mutation.mutation_id);
+        if let Some(task) = self.applied_mutations.lock().expect("mutex").get(&key) {
+            return Ok(task.clone());
+        }
+
+        let task = match input.mutation.operation {
+            SyncOperation::Create(create) => Some(self.create_task(create).await?),
+            SyncOperation::Update(update) => Some(self.update_task(update).await?),
+            SyncOperation::Delete(delete) => {
+                self.delete_task(delete).await?;
+                None
+            }
+        };
+        self.applied_mutations
+            .lock()
+            .expect("mutex")
+            .insert(key, task.clone());
+        Ok(task)
+    }
+
+    async fn list_task_changes(
+        &self,
+        input: ListTaskChangesInput,
+    ) -> Result<TaskChanges, AppError> {
+        take_or_default(&self.changes_result, || {
+            Ok(TaskChanges {
+                tasks: vec![sample_task(&input.user_id, "task-1")],
+                deleted: Vec::new(),
+                next_since: input.since.unwrap_or(0) + 1,
+                has_more: false,
+            })
         })
     }
 }
@@ -250,6 +778,7 @@ impl UserRepository for MockUserRepo {
                 email: input.email,
                 name: input.name,
                 picture: input.picture,
+                timezone: input.timezone.unwrap_or_else(|| "UTC".to_string()),
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
             })
@@ -300,3 +829,616 @@ impl UserRepository for MockUserRepo {
         })
     }
 }
+
+pub struct MockBoardRepo {
+    pub create_result: Mutex<Option<Result<Board, AppError>>>,
+    pub update_result: Mutex<Option<Result<Board, AppError>>>,
+    pub delete_result: Mutex<Option<Result<(), AppError>>>,
+    pub get_result: Mutex<Option<Result<Option<Board>, AppError>>>,
+    pub list_result: Mutex<Option<Result<Vec<Board>, AppError>>>,
+    pub list_tasks_result: Mutex<Option<Result<Vec<Task>, AppError>>>,
+    pub move_card_result: Mutex<Option<Result<Task, AppError>>>,
+}
+
+impl Default for MockBoardRepo {
+    fn default() -> Self {
+        Self {
+            create_result: Mutex::new(None),
+            update_result: Mutex::new(None),
+            delete_result: Mutex::new(None),
+            get_result: Mutex::new(None),
+            list_result: Mutex::new(None),
+            list_tasks_result: Mutex::new(None),
+            move_card_result: Mutex::new(None),
+        }
+    }
+}
+
+impl MockBoardRepo {
+    pub fn with_create_result(result: Result<Board, AppError>) -> Self {
+        Self {
+            create_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_update_result(result: Result<Board, AppError>) -> Self {
+        Self {
+            update_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_delete_result(result: Result<(), AppError>) -> Self {
+        Self {
+            delete_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_get_result(result: Result<Option<Board>, AppError>) -> Self {
+        Self {
+            get_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_list_result(result: Result<Vec<Board>, AppError>) -> Self {
+        Self {
+            list_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_list_tasks_result(result: Result<Vec<Task>, AppError>) -> Self {
+        Self {
+            list_tasks_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_move_card_result(result: Result<Task, AppError>) -> Self {
+        Self {
+            move_card_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+}
+
+#[async_trait]
+impl BoardRepository for MockBoardRepo {
+    async fn create_board(&self, input: CreateBoardInput) -> Result<Board, AppError> {
+        take_or_default(&self.create_result, || {
+            Ok(sample_board(&input.user_id, "board-1"))
+        })
+    }
+
+    async fn update_board(&self, input: UpdateBoardInput) -> Result<Board, AppError> {
+        take_or_default(&self.update_result, || {
+            Ok(sample_board(&input.user_id, &input.board_id))
+        })
+    }
+
+    async fn delete_board(&self, _input: DeleteBoardInput) -> Result<(), AppError> {
+        take_or_default(&self.delete_result, || Ok(()))
+    }
+
+    async fn get_board(&self, input: GetBoardInput) -> Result<Option<Board>, AppError> {
+        take_or_default(&self.get_result, || {
+            Ok(Some(sample_board(&input.user_id, &input.board_id)))
+        })
+    }
+
+    async fn list_boards(&self, input: ListBoardsInput) -> Result<Vec<Board>, AppError> {
+        take_or_default(&self.list_result, || {
+            Ok(vec![sample_board(&input.user_id, "board-1")])
+        })
+    }
+
+    async fn list_board_tasks(&self, input: ListBoardTasksInput) -> Result<Vec<Task>, AppError> {
+        take_or_default(&self.list_tasks_result, || {
+            Ok(vec![sample_task(&input.user_id, "task-1")])
+        })
+    }
+
+    async fn move_card(&self, input: MoveCardInput) -> Result<Task, AppError> {
+        take_or_default(&self.move_card_result, || {
+            let mut task = sample_task(&input.user_id, &input.task_id);
+            task.status = TaskStatus::InProgress;
+            task.version = input.version + 1;
+            Ok(task)
+        })
+    }
+}
+
+pub struct MockTimeEntryRepo {
+    pub start_result: Mutex<Option<Result<TimeEntry, AppError>>>,
+    pub stop_result: Mutex<Option<Result<TimeEntry, AppError>>>,
+    pub create_result: Mutex<Option<Result<TimeEntry, AppError>>>,
+    pub delete_result: Mutex<Option<Result<(), AppError>>>,
+    pub list_result: Mutex<Option<Result<Vec<TimeEntry>, AppError>>>,
+    pub report_result: Mutex<Option<Result<Vec<TimeReportRow>, AppError>>>,
+}
+
+impl Default for MockTimeEntryRepo {
+    fn default() -> Self {
+        Self {
+            start_result: Mutex::new(None),
+            stop_result: Mutex::new(None),
+            create_result: Mutex::new(None),
+            delete_result: Mutex::new(None),
+            list_result: Mutex::new(None),
+            report_result: Mutex::new(None),
+        }
+    }
+}
+
+impl MockTimeEntryRepo {
+    pub fn with_start_result(result: Result<TimeEntry, AppError>) -> Self {
+        Self {
+            start_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_stop_result(result: Result<TimeEntry, AppError>) -> Self {
+        Self {
+            stop_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_delete_result(result: Result<(), AppError>) -> Self {
+        Self {
+            delete_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_list_result(result: Result<Vec<TimeEntry>, AppError>) -> Self {
+        Self {
+            list_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+}
+
+#[async_trait]
+impl TimeEntryRepository for MockTimeEntryRepo {
+    async fn start_timer(&self, input: StartTimerInput) -> Result<TimeEntry, AppError> {
+        take_or_default(&self.start_result, || {
+            let mut entry = sample_time_entry(&input.user_id, &input.task_id);
+            entry.ended_at = None;
+            Ok(entry)
+        })
+    }
+
+    async fn stop_timer(&self, input: StopTimerInput) -> Result<TimeEntry, AppError> {
+        take_or_default(&self.stop_result, || {
+            Ok(sample_time_entry(&input.user_id, "task-1"))
+        })
+    }
+
+    async fn create_time_entry(&self, input: CreateTimeEntryInput) -> Result<TimeEntry, AppError> {
+        take_or_default(&self.create_result, || {
+            Ok(sample_time_entry(&input.user_id, &input.task_id))
+        })
+    }
+
+    async fn delete_time_entry(&self, _input: DeleteTimeEntryInput) -> Result<(), AppError> {
+        take_or_default(&self.delete_result, || Ok(()))
+    }
+
+    async fn list_time_entries(
+        &self,
+        input: ListTimeEntriesInput,
+    ) -> Result<Vec<TimeEntry>, AppError> {
+        take_or_default(&self.list_result, || {
+            Ok(vec![sample_time_entry(&input.user_id, &input.task_id)])
+        })
+    }
+
+    async fn time_report(&self, input: TimeReportQuery) -> Result<Vec<TimeReportRow>, AppError> {
+        take_or_default(&self.report_result, || {
+            Ok(vec![TimeReportRow {
+                day: input.from,
+                task_id: "task-1".to_string(),
+                content: "sample, task".to_string(),
+                seconds: 5400,
+            }])
+        })
+    }
+}
+
+pub struct MockTemplateRepo {
+    pub create_result: Mutex<Option<Result<TaskTemplate, AppError>>>,
+    pub update_result: Mutex<Option<Result<TaskTemplate, AppError>>>,
+    pub delete_result: Mutex<Option<Result<(), AppError>>>,
+    pub get_result: Mutex<Option<Result<Option<TaskTemplate>, AppError>>>,
+    pub list_result: Mutex<Option<Result<Vec<TaskTemplate>, AppError>>>,
+}
+
+impl Default for MockTemplateRepo {
+    fn default() -> Self {
+        Self {
+            create_result: Mutex::new(None),
+            update_result: Mutex::new(None),
+            delete_result: Mutex::new(None),
+            get_result: Mutex::new(None),
+            list_result: Mutex::new(None),
+        }
+    }
+}
+
+impl MockTemplateRepo {
+    pub fn with_create_result(result: Result<TaskTemplate, AppError>) -> Self {
+        Self {
+            create_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_update_result(result: Result<TaskTemplate, AppError>) -> Self {
+        Self {
+            update_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_delete_result(result: Result<(), AppError>) -> Self {
+        Self {
+            delete_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_get_result(result: Result<Option<TaskTemplate>, AppError>) -> Self {
+        Self {
+            get_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_list_result(result: Result<Vec<TaskTemplate>, AppError>) -> Self {
+        Self {
+            list_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+}
+
+#[async_trait]
+impl TemplateRepository for MockTemplateRepo {
+    async fn create_template(&self, input: CreateTemplateInput) -> Result<TaskTemplate, AppError> {
+        take_or_default(&self.create_result, || {
+            Ok(sample_template(&input.user_id, "template-1"))
+        })
+    }
+
+    async fn update_template(&self, input: UpdateTemplateInput) -> Result<TaskTemplate, AppError> {
+        take_or_default(&self.update_result, || {
+            Ok(sample_template(&input.user_id, &input.template_id))
+        })
+    }
+
+    async fn delete_template(&self, _input: DeleteTemplateInput) -> Result<(), AppError> {
+        take_or_default(&self.delete_result, || Ok(()))
+    }
+
+    async fn get_template(
+        &self,
+        input: GetTemplateInput,
+    ) -> Result<Option<TaskTemplate>, AppError> {
+        take_or_default(&self.get_result, || {
+            Ok(Some(sample_template(&input.user_id, &input.template_id)))
+        })
+    }
+
+    async fn list_templates(
+        &self,
+        input: ListTemplatesInput,
+    ) -> Result<Vec<TaskTemplate>, AppError> {
+        take_or_default(&self.list_result, || {
+            Ok(vec![sample_template(&input.user_id, "template-1")])
+        })
+    }
+}
+
+pub struct MockViewRepo {
+    pub create_result: Mutex<Option<Result<SavedView, AppError>>>,
+    pub update_result: Mutex<Option<Result<SavedView, AppError>>>,
+    pub delete_result: Mutex<Option<Result<(), AppError>>>,
+    pub get_result: Mutex<Option<Result<Option<SavedView>, AppError>>>,
+    pub list_result: Mutex<Option<Result<Vec<SavedView>, AppError>>>,
+}
+
+impl Default for MockViewRepo {
+    fn default() -> Self {
+        Self {
+            create_result: Mutex::new(None),
+            update_result: Mutex::new(None),
+            delete_result: Mutex::new(None),
+            get_result: Mutex::new(None),
+            list_result: Mutex::new(None),
+        }
+    }
+}
+
+impl MockViewRepo {
+    pub fn with_create_result(result: Result<SavedView, AppError>) -> Self {
+        Self {
+            create_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_update_result(result: Result<SavedView, AppError>) -> Self {
+        Self {
+            update_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_delete_result(result: Result<(), AppError>) -> Self {
+        Self {
+            delete_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_get_result(result: Result<Option<SavedView>, AppError>) -> Self {
+        Self {
+            get_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_list_result(result: Result<Vec<SavedView>, AppError>) -> Self {
+        Self {
+            list_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+}
+
+#[async_trait]
+impl ViewRepository for MockViewRepo {
+    async fn create_view(&self, input: CreateViewInput) -> Result<SavedView, AppError> {
+        take_or_default(&self.create_result, || {
+            Ok(sample_view(&input.user_id, "view-1"))
+        })
+    }
+
+    async fn update_view(&self, input: UpdateViewInput) -> Result<SavedView, AppError> {
+        take_or_default(&self.update_result, || {
+            Ok(sample_view(&input.user_id, &input.view_id))
+        })
+    }
+
+    async fn delete_view(&self, _input: DeleteViewInput) -> Result<(), AppError> {
+        take_or_default(&self.delete_result, || Ok(()))
+    }
+
+    async fn get_view(&self, input: GetViewInput) -> Result<Option<SavedView>, AppError> {
+        take_or_default(&self.get_result, || {
+            Ok(Some(sample_view(&input.user_id, &input.view_id)))
+        })
+    }
+
+    async fn list_views(&self, input: ListViewsInput) -> Result<Vec<SavedView>, AppError> {
+        take_or_default(&self.list_result, || {
+            Ok(vec![sample_view(&input.user_id, "view-1")])
+        })
+    }
+}
+
+pub struct MockFeedRepo {
+    pub rotate_result: Mutex<Option<Result<FeedToken, AppError>>>,
+    pub revoke_result: Mutex<Option<Result<(), AppError>>>,
+    pub owner_result: Mutex<Option<Result<Option<String>, AppError>>>,
+}
+
+impl Default for MockFeedRepo {
+    fn default() -> Self {
+        Self {
+            rotate_result: Mutex::new(None),
+            revoke_result: Mutex::new(None),
+            owner_result: Mutex::new(None),
+        }
+    }
+}
+
+impl MockFeedRepo {
+    pub fn with_rotate_result(result: Result<FeedToken, AppError>) -> Self {
+        Self {
+            rotate_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_revoke_result(result: Result<(), AppError>) -> Self {
+        Self {
+            revoke_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_owner_result(result: Result<Option<String>, AppError>) -> Self {
+        Self {
+            owner_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+}
+
+#[async_trait]
+impl FeedRepository for MockFeedRepo {
+    async fn rotate_feed_token(&self, input: RotateFeedTokenInput) -> Result<FeedToken, AppError> {
+        take_or_default(&self.rotate_result, || {
+            Ok(FeedToken {
+                user_id: input.user_id,
+                token: "feed-token".to_string(),
+                created_at: Utc::now(),
+            })
+        })
+    }
+
+    async fn revoke_feed_token(&self, _input: RevokeFeedTokenInput) -> Result<(), AppError> {
+        take_or_default(&self.revoke_result, || Ok(()))
+    }
+
+    async fn get_feed_owner(&self, _input: GetFeedOwnerInput) -> Result<Option<String>, AppError> {
+        take_or_default(&self.owner_result, || Ok(Some("user-123".to_string())))
+    }
+}
+
+fn sample_share_link(share_id: &str, task_id: &str) -> ShareLink {
+    ShareLink {
+        share_id: share_id.to_string(),
+        task_id: task_id.to_string(),
+        created_by: "user-123".to_string(),
+        expires_at: Utc::now() + chrono::Duration::days(7),
+        has_password: false,
+        revoked_at: None,
+        created_at: Utc::now(),
+    }
+}
+
+pub struct MockShareRepo {
+    pub create_result: Mutex<Option<Result<IssuedShareLink, AppError>>>,
+    pub list_result: Mutex<Option<Result<Vec<ShareLink>, AppError>>>,
+    pub revoke_result: Mutex<Option<Result<ShareLink, AppError>>>,
+    pub access_result: Mutex<Option<Result<Vec<ShareAccess>, AppError>>>,
+    pub find_result: Mutex<Option<Result<Option<ShareLink>, AppError>>>,
+    pub check_result: Mutex<Option<Result<bool, AppError>>>,
+    pub failed_count_result: Mutex<Option<Result<i64, AppError>>>,
+    pub record_result: Mutex<Option<Result<(), AppError>>>,
+}
+
+impl Default for MockShareRepo {
+    fn default() -> Self {
+        Self {
+            create_result: Mutex::new(None),
+            list_result: Mutex::new(None),
+            revoke_result: Mutex::new(None),
+            access_result: Mutex::new(None),
+            find_result: Mutex::new(None),
+            check_result: Mutex::new(None),
+            failed_count_result: Mutex::new(None),
+            record_result: Mutex::new(None),
+        }
+    }
+}
+
+impl MockShareRepo {
+    pub fn with_create_result(result: Result<IssuedShareLink, AppError>) -> Self {
+        Self {
+            create_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_list_result(result: Result<Vec<ShareLink>, AppError>) -> Self {
+        Self {
+            list_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_revoke_result(result: Result<ShareLink, AppError>) -> Self {
+        Self {
+            revoke_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_access_result(result: Result<Vec<ShareAccess>, AppError>) -> Self {
+        Self {
+            access_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_find_result(result: Result<Option<ShareLink>, AppError>) -> Self {
+        Self {
+            find_result: Mutex::new(Some(result)),
+            ..Default::default()
+        }
+    }
+}
+
+#[async_trait]
+impl ShareRepository for MockShareRepo {
+    async fn create_share_link(
+        &self,
+        input: CreateShareLinkInput,
+    ) -> Result<IssuedShareLink, AppError> {
+        take_or_default(&self.create_result, || {
+            Ok(IssuedShareLink {
+                link: ShareLink {
+                    expires_at: input.expires_at,
+                    has_password: input.password.is_some(),
+                    ..sample_share_link("share-1", &input.task_id)
+                },
+                token: "share-token".to_string(),
+            })
+        })
+    }
+
+    async fn list_share_links(
+        &self,
+        input: ListShareLinksInput,
+    ) -> Result<Vec<ShareLink>, AppError> {
+        take_or_default(&self.list_result, || {
+            Ok(vec![sample_share_link("share-1", &input.task_id)])
+        })
+    }
+
+    async fn revoke_share_link(&self, input: RevokeShareLinkInput) -> Result<ShareLink, AppError> {
+        take_or_default(&self.revoke_result, || {
+            Ok(ShareLink {
+                revoked_at: Some(Utc::now()),
+                ..sample_share_link(&input.share_id, "task-1")
+            })
+        })
+    }
+
+    async fn list_share_access(
+        &self,
+        input: ListShareAccessInput,
+    ) -> Result<Vec<ShareAccess>, AppError> {
+        take_or_default(&self.access_result, || {
+            Ok(vec![ShareAccess {
+                share_id: input.share_id,
+                accessed_at: Utc::now(),
+                granted: true,
+                ip: Some("203.0.113.7".to_string()),
+                user_agent: None,
+            }])
+        })
+    }
+
+    async fn find_share_link(
+        &self,
+        _input: FindShareLinkInput,
+    ) -> Result<Option<ShareLink>, AppError> {
+        take_or_default(&self.find_result, || {
+            Ok(Some(sample_share_link("share-1", "task-1")))
+        })
+    }
+
+    async fn check_share_password(&self, input: CheckSharePasswordInput) -> Result<bool, AppError> {
+        take_or_default(&self.check_result, || Ok(input.password == "correct horse"))
+    }
+
+    async fn count_failed_share_access(
+        &self,
+        _input: CountFailedShareAccessInput,
+    ) -> Result<i64, AppError> {
+        take_or_default(&self.failed_count_result, || Ok(0))
+    }
+
+    async fn record_share_access(&self, _input: RecordShareAccessInput) -> Result<(), AppError> {
+        take_or_default(&self.record_result, || Ok(()))
+    }
+}
@@ -0,0 +1,70 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+use application::time_entry_service;
+use domain::time_entry::inputs::DeleteTimeEntryInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(entry_id): Path<String>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    time_entry_service::delete_time_entry::delete_time_entry(
+        state.time_entry_repo.as_ref(),
+        DeleteTimeEntryInput {
+            user_id: user.user_id,
+            entry_id,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::test_support::{
+        assert_status, auth_user, time_entry_state, MockTimeEntryRepo,
+    };
+
+    #[tokio::test]
+    async fn returns_no_content_on_success() {
+        let state = time_entry_state(MockTimeEntryRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("entry-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_when_missing() {
+        let state = time_entry_state(MockTimeEntryRepo::with_delete_result(Err(
+            AppError::not_found("TimeEntry", "Time entry not found"),
+        )));
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("entry-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,73 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::time_entry_service;
+use domain::time_entry::inputs::ListTimeEntriesInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::time_entries::types::{TimeEntryListResponse, TimeEntryResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(task_id): Path<String>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let entries = time_entry_service::list_time_entries::list_time_entries(
+        state.time_entry_repo.as_ref(),
+        ListTimeEntriesInput {
+            user_id: user.user_id,
+            task_id,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(Json(TimeEntryListResponse {
+        entries: entries.into_iter().map(TimeEntryResponse::from).collect(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::test_support::{
+        assert_status, auth_user, time_entry_state, MockTimeEntryRepo,
+    };
+
+    #[tokio::test]
+    async fn returns_ok_on_success() {
+        let state = time_entry_state(MockTimeEntryRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_when_task_missing() {
+        let state = time_entry_state(MockTimeEntryRepo::with_list_result(Err(
+            AppError::not_found("Task", "Task not found"),
+        )));
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+}
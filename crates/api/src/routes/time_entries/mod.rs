@@ -0,0 +1,7 @@
+pub mod delete;
+pub mod list;
+pub mod post;
+pub mod report;
+pub mod start;
+pub mod stop;
+pub mod types;
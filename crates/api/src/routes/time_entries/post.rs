@@ -0,0 +1,90 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::time_entry_service;
+use domain::time_entry::inputs::CreateTimeEntryInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::time_entries::types::{CreateTimeEntryRequest, TimeEntryResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(task_id): Path<String>,
+    Json(body): Json<CreateTimeEntryRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let entry = time_entry_service::create_time_entry::create_time_entry(
+        state.time_entry_repo.as_ref(),
+        CreateTimeEntryInput {
+            user_id: user.user_id,
+            task_id,
+            started_at: body.started_at,
+            ended_at: body.ended_at,
+            note: body.note,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok((StatusCode::CREATED, Json(TimeEntryResponse::from(entry))))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use axum::Json;
+    use chrono::{Duration, Utc};
+
+    use super::handler;
+    use crate::routes::test_support::{
+        assert_status, auth_user, time_entry_state, MockTimeEntryRepo,
+    };
+    use crate::routes::time_entries::types::CreateTimeEntryRequest;
+
+    #[tokio::test]
+    async fn returns_created_on_success() {
+        let state = time_entry_state(MockTimeEntryRepo::default());
+        let ended_at = Utc::now() - Duration::minutes(5);
+        let body = CreateTimeEntryRequest {
+            started_at: ended_at - Duration::hours(1),
+            ended_at,
+            note: Some("review".to_string()),
+        };
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            Json(body),
+        )
+        .await;
+
+        assert_status(result, StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_when_range_is_inverted() {
+        let state = time_entry_state(MockTimeEntryRepo::default());
+        let started_at = Utc::now() - Duration::hours(1);
+        let body = CreateTimeEntryRequest {
+            started_at,
+            ended_at: started_at - Duration::minutes(1),
+            note: None,
+        };
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            Json(body),
+        )
+        .await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,132 @@
+use axum::extract::{Query, State};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+
+use application::time_entry_service;
+use domain::time_entry::inputs::TimeReportInput;
+use domain::time_entry::report::TimeReport;
+
+use crate::csv::{csv_response, csv_row};
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, validation_error, ErrorResponse};
+use crate::routes::time_entries::types::{TimeReportQuery, TimeReportResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Query(params): Query<TimeReportQuery>,
+) -> Result<Response, ErrorResponse> {
+    let csv = match params.format.as_deref() {
+        None | Some("json") => false,
+        Some("csv") => true,
+        Some(_) => {
+            return Err(validation_error(
+                "invalid_format",
+                "Format must be json or csv",
+            ))
+        }
+    };
+
+    let report = time_entry_service::time_report::time_report(
+        state.time_entry_repo.as_ref(),
+        state.user_repo.as_ref(),
+        TimeReportInput {
+            user_id: user.user_id,
+            from: params.from,
+            to: params.to,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    if csv {
+        let filename = format!("time-report-{}-{}.csv", report.from, report.to);
+        return Ok(csv_response(&filename, report_csv(&report)));
+    }
+
+    Ok(Json(TimeReportResponse::from(report)).into_response())
+}
+
+fn report_csv(report: &TimeReport) -> String {
+    let mut body = csv_row(&["day", "task_id", "content", "seconds", "hours"]);
+    for row in &report.rows {
+        body.push_str(&csv_row(&[
+            row.day.to_string(),
+            row.task_id.clone(),
+            row.content.clone(),
+            row.seconds.to_string(),
+            format!("{:.2}", row.seconds as f64 / 3600.0),
+        ]));
+    }
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Query, State};
+    use axum::http::header::CONTENT_TYPE;
+    use axum::http::StatusCode;
+    use chrono::NaiveDate;
+
+    use super::handler;
+    use crate::csv::CSV_CONTENT_TYPE;
+    use crate::routes::test_support::{
+        assert_status, auth_user, time_entry_state, MockTimeEntryRepo,
+    };
+    use crate::routes::time_entries::types::TimeReportQuery;
+
+    fn query(to: &str, format: Option<&str>) -> Query<TimeReportQuery> {
+        Query(TimeReportQuery {
+            from: NaiveDate::from_ymd_opt(2025, 3, 1).expect("date"),
+            to: to.parse().expect("date"),
+            format: format.map(str::to_string),
+        })
+    }
+
+    #[tokio::test]
+    async fn returns_ok_on_success() {
+        let state = time_entry_state(MockTimeEntryRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            query("2025-03-31", None),
+        )
+        .await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_csv_when_requested() {
+        let state = time_entry_state(MockTimeEntryRepo::default());
+
+        let response = handler(
+            State(state),
+            Extension(auth_user()),
+            query("2025-03-31", Some("csv")),
+        )
+        .await
+        .unwrap_or_else(|_| panic!("expected a CSV response"));
+
+        assert_eq!(
+            response.headers().get(CONTENT_TYPE).expect("content type"),
+            CSV_CONTENT_TYPE
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_for_inverted_range() {
+        let state = time_entry_state(MockTimeEntryRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            query("2025-02-01", None),
+        )
+        .await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,95 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::time_entry_service;
+use domain::time_entry::inputs::StartTimerInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::time_entries::types::{StartTimerRequest, TimeEntryResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(task_id): Path<String>,
+    body: Option<Json<StartTimerRequest>>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let Json(body) = body.unwrap_or_default();
+
+    let entry = time_entry_service::start_timer::start_timer(
+        state.time_entry_repo.as_ref(),
+        StartTimerInput {
+            user_id: user.user_id,
+            task_id,
+            note: body.note,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok((StatusCode::CREATED, Json(TimeEntryResponse::from(entry))))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::test_support::{
+        assert_status, auth_user, time_entry_state, MockTimeEntryRepo,
+    };
+
+    #[tokio::test]
+    async fn returns_created_on_success() {
+        let state = time_entry_state(MockTimeEntryRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            None,
+        )
+        .await;
+
+        assert_status(result, StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn returns_conflict_when_timer_running() {
+        let state = time_entry_state(MockTimeEntryRepo::with_start_result(Err(
+            AppError::conflict("TimeEntry", "A timer is already running"),
+        )));
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            None,
+        )
+        .await;
+
+        assert_status(result, StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_when_task_missing() {
+        let state = time_entry_state(MockTimeEntryRepo::with_start_result(Err(
+            AppError::not_found("Task", "Task not found"),
+        )));
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("task-1".to_string()),
+            None,
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+}
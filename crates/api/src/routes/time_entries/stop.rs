@@ -0,0 +1,59 @@
+use axum::extract::State;
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::time_entry_service;
+use domain::time_entry::inputs::StopTimerInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::time_entries::types::TimeEntryResponse;
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let entry = time_entry_service::stop_timer::stop_timer(
+        state.time_entry_repo.as_ref(),
+        StopTimerInput {
+            user_id: user.user_id,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(Json(TimeEntryResponse::from(entry)))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, State};
+    use axum::http::StatusCode;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::test_support::{
+        assert_status, auth_user, time_entry_state, MockTimeEntryRepo,
+    };
+
+    #[tokio::test]
+    async fn returns_ok_on_success() {
+        let state = time_entry_state(MockTimeEntryRepo::default());
+
+        let result = handler(State(state), Extension(auth_user())).await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_when_no_timer_running() {
+        let state = time_entry_state(MockTimeEntryRepo::with_stop_result(Err(
+            AppError::not_found("TimeEntry", "No timer is running"),
+        )));
+
+        let result = handler(State(state), Extension(auth_user())).await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+}
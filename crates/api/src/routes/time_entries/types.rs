@@ -0,0 +1,77 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use domain::time_entry::entity::TimeEntry;
+use domain::time_entry::report::{DayTotal, TaskTotal, TimeReport, TimeReportRow};
+
+#[derive(Debug, Default, Deserialize)]
+pub struct StartTimerRequest {
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateTimeEntryRequest {
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TimeReportQuery {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub format: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TimeEntryResponse {
+    pub entry_id: String,
+    pub task_id: String,
+    pub user_id: String,
+    pub started_at: String,
+    pub ended_at: Option<String>,
+    pub duration_seconds: Option<i64>,
+    pub note: Option<String>,
+}
+
+impl From<TimeEntry> for TimeEntryResponse {
+    fn from(entry: TimeEntry) -> Self {
+        Self {
+            duration_seconds: entry.duration_seconds(),
+            entry_id: entry.entry_id,
+            task_id: entry.task_id,
+            user_id: entry.user_id,
+            started_at: entry.started_at.to_rfc3339(),
+            ended_at: entry.ended_at.map(|dt| dt.to_rfc3339()),
+            note: entry.note,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TimeEntryListResponse {
+    pub entries: Vec<TimeEntryResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TimeReportResponse {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub total_seconds: i64,
+    pub by_day: Vec<DayTotal>,
+    pub by_task: Vec<TaskTotal>,
+    pub rows: Vec<TimeReportRow>,
+}
+
+impl From<TimeReport> for TimeReportResponse {
+    fn from(report: TimeReport) -> Self {
+        Self {
+            from: report.from,
+            to: report.to,
+            total_seconds: report.total_seconds,
+            by_day: report.by_day,
+            by_task: report.by_task,
+            rows: report.rows,
+        }
+    }
+}
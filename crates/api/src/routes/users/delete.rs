@@ -1,24 +1,33 @@
 use axum::extract::{Path, State};
+use axum::http::HeaderMap;
 use axum::response::IntoResponse;
 use axum::Json;
 
 use application::user_service;
 use domain::user::inputs::DeleteUserInput;
 
-use crate::response::{from_app_error, ErrorResponse};
+use crate::etag::{from_conditional_error, if_match, timestamp_from_token};
+use crate::response::ErrorResponse;
 use crate::routes::users::types::CountResponse;
 use crate::AppState;
 
 pub async fn handler(
     State(state): State<AppState>,
     Path(user_id): Path<String>,
+    headers: HeaderMap,
 ) -> Result<impl IntoResponse, ErrorResponse> {
+    let if_match = if_match(&headers)?;
+    let expected_updated_at = if_match.map(timestamp_from_token).transpose()?;
+
     let count = user_service::delete_user::delete_user(
         state.user_repo.as_ref(),
-        DeleteUserInput { user_id },
+        DeleteUserInput {
+            user_id,
+            expected_updated_at,
+        },
     )
     .await
-    .map_err(from_app_error)?;
+    .map_err(|error| from_conditional_error(error, if_match.is_some()))?;
 
     Ok(Json(CountResponse { count }))
 }
@@ -26,7 +35,7 @@ pub async fn handler(
 #[cfg(test)]
 mod tests {
     use axum::extract::{Path, State};
-    use axum::http::StatusCode;
+    use axum::http::{HeaderMap, StatusCode};
     use domain::error::AppError;
 
     use super::handler;
@@ -36,7 +45,7 @@ mod tests {
     async fn returns_ok_on_success() {
         let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
 
-        let result = handler(State(state), Path("user-1".to_string())).await;
+        let result = handler(State(state), Path("user-1".to_string()), HeaderMap::new()).await;
 
         assert_status(result, StatusCode::OK);
     }
@@ -48,7 +57,7 @@ mod tests {
             MockUserRepo::with_delete_result(Err(AppError::database("db error"))),
         );
 
-        let result = handler(State(state), Path("user-1".to_string())).await;
+        let result = handler(State(state), Path("user-1".to_string()), HeaderMap::new()).await;
 
         assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
     }
@@ -1,4 +1,5 @@
 use axum::extract::{Path, State};
+use axum::http::HeaderMap;
 use axum::response::IntoResponse;
 use axum::Json;
 
@@ -6,6 +7,7 @@ use application::user_service;
 use domain::error::AppError;
 use domain::user::inputs::GetUserInput;
 
+use crate::etag::{if_none_match, not_modified, timestamp_etag, with_etag};
 use crate::response::{from_app_error, ErrorResponse};
 use crate::routes::users::types::UserResponse;
 use crate::AppState;
@@ -13,19 +15,25 @@ use crate::AppState;
 pub async fn handler(
     State(state): State<AppState>,
     Path(user_id): Path<String>,
+    headers: HeaderMap,
 ) -> Result<impl IntoResponse, ErrorResponse> {
     let user = user_service::get_user::get_user(state.user_repo.as_ref(), GetUserInput { user_id })
         .await
         .map_err(from_app_error)?
         .ok_or_else(|| from_app_error(AppError::not_found("User", "User not found")))?;
 
-    Ok(Json(UserResponse::from(user)))
+    let etag = timestamp_etag(user.updated_at);
+    if if_none_match(&headers, &etag) {
+        return Ok(not_modified(&etag));
+    }
+
+    Ok(with_etag(&etag, Json(UserResponse::from(user))))
 }
 
 #[cfg(test)]
 mod tests {
     use axum::extract::{Path, State};
-    use axum::http::StatusCode;
+    use axum::http::{HeaderMap, StatusCode};
     use domain::error::AppError;
 
     use super::handler;
@@ -35,7 +43,7 @@ mod tests {
     async fn returns_ok_on_success() {
         let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
 
-        let result = handler(State(state), Path("user-1".to_string())).await;
+        let result = handler(State(state), Path("user-1".to_string()), HeaderMap::new()).await;
 
         assert_status(result, StatusCode::OK);
     }
@@ -47,7 +55,7 @@ mod tests {
             MockUserRepo::with_get_result(Ok(None)),
         );
 
-        let result = handler(State(state), Path("user-1".to_string())).await;
+        let result = handler(State(state), Path("user-1".to_string()), HeaderMap::new()).await;
 
         assert_status(result, StatusCode::NOT_FOUND);
     }
@@ -59,7 +67,7 @@ mod tests {
             MockUserRepo::with_get_result(Err(AppError::database("db error"))),
         );
 
-        let result = handler(State(state), Path("user-1".to_string())).await;
+        let result = handler(State(state), Path("user-1".to_string()), HeaderMap::new()).await;
 
         assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
     }
@@ -3,6 +3,7 @@ pub mod get;
 pub mod get_by_email;
 pub mod get_by_username;
 pub mod list;
+pub mod patch;
 pub mod post;
 pub mod put;
 pub mod types;
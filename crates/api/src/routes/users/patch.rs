@@ -0,0 +1,132 @@
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::user_service;
+use domain::user::inputs::PatchUserInput;
+
+use crate::etag::{
+    from_conditional_error, if_match, timestamp_etag, timestamp_from_token, with_etag,
+};
+use crate::patch::{parse_patch, patch_format};
+use crate::response::ErrorResponse;
+use crate::routes::users::types::UserResponse;
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    Path(user_id): Path<String>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let format = patch_format(&headers)?;
+    let patch = parse_patch(&body)?;
+    let if_match = if_match(&headers)?;
+    let expected_updated_at = if_match.map(timestamp_from_token).transpose()?;
+
+    let user = user_service::patch_user::patch_user(
+        state.user_repo.as_ref(),
+        PatchUserInput {
+            user_id,
+            format,
+            patch,
+            expected_updated_at,
+        },
+    )
+    .await
+    .map_err(|error| from_conditional_error(error, if_match.is_some()))?;
+
+    let etag = timestamp_etag(user.updated_at);
+    Ok(with_etag(&etag, Json(UserResponse::from(user))))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Bytes;
+    use axum::extract::{Path, State};
+    use axum::http::header::CONTENT_TYPE;
+    use axum::http::{HeaderMap, HeaderValue, StatusCode};
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::patch::{JSON_PATCH_CONTENT_TYPE, MERGE_PATCH_CONTENT_TYPE};
+    use crate::routes::test_support::{app_state, assert_status, MockTaskRepo, MockUserRepo};
+
+    fn headers(content_type: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+        headers
+    }
+
+    #[tokio::test]
+    async fn returns_ok_for_merge_patch_clearing_name() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let body = Bytes::from_static(br#"{"name":null,"picture":"https://example.com/a.png"}"#);
+
+        let result = handler(
+            State(state),
+            Path("user-1".to_string()),
+            headers(MERGE_PATCH_CONTENT_TYPE),
+            body,
+        )
+        .await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_when_patched_user_is_invalid() {
+        let state = app_state(MockTaskRepo::default(), MockUserRepo::default());
+        let body = Bytes::from_static(br#"[{"op":"replace","path":"/email","value":" "}]"#);
+
+        let result = handler(
+            State(state),
+            Path("user-1".to_string()),
+            headers(JSON_PATCH_CONTENT_TYPE),
+            body,
+        )
+        .await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_when_missing() {
+        let state = app_state(
+            MockTaskRepo::default(),
+            MockUserRepo::with_get_result(Ok(None)),
+        );
+        let body = Bytes::from_static(br#"{"name":"New"}"#);
+
+        let result = handler(
+            State(state),
+            Path("user-1".to_string()),
+            headers(MERGE_PATCH_CONTENT_TYPE),
+            body,
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn returns_internal_server_error_on_repo_failure() {
+        let state = app_state(
+            MockTaskRepo::default(),
+            MockUserRepo::with_update_result(Err(AppError::database("db error"))),
+        );
+        let body = Bytes::from_static(br#"{"name":"New"}"#);
+
+        let result = handler(
+            State(state),
+            Path("user-1".to_string()),
+            headers(MERGE_PATCH_CONTENT_TYPE),
+            body,
+        )
+        .await;
+
+        assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -5,6 +5,7 @@ use axum::Json;
 
 use application::user_service;
 use domain::user::inputs::CreateUserInput;
+use domain::user::timezone::parse_timezone;
 
 use crate::response::{from_app_error, validation_error, ErrorResponse};
 use crate::routes::users::types::{CreateUserRequest, UserResponse};
@@ -33,6 +34,10 @@ pub async fn handler(
         return Err(validation_error("invalid_email", "Email is required"));
     }
 
+    if let Some(timezone) = &body.timezone {
+        parse_timezone(timezone).map_err(from_app_error)?;
+    }
+
     let user = user_service::create_user::create_user(
         state.user_repo.as_ref(),
         CreateUserInput {
@@ -42,6 +47,7 @@ pub async fn handler(
             email: body.email,
             name: body.name,
             picture: body.picture,
+            timezone: body.timezone,
         },
     )
     .await
@@ -71,6 +77,7 @@ mod tests {
             email: "".to_string(),
             name: None,
             picture: None,
+            timezone: None,
         };
 
         let result = handler(State(state), Json(body)).await;
@@ -88,6 +95,7 @@ mod tests {
             email: "user1@example.com".to_string(),
             name: None,
             picture: None,
+            timezone: None,
         };
 
         let result = handler(State(state), Json(body)).await;
@@ -108,6 +116,7 @@ mod tests {
             email: "user1@example.com".to_string(),
             name: None,
             picture: None,
+            timezone: None,
         };
 
         let result = handler(State(state), Json(body)).await;
@@ -1,10 +1,15 @@
 use axum::extract::{Path, State};
+use axum::http::HeaderMap;
 use axum::response::IntoResponse;
 use axum::Json;
 
 use application::user_service;
 use domain::user::inputs::UpdateUserInput;
+use domain::user::timezone::parse_timezone;
 
+use crate::etag::{
+    from_conditional_error, if_match, timestamp_etag, timestamp_from_token, with_etag,
+};
 use crate::response::{from_app_error, validation_error, ErrorResponse};
 use crate::routes::users::types::{UpdateUserRequest, UserResponse};
 use crate::AppState;
@@ -12,12 +17,17 @@ use crate::AppState;
 pub async fn handler(
     State(state): State<AppState>,
     Path(user_id): Path<String>,
+    headers: HeaderMap,
     Json(body): Json<UpdateUserRequest>,
 ) -> Result<impl IntoResponse, ErrorResponse> {
+    let if_match = if_match(&headers)?;
+    let expected_updated_at = if_match.map(timestamp_from_token).transpose()?;
+
     if body.username.is_none()
         && body.email.is_none()
         && body.name.is_none()
         && body.picture.is_none()
+        && body.timezone.is_none()
     {
         return Err(validation_error(
             "invalid_body",
@@ -25,6 +35,10 @@ pub async fn handler(
         ));
     }
 
+    if let Some(timezone) = &body.timezone {
+        parse_timezone(timezone).map_err(from_app_error)?;
+    }
+
     let user = user_service::update_user::update_user(
         state.user_repo.as_ref(),
         UpdateUserInput {
@@ -33,18 +47,22 @@ pub async fn handler(
             email: body.email,
             name: body.name,
             picture: body.picture,
+            timezone: body.timezone,
+            expected_updated_at,
         },
     )
     .await
-    .map_err(from_app_error)?;
+    .map_err(|error| from_conditional_error(error, if_match.is_some()))?;
 
-    Ok(Json(UserResponse::from(user)))
+    let etag = timestamp_etag(user.updated_at);
+    Ok(with_etag(&etag, Json(UserResponse::from(user))))
 }
 
 #[cfg(test)]
 mod tests {
     use axum::extract::{Path, State};
-    use axum::http::StatusCode;
+    use axum::http::header::IF_MATCH;
+    use axum::http::{HeaderMap, HeaderValue, StatusCode};
     use axum::Json;
     use domain::error::AppError;
 
@@ -60,9 +78,16 @@ mod tests {
             email: None,
             name: None,
             picture: None,
+            timezone: None,
         };
 
-        let result = handler(State(state), Path("user-1".to_string()), Json(body)).await;
+        let result = handler(
+            State(state),
+            Path("user-1".to_string()),
+            HeaderMap::new(),
+            Json(body),
+        )
+        .await;
 
         assert_status(result, StatusCode::BAD_REQUEST);
     }
@@ -75,9 +100,16 @@ mod tests {
             email: None,
             name: None,
             picture: None,
+            timezone: None,
         };
 
-        let result = handler(State(state), Path("user-1".to_string()), Json(body)).await;
+        let result = handler(
+            State(state),
+            Path("user-1".to_string()),
+            HeaderMap::new(),
+            Json(body),
+        )
+        .await;
 
         assert_status(result, StatusCode::OK);
     }
@@ -93,10 +125,44 @@ mod tests {
             email: None,
             name: None,
             picture: None,
+            timezone: None,
         };
 
-        let result = handler(State(state), Path("user-1".to_string()), Json(body)).await;
+        let result = handler(
+            State(state),
+            Path("user-1".to_string()),
+            HeaderMap::new(),
+            Json(body),
+        )
+        .await;
 
         assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
     }
+
+    #[tokio::test]
+    async fn returns_precondition_failed_when_if_match_is_stale() {
+        let state = app_state(
+            MockTaskRepo::default(),
+            MockUserRepo::with_update_result(Err(AppError::version_conflict("User", 2))),
+        );
+        let mut headers = HeaderMap::new();
+        headers.insert(IF_MATCH, HeaderValue::from_static("\"1\""));
+        let body = UpdateUserRequest {
+            username: Some("user2".to_string()),
+            email: None,
+            name: None,
+            picture: None,
+            timezone: None,
+        };
+
+        let result = handler(
+            State(state),
+            Path("user-1".to_string()),
+            headers,
+            Json(body),
+        )
+        .await;
+
+        assert_status(result, StatusCode::PRECONDITION_FAILED);
+    }
 }
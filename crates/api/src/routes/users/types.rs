@@ -10,6 +10,7 @@ pub struct CreateUserRequest {
     pub email: String,
     pub name: Option<String>,
     pub picture: Option<String>,
+    pub timezone: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -18,6 +19,7 @@ pub struct UpdateUserRequest {
     pub email: Option<String>,
     pub name: Option<Option<String>>,
     pub picture: Option<Option<String>>,
+    pub timezone: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -33,6 +35,7 @@ pub struct UserResponse {
     pub client_id: String,
     pub username: String,
     pub email: String,
+    pub timezone: String,
 }
 
 impl From<User> for UserResponse {
@@ -42,6 +45,7 @@ impl From<User> for UserResponse {
             client_id: user.client_id,
             username: user.username,
             email: user.email,
+            timezone: user.timezone,
         }
     }
 }
@@ -0,0 +1,69 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+use application::view_service;
+use domain::view::inputs::DeleteViewInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(view_id): Path<String>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    view_service::delete_view::delete_view(
+        state.view_repo.as_ref(),
+        DeleteViewInput {
+            user_id: user.user_id,
+            view_id,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::test_support::{assert_status, auth_user, view_state, MockViewRepo};
+
+    #[tokio::test]
+    async fn returns_no_content_on_success() {
+        let state = view_state(MockViewRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("view-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_when_missing() {
+        let state = view_state(MockViewRepo::with_delete_result(Err(AppError::not_found(
+            "View",
+            "View not found",
+        ))));
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("view-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+}
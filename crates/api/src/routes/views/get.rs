@@ -0,0 +1,66 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::view_service;
+use domain::view::inputs::GetViewInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::views::types::ViewResponse;
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(view_id): Path<String>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let view = view_service::get_view::get_view(
+        state.view_repo.as_ref(),
+        GetViewInput {
+            user_id: user.user_id,
+            view_id,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(Json(ViewResponse::from(view)))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+
+    use super::handler;
+    use crate::routes::test_support::{assert_status, auth_user, view_state, MockViewRepo};
+
+    #[tokio::test]
+    async fn returns_ok_on_success() {
+        let state = view_state(MockViewRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("view-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_when_missing() {
+        let state = view_state(MockViewRepo::with_get_result(Ok(None)));
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("view-1".to_string()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+}
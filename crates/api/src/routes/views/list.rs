@@ -0,0 +1,59 @@
+use axum::extract::State;
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::view_service;
+use domain::view::inputs::ListViewsInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::views::types::{ViewListResponse, ViewResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let views = view_service::list_views::list_views(
+        state.view_repo.as_ref(),
+        ListViewsInput {
+            user_id: user.user_id,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(Json(ViewListResponse {
+        views: views.into_iter().map(ViewResponse::from).collect(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, State};
+    use axum::http::StatusCode;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::test_support::{assert_status, auth_user, view_state, MockViewRepo};
+
+    #[tokio::test]
+    async fn returns_ok_on_success() {
+        let state = view_state(MockViewRepo::default());
+
+        let result = handler(State(state), Extension(auth_user())).await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_internal_server_error_on_repo_failure() {
+        let state = view_state(MockViewRepo::with_list_result(Err(AppError::database(
+            "db error",
+        ))));
+
+        let result = handler(State(state), Extension(auth_user())).await;
+
+        assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
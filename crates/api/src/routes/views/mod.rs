@@ -0,0 +1,7 @@
+pub mod delete;
+pub mod get;
+pub mod list;
+pub mod post;
+pub mod put;
+pub mod tasks;
+pub mod types;
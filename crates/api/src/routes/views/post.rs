@@ -0,0 +1,95 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::view_service;
+use domain::view::inputs::CreateViewInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::views::types::{ViewRequest, ViewResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Json(body): Json<ViewRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let view = view_service::create_view::create_view(
+        state.view_repo.as_ref(),
+        CreateViewInput {
+            user_id: user.user_id,
+            name: body.name,
+            filter: body.filter,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok((StatusCode::CREATED, Json(ViewResponse::from(view))))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, State};
+    use axum::http::StatusCode;
+    use axum::Json;
+    use domain::error::AppError;
+    use domain::task::filter::TaskFilter;
+
+    use super::handler;
+    use crate::routes::test_support::{assert_status, auth_user, view_state, MockViewRepo};
+    use crate::routes::views::types::ViewRequest;
+
+    fn body(due: &str) -> ViewRequest {
+        ViewRequest {
+            name: "This week".to_string(),
+            filter: TaskFilter {
+                status: Some("pending".to_string()),
+                labels: Some("home".to_string()),
+                due: Some(due.to_string()),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn returns_created_on_success() {
+        let state = view_state(MockViewRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Json(body("next 7 days")),
+        )
+        .await;
+
+        assert_status(result, StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_for_invalid_filter() {
+        let state = view_state(MockViewRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Json(body("eventually")),
+        )
+        .await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn returns_internal_server_error_on_repo_failure() {
+        let state = view_state(MockViewRepo::with_create_result(Err(AppError::database(
+            "db error",
+        ))));
+
+        let result = handler(State(state), Extension(auth_user()), Json(body("today"))).await;
+
+        assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
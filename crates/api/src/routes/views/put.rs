@@ -0,0 +1,89 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+
+use application::view_service;
+use domain::view::inputs::UpdateViewInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::views::types::{ViewRequest, ViewResponse};
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(view_id): Path<String>,
+    Json(body): Json<ViewRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let view = view_service::update_view::update_view(
+        state.view_repo.as_ref(),
+        UpdateViewInput {
+            user_id: user.user_id,
+            view_id,
+            name: body.name,
+            filter: body.filter,
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(Json(ViewResponse::from(view)))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, State};
+    use axum::http::StatusCode;
+    use axum::Json;
+    use domain::error::AppError;
+    use domain::task::filter::TaskFilter;
+
+    use super::handler;
+    use crate::routes::test_support::{assert_status, auth_user, view_state, MockViewRepo};
+    use crate::routes::views::types::ViewRequest;
+
+    #[tokio::test]
+    async fn returns_bad_request_without_name() {
+        let state = view_state(MockViewRepo::default());
+        let body = ViewRequest {
+            name: "  ".to_string(),
+            filter: TaskFilter::default(),
+        };
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("view-1".to_string()),
+            Json(body),
+        )
+        .await;
+
+        assert_status(result, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_when_missing() {
+        let state = view_state(MockViewRepo::with_update_result(Err(AppError::not_found(
+            "View",
+            "View not found",
+        ))));
+        let body = ViewRequest {
+            name: "Overdue".to_string(),
+            filter: TaskFilter {
+                due: Some("overdue".to_string()),
+                ..Default::default()
+            },
+        };
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("view-1".to_string()),
+            Json(body),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,112 @@
+use axum::extract::{Path, Query, State};
+use axum::response::IntoResponse;
+use axum::Json;
+use chrono::Utc;
+
+use application::view_service;
+use domain::view::inputs::RunViewInput;
+
+use crate::middleware::cognito_auth::AuthUser;
+use crate::response::{from_app_error, ErrorResponse};
+use crate::routes::tasks::types::{TaskListResponse, TaskResponse};
+use crate::routes::views::types::ViewTasksQuery;
+use crate::AppState;
+
+pub async fn handler(
+    State(state): State<AppState>,
+    axum::extract::Extension(user): axum::extract::Extension<AuthUser>,
+    Path(view_id): Path<String>,
+    Query(params): Query<ViewTasksQuery>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let page = params.page.unwrap_or(1).max(1);
+    let limit = params.limit.unwrap_or(20).clamp(1, 100);
+
+    let tasks = view_service::run_view::run_view(
+        state.view_repo.as_ref(),
+        state.task_repo.as_ref(),
+        state.user_repo.as_ref(),
+        RunViewInput {
+            user_id: user.user_id,
+            view_id,
+            page,
+            limit,
+            now: Utc::now(),
+        },
+    )
+    .await
+    .map_err(from_app_error)?;
+
+    Ok(Json(TaskListResponse {
+        tasks: tasks.into_iter().map(TaskResponse::from).collect(),
+        page,
+        limit,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::{Extension, Path, Query, State};
+    use axum::http::StatusCode;
+    use domain::error::AppError;
+
+    use super::handler;
+    use crate::routes::test_support::{
+        app_state, assert_status, auth_user, view_state, MockTaskRepo, MockUserRepo, MockViewRepo,
+    };
+    use crate::routes::views::types::ViewTasksQuery;
+
+    fn params() -> ViewTasksQuery {
+        ViewTasksQuery {
+            page: None,
+            limit: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn returns_ok_on_success() {
+        let state = view_state(MockViewRepo::default());
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("view-1".to_string()),
+            Query(params()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_when_view_missing() {
+        let state = view_state(MockViewRepo::with_get_result(Ok(None)));
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("view-1".to_string()),
+            Query(params()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn returns_internal_server_error_on_repo_failure() {
+        let state = app_state(
+            MockTaskRepo::with_list_result(Err(AppError::database("db error"))),
+            MockUserRepo::default(),
+        );
+
+        let result = handler(
+            State(state),
+            Extension(auth_user()),
+            Path("view-1".to_string()),
+            Query(params()),
+        )
+        .await;
+
+        assert_status(result, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
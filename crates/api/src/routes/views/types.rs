@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+use domain::task::filter::TaskFilter;
+use domain::view::entity::SavedView;
+
+#[derive(Debug, Deserialize)]
+pub struct ViewRequest {
+    pub name: String,
+    #[serde(default)]
+    pub filter: TaskFilter,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ViewTasksQuery {
+    pub page: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ViewResponse {
+    pub view_id: String,
+    pub name: String,
+    pub filter: TaskFilter,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<SavedView> for ViewResponse {
+    fn from(view: SavedView) -> Self {
+        Self {
+            view_id: view.view_id,
+            name: view.name,
+            filter: view.filter,
+            created_at: view.created_at.to_rfc3339(),
+            updated_at: view.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ViewListResponse {
+    pub views: Vec<ViewResponse>,
+}
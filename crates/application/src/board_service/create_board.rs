@@ -0,0 +1,15 @@
+use domain::board::entity::Board;
+use domain::board::inputs::CreateBoardInput;
+use domain::board::validation::validate_board;
+use domain::error::AppError;
+
+use super::repository::BoardRepository;
+
+pub async fn create_board<R: BoardRepository + ?Sized>(
+    repo: &R,
+    input: CreateBoardInput,
+) -> Result<Board, AppError> {
+    validate_board(&input.name, &input.columns)?;
+
+    repo.create_board(input).await
+}
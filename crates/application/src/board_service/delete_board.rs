@@ -0,0 +1,11 @@
+use domain::board::inputs::DeleteBoardInput;
+use domain::error::AppError;
+
+use super::repository::BoardRepository;
+
+pub async fn delete_board<R: BoardRepository + ?Sized>(
+    repo: &R,
+    input: DeleteBoardInput,
+) -> Result<(), AppError> {
+    repo.delete_board(input).await
+}
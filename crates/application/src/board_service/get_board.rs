@@ -0,0 +1,24 @@
+use domain::board::entity::BoardView;
+use domain::board::inputs::{GetBoardInput, ListBoardTasksInput};
+use domain::error::AppError;
+
+use super::repository::BoardRepository;
+
+pub async fn get_board<R: BoardRepository + ?Sized>(
+    repo: &R,
+    input: GetBoardInput,
+) -> Result<BoardView, AppError> {
+    let board = repo
+        .get_board(input.clone())
+        .await?
+        .ok_or_else(|| AppError::not_found("Board", "Board not found"))?;
+
+    let tasks = repo
+        .list_board_tasks(ListBoardTasksInput {
+            user_id: input.user_id,
+            statuses: board.columns.iter().map(|column| column.status).collect(),
+        })
+        .await?;
+
+    Ok(BoardView::group(board, tasks))
+}
@@ -0,0 +1,12 @@
+use domain::board::entity::Board;
+use domain::board::inputs::ListBoardsInput;
+use domain::error::AppError;
+
+use super::repository::BoardRepository;
+
+pub async fn list_boards<R: BoardRepository + ?Sized>(
+    repo: &R,
+    input: ListBoardsInput,
+) -> Result<Vec<Board>, AppError> {
+    repo.list_boards(input).await
+}
@@ -0,0 +1,7 @@
+pub mod create_board;
+pub mod delete_board;
+pub mod get_board;
+pub mod list_boards;
+pub mod move_card;
+pub mod repository;
+pub mod update_board;
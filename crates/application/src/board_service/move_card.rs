@@ -0,0 +1,22 @@
+use domain::board::inputs::MoveCardInput;
+use domain::error::AppError;
+use domain::task::entity::Task;
+
+use super::repository::BoardRepository;
+
+pub async fn move_card<R: BoardRepository + ?Sized>(
+    repo: &R,
+    input: MoveCardInput,
+) -> Result<Task, AppError> {
+    if input.before.as_deref() == Some(input.task_id.as_str())
+        || input.after.as_deref() == Some(input.task_id.as_str())
+    {
+        return Err(AppError::validation(
+            "invalid_move",
+            "A task cannot be moved relative to itself",
+            None,
+        ));
+    }
+
+    repo.move_card(input).await
+}
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use domain::board::entity::Board;
+use domain::board::inputs::{
+    CreateBoardInput, DeleteBoardInput, GetBoardInput, ListBoardTasksInput, ListBoardsInput,
+    MoveCardInput, UpdateBoardInput,
+};
+use domain::error::AppError;
+use domain::task::entity::Task;
+
+#[async_trait]
+pub trait BoardRepository: Send + Sync {
+    async fn create_board(&self, input: CreateBoardInput) -> Result<Board, AppError>;
+    async fn update_board(&self, input: UpdateBoardInput) -> Result<Board, AppError>;
+    async fn delete_board(&self, input: DeleteBoardInput) -> Result<(), AppError>;
+    async fn get_board(&self, input: GetBoardInput) -> Result<Option<Board>, AppError>;
+    async fn list_boards(&self, input: ListBoardsInput) -> Result<Vec<Board>, AppError>;
+    async fn list_board_tasks(&self, input: ListBoardTasksInput) -> Result<Vec<Task>, AppError>;
+    async fn move_card(&self, input: MoveCardInput) -> Result<Task, AppError>;
+}
@@ -0,0 +1,15 @@
+use domain::board::entity::Board;
+use domain::board::inputs::UpdateBoardInput;
+use domain::board::validation::validate_board;
+use domain::error::AppError;
+
+use super::repository::BoardRepository;
+
+pub async fn update_board<R: BoardRepository + ?Sized>(
+    repo: &R,
+    input: UpdateBoardInput,
+) -> Result<Board, AppError> {
+    validate_board(&input.name, &input.columns)?;
+
+    repo.update_board(input).await
+}
@@ -0,0 +1,4 @@
+pub mod repository;
+pub mod revoke_feed_token;
+pub mod rotate_feed_token;
+pub mod task_feed;
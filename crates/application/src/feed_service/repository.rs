@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use domain::error::AppError;
+use domain::feed::entity::FeedToken;
+use domain::feed::inputs::{GetFeedOwnerInput, RevokeFeedTokenInput, RotateFeedTokenInput};
+
+#[async_trait]
+pub trait FeedRepository: Send + Sync {
+    async fn rotate_feed_token(&self, input: RotateFeedTokenInput) -> Result<FeedToken, AppError>;
+    async fn revoke_feed_token(&self, input: RevokeFeedTokenInput) -> Result<(), AppError>;
+    async fn get_feed_owner(&self, input: GetFeedOwnerInput) -> Result<Option<String>, AppError>;
+}
@@ -0,0 +1,11 @@
+use domain::error::AppError;
+use domain::feed::inputs::RevokeFeedTokenInput;
+
+use super::repository::FeedRepository;
+
+pub async fn revoke_feed_token<R: FeedRepository + ?Sized>(
+    repo: &R,
+    input: RevokeFeedTokenInput,
+) -> Result<(), AppError> {
+    repo.revoke_feed_token(input).await
+}
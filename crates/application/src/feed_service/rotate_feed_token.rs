@@ -0,0 +1,12 @@
+use domain::error::AppError;
+use domain::feed::entity::FeedToken;
+use domain::feed::inputs::RotateFeedTokenInput;
+
+use super::repository::FeedRepository;
+
+pub async fn rotate_feed_token<R: FeedRepository + ?Sized>(
+    repo: &R,
+    input: RotateFeedTokenInput,
+) -> Result<FeedToken, AppError> {
+    repo.rotate_feed_token(input).await
+}
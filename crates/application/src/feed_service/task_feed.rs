@@ -0,0 +1,52 @@
+use domain::error::AppError;
+use domain::feed::inputs::{GetFeedOwnerInput, TaskFeedInput};
+use domain::task::entity::Task;
+use domain::task::filter::TaskQuery;
+use domain::task::inputs::{ListTasksInput, SnoozeFilter};
+
+use super::repository::FeedRepository;
+use crate::task_service::repository::TaskRepository;
+
+const FEED_PAGE_SIZE: i64 = 500;
+pub const MAX_FEED_TASKS: usize = 2000;
+
+// Unknown and revoked tokens look the same to the caller.
+pub async fn task_feed<F, R>(
+    feeds: &F,
+    tasks: &R,
+    input: TaskFeedInput,
+) -> Result<Vec<Task>, AppError>
+where
+    F: FeedRepository + ?Sized,
+    R: TaskRepository + ?Sized,
+{
+    let user_id = feeds
+        .get_feed_owner(GetFeedOwnerInput { token: input.token })
+        .await?
+        .ok_or_else(|| AppError::not_found("Feed", "Feed not found"))?;
+
+    let mut feed = Vec::new();
+    let mut page = 1;
+    while feed.len() < MAX_FEED_TASKS {
+        let batch = tasks
+            .list_tasks(ListTasksInput {
+                user_id: user_id.clone(),
+                page,
+                limit: FEED_PAGE_SIZE,
+                query: TaskQuery {
+                    snoozed: SnoozeFilter::Include,
+                    ..Default::default()
+                },
+            })
+            .await?;
+        let done = (batch.len() as i64) < FEED_PAGE_SIZE;
+        feed.extend(batch);
+        if done {
+            break;
+        }
+        page += 1;
+    }
+    feed.truncate(MAX_FEED_TASKS);
+
+    Ok(feed)
+}
@@ -1,2 +1,8 @@
+pub mod board_service;
+pub mod feed_service;
+pub mod share_service;
 pub mod task_service;
+pub mod template_service;
+pub mod time_entry_service;
 pub mod user_service;
+pub mod view_service;
@@ -0,0 +1,26 @@
+use chrono::Duration;
+use domain::error::AppError;
+use domain::share::entity::IssuedShareLink;
+use domain::share::inputs::{CreateShareLinkInput, IssueShareLinkInput};
+use domain::share::validation::{validate_share_days, validate_share_password, DEFAULT_SHARE_DAYS};
+
+use super::repository::ShareRepository;
+
+pub async fn create_share_link<R: ShareRepository + ?Sized>(
+    repo: &R,
+    input: IssueShareLinkInput,
+) -> Result<IssuedShareLink, AppError> {
+    let days = input.expires_in_days.unwrap_or(DEFAULT_SHARE_DAYS);
+    validate_share_days(days)?;
+    if let Some(password) = &input.password {
+        validate_share_password(password)?;
+    }
+
+    repo.create_share_link(CreateShareLinkInput {
+        user_id: input.user_id,
+        task_id: input.task_id,
+        expires_at: input.now + Duration::days(days),
+        password: input.password,
+    })
+    .await
+}
@@ -0,0 +1,12 @@
+use domain::error::AppError;
+use domain::share::entity::ShareAccess;
+use domain::share::inputs::ListShareAccessInput;
+
+use super::repository::ShareRepository;
+
+pub async fn list_share_access<R: ShareRepository + ?Sized>(
+    repo: &R,
+    input: ListShareAccessInput,
+) -> Result<Vec<ShareAccess>, AppError> {
+    repo.list_share_access(input).await
+}
@@ -0,0 +1,12 @@
+use domain::error::AppError;
+use domain::share::entity::ShareLink;
+use domain::share::inputs::ListShareLinksInput;
+
+use super::repository::ShareRepository;
+
+pub async fn list_share_links<R: ShareRepository + ?Sized>(
+    repo: &R,
+    input: ListShareLinksInput,
+) -> Result<Vec<ShareLink>, AppError> {
+    repo.list_share_links(input).await
+}
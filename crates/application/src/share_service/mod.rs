@@ -0,0 +1,6 @@
+pub mod create_share_link;
+pub mod list_share_access;
+pub mod list_share_links;
+pub mod open_share_link;
+pub mod repository;
+pub mod revoke_share_link;
@@ -0,0 +1,94 @@
+use chrono::Duration;
+use domain::error::AppError;
+use domain::share::entity::SharedTask;
+use domain::share::inputs::{
+    CheckSharePasswordInput, CountFailedShareAccessInput, FindShareLinkInput, OpenShareLinkInput,
+    RecordShareAccessInput,
+};
+use domain::share::validation::{MAX_FAILED_SHARE_ATTEMPTS, SHARE_ATTEMPT_WINDOW_MINUTES};
+use domain::task::inputs::ListTaskSubtreeInput;
+
+use super::repository::ShareRepository;
+use crate::task_service::repository::TaskRepository;
+
+// Unknown, expired and revoked links all look the same to the caller. The
+// task is read as the link's creator, so a creator who loses access to the
+// task also stops sharing it.
+pub async fn open_share_link<S, R>(
+    shares: &S,
+    tasks: &R,
+    input: OpenShareLinkInput,
+) -> Result<SharedTask, AppError>
+where
+    S: ShareRepository + ?Sized,
+    R: TaskRepository + ?Sized,
+{
+    let link = shares
+        .find_share_link(FindShareLinkInput { token: input.token })
+        .await?
+        .filter(|link| link.is_active(input.now))
+        .ok_or_else(|| AppError::not_found("ShareLink", "Share link not found"))?;
+
+    let record = |granted: bool| RecordShareAccessInput {
+        share_id: link.share_id.clone(),
+        granted,
+        ip: input.ip.clone(),
+        user_agent: input.user_agent.clone(),
+    };
+
+    if link.has_password {
+        // Checked before the password is hashed. Refused attempts are logged
+        // too, so the link stays locked while the guessing continues.
+        let failures = shares
+            .count_failed_share_access(CountFailedShareAccessInput {
+                share_id: link.share_id.clone(),
+                since: input.now - Duration::minutes(SHARE_ATTEMPT_WINDOW_MINUTES),
+            })
+            .await?;
+        if failures >= MAX_FAILED_SHARE_ATTEMPTS {
+            shares.record_share_access(record(false)).await?;
+            return Err(AppError::TooManyRequests {
+                resource: "ShareLink".to_string(),
+                message: "Too many failed password attempts; try again later".to_string(),
+            });
+        }
+
+        let granted = match input.password {
+            Some(password) => {
+                shares
+                    .check_share_password(CheckSharePasswordInput {
+                        share_id: link.share_id.clone(),
+                        password,
+                    })
+                    .await?
+            }
+            None => false,
+        };
+        if !granted {
+            shares.record_share_access(record(false)).await?;
+            return Err(AppError::Unauthorized {
+                resource: "ShareLink".to_string(),
+                message: "A valid password is required".to_string(),
+            });
+        }
+    }
+
+    let mut subtree = tasks
+        .list_task_subtree(ListTaskSubtreeInput {
+            user_id: link.created_by.clone(),
+            task_id: link.task_id.clone(),
+        })
+        .await?
+        .into_iter();
+    let task = subtree
+        .next()
+        .ok_or_else(|| AppError::not_found("ShareLink", "Share link not found"))?;
+
+    shares.record_share_access(record(true)).await?;
+
+    Ok(SharedTask {
+        expires_at: link.expires_at,
+        task,
+        subtasks: subtree.collect(),
+    })
+}
@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use domain::error::AppError;
+use domain::share::entity::{IssuedShareLink, ShareAccess, ShareLink};
+use domain::share::inputs::{
+    CheckSharePasswordInput, CountFailedShareAccessInput, CreateShareLinkInput, FindShareLinkInput,
+    ListShareAccessInput, ListShareLinksInput, RecordShareAccessInput, RevokeShareLinkInput,
+};
+
+#[async_trait]
+pub trait ShareRepository: Send + Sync {
+    async fn create_share_link(
+        &self,
+        input: CreateShareLinkInput,
+    ) -> Result<IssuedShareLink, AppError>;
+    async fn list_share_links(
+        &self,
+        input: ListShareLinksInput,
+    ) -> Result<Vec<ShareLink>, AppError>;
+    async fn revoke_share_link(&self, input: RevokeShareLinkInput) -> Result<ShareLink, AppError>;
+    async fn list_share_access(
+        &self,
+        input: ListShareAccessInput,
+    ) -> Result<Vec<ShareAccess>, AppError>;
+    async fn find_share_link(
+        &self,
+        input: FindShareLinkInput,
+    ) -> Result<Option<ShareLink>, AppError>;
+    async fn check_share_password(&self, input: CheckSharePasswordInput) -> Result<bool, AppError>;
+    async fn count_failed_share_access(
+        &self,
+        input: CountFailedShareAccessInput,
+    ) -> Result<i64, AppError>;
+    async fn record_share_access(&self, input: RecordShareAccessInput) -> Result<(), AppError>;
+}
@@ -0,0 +1,12 @@
+use domain::error::AppError;
+use domain::share::entity::ShareLink;
+use domain::share::inputs::RevokeShareLinkInput;
+
+use super::repository::ShareRepository;
+
+pub async fn revoke_share_link<R: ShareRepository + ?Sized>(
+    repo: &R,
+    input: RevokeShareLinkInput,
+) -> Result<ShareLink, AppError> {
+    repo.revoke_share_link(input).await
+}
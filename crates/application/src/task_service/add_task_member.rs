@@ -0,0 +1,19 @@
+use domain::error::AppError;
+use domain::task::inputs::AddTaskMemberInput;
+
+use super::repository::TaskRepository;
+
+pub async fn add_task_member<R: TaskRepository + ?Sized>(
+    repo: &R,
+    input: AddTaskMemberInput,
+) -> Result<(), AppError> {
+    if input.member_id.trim().is_empty() {
+        return Err(AppError::validation(
+            "invalid_member",
+            "Member id is required",
+            Some("user_id".to_string()),
+        ));
+    }
+
+    repo.add_task_member(input).await
+}
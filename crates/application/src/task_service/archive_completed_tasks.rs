@@ -0,0 +1,20 @@
+use domain::error::AppError;
+use domain::task::inputs::ArchiveCompletedTasksInput;
+
+use super::repository::TaskRepository;
+
+// Archives in batches so a large backlog never holds row locks on every
+// completed task at once; a short batch means nothing is left to archive.
+pub async fn archive_completed_tasks<R: TaskRepository + ?Sized>(
+    repo: &R,
+    input: ArchiveCompletedTasksInput,
+) -> Result<u64, AppError> {
+    let mut total = 0;
+    loop {
+        let archived = repo.archive_completed_tasks(input.clone()).await?;
+        total += archived;
+        if archived < input.batch_size.max(1) as u64 {
+            return Ok(total);
+        }
+    }
+}
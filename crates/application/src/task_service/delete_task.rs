@@ -6,6 +6,6 @@ use super::repository::TaskRepository;
 pub async fn delete_task<R: TaskRepository + ?Sized>(
     repo: &R,
     input: DeleteTaskInput,
-) -> Result<i64, AppError> {
+) -> Result<(), AppError> {
     repo.delete_task(input).await
 }
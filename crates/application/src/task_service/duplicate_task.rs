@@ -0,0 +1,30 @@
+use domain::error::AppError;
+use domain::task::duplicate::duplicate_tree;
+use domain::task::entity::Task;
+use domain::task::inputs::{CreateTaskTreeInput, DuplicateTaskInput, ListTaskSubtreeInput};
+
+use super::repository::TaskRepository;
+
+// The copy sits next to the original and belongs to the caller alone. Only
+// subtasks the caller can see are copied; the tree is created in one
+// transaction, so a failure leaves nothing behind.
+pub async fn duplicate_task<R: TaskRepository + ?Sized>(
+    repo: &R,
+    input: DuplicateTaskInput,
+) -> Result<Vec<Task>, AppError> {
+    let subtree = repo
+        .list_task_subtree(ListTaskSubtreeInput {
+            user_id: input.user_id.clone(),
+            task_id: input.task_id,
+        })
+        .await?;
+    let copy = duplicate_tree(&subtree, &input.options)?
+        .ok_or_else(|| AppError::not_found("Task", "Task not found"))?;
+
+    repo.create_task_tree(CreateTaskTreeInput {
+        user_id: input.user_id,
+        parent_task_id: subtree[0].parent_task_id.clone(),
+        tasks: vec![copy],
+    })
+    .await
+}
@@ -0,0 +1,36 @@
+use chrono_tz::Tz;
+use domain::error::AppError;
+use domain::task::entity::Task;
+use domain::task::inputs::{FilterTasksInput, ListTasksInput};
+
+use super::repository::TaskRepository;
+use crate::user_service::get_user_timezone::get_user_timezone;
+use crate::user_service::repository::UserRepository;
+
+pub async fn filter_tasks<R, U>(
+    tasks: &R,
+    users: &U,
+    input: FilterTasksInput,
+) -> Result<Vec<Task>, AppError>
+where
+    R: TaskRepository + ?Sized,
+    U: UserRepository + ?Sized,
+{
+    // Only relative due dates depend on the user's day boundaries, so skip
+    // the user lookup for every other query.
+    let timezone = if input.filter.needs_timezone() {
+        get_user_timezone(users, &input.user_id).await?
+    } else {
+        Tz::UTC
+    };
+    let query = input.filter.parse(&input.user_id, input.now, timezone)?;
+
+    tasks
+        .list_tasks(ListTasksInput {
+            user_id: input.user_id,
+            page: input.page,
+            limit: input.limit,
+            query,
+        })
+        .await
+}
@@ -0,0 +1,22 @@
+use domain::error::AppError;
+use domain::task::inputs::{GetTaskInput, ListTaskSubtreeInput};
+use domain::task::rollup::TaskRollup;
+
+use super::repository::TaskRepository;
+
+pub async fn get_task_rollup<R: TaskRepository + ?Sized>(
+    repo: &R,
+    input: GetTaskInput,
+) -> Result<TaskRollup, AppError> {
+    let tasks = repo
+        .list_task_subtree(ListTaskSubtreeInput {
+            user_id: input.user_id,
+            task_id: input.task_id.clone(),
+        })
+        .await?;
+    if tasks.is_empty() {
+        return Err(AppError::not_found("Task", "Task not found"));
+    }
+
+    Ok(TaskRollup::from_tasks(&input.task_id, &tasks))
+}
@@ -0,0 +1,67 @@
+use domain::error::AppError;
+use domain::task::import::{
+    ImportFailure, ImportLineError, ImportSummary, IMPORT_BATCH_SIZE, MAX_IMPORT_ROWS,
+};
+use domain::task::inputs::{CreateTasksInput, ImportTasksInput};
+
+use super::repository::TaskRepository;
+
+// Valid rows are imported even when others fail; each batch is its own
+// transaction, so a database error stops the import after the last
+// committed batch and is reported alongside what was imported.
+pub async fn import_tasks<R: TaskRepository + ?Sized>(
+    repo: &R,
+    input: ImportTasksInput,
+) -> Result<ImportSummary, AppError> {
+    if input.rows.len() > MAX_IMPORT_ROWS {
+        return Err(AppError::validation(
+            "too_many_rows",
+            format!("An import can contain at most {MAX_IMPORT_ROWS} tasks"),
+            None,
+        ));
+    }
+
+    let total = input.rows.len();
+    let mut valid = Vec::new();
+    let mut errors = Vec::new();
+    for row in input.rows {
+        let parsed = row.and_then(|task| {
+            let line = task.line;
+            task.into_create_input(&input.user_id, input.max_content_chars, input.now)
+                .map_err(|error| ImportLineError::from_error(line, error))
+        });
+        match parsed {
+            Ok(task) => valid.push(task),
+            Err(error) => errors.push(error),
+        }
+    }
+    errors.sort_by_key(|error| error.line);
+
+    let mut imported = 0;
+    let mut failure = None;
+    if !input.dry_run {
+        for batch in valid.chunks(IMPORT_BATCH_SIZE) {
+            match repo
+                .create_tasks(CreateTasksInput {
+                    tasks: batch.to_vec(),
+                })
+                .await
+            {
+                Ok(created) => imported += created.len(),
+                Err(error) => {
+                    failure = Some(ImportFailure::from(error));
+                    break;
+                }
+            }
+        }
+    }
+
+    Ok(ImportSummary {
+        dry_run: input.dry_run,
+        total,
+        valid: valid.len(),
+        imported,
+        errors,
+        failure,
+    })
+}
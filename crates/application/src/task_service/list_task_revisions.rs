@@ -0,0 +1,19 @@
+use domain::error::AppError;
+use domain::task::inputs::{GetTaskInput, ListTaskRevisionsInput};
+use domain::task::revision::TaskRevision;
+
+use super::repository::TaskRepository;
+
+pub async fn list_task_revisions<R: TaskRepository + ?Sized>(
+    repo: &R,
+    input: ListTaskRevisionsInput,
+) -> Result<Vec<TaskRevision>, AppError> {
+    repo.get_task(GetTaskInput {
+        user_id: input.user_id.clone(),
+        task_id: input.task_id.clone(),
+    })
+    .await?
+    .ok_or_else(|| AppError::not_found("Task", "Task not found"))?;
+
+    repo.list_task_revisions(input).await
+}
@@ -0,0 +1,70 @@
+use domain::error::AppError;
+use domain::task::inputs::{GetTaskInput, GetTaskRevisionInput, MergeTaskInput, UpdateTaskInput};
+use domain::task::merge::{merge_text, ContentConflict, TaskMerge, TextMerge};
+use domain::task::validation::validate_content;
+
+use super::repository::TaskRepository;
+
+pub async fn merge_task<R: TaskRepository + ?Sized>(
+    repo: &R,
+    input: MergeTaskInput,
+) -> Result<TaskMerge, AppError> {
+    validate_content(&input.content, input.max_content_chars)?;
+
+    let task = repo
+        .get_task(GetTaskInput {
+            user_id: input.user_id.clone(),
+            task_id: input.task_id.clone(),
+        })
+        .await?
+        .ok_or_else(|| AppError::not_found("Task", "Task not found"))?;
+
+    let content = if task.version == input.base_version {
+        input.content
+    } else {
+        let base = repo
+            .get_task_revision(GetTaskRevisionInput {
+                user_id: input.user_id.clone(),
+                task_id: input.task_id.clone(),
+                version: input.base_version,
+            })
+            .await?
+            .ok_or_else(|| AppError::not_found("TaskRevision", "Task revision not found"))?
+            .snapshot
+            .content;
+
+        match merge_text(&base, &input.content, &task.content, input.granularity) {
+            TextMerge::Clean(merged) => {
+                validate_content(&merged, input.max_content_chars)?;
+                merged
+            }
+            TextMerge::Conflict(hunks) => {
+                return Ok(TaskMerge::Conflict(ContentConflict {
+                    current_version: task.version,
+                    base,
+                    ours: input.content,
+                    theirs: task.content,
+                    hunks,
+                }))
+            }
+        }
+    };
+
+    if content == task.content {
+        return Ok(TaskMerge::Merged(Box::new(task)));
+    }
+
+    // A write that lands between the read above and this update still fails
+    // the version check; the client can simply merge again.
+    let merged = repo
+        .update_task(UpdateTaskInput {
+            user_id: input.user_id,
+            task_id: input.task_id,
+            content: Some(content),
+            version: task.version,
+            ..Default::default()
+        })
+        .await?;
+
+    Ok(TaskMerge::Merged(Box::new(merged)))
+}
@@ -1,6 +1,29 @@
+pub mod add_task_member;
+pub mod archive_completed_tasks;
 pub mod create_task;
 pub mod delete_task;
+pub mod duplicate_task;
+pub mod filter_tasks;
 pub mod get_task;
+pub mod get_task_rollup;
+pub mod import_tasks;
+pub mod list_task_revisions;
 pub mod list_tasks;
+pub mod merge_task;
+pub mod move_task;
+pub mod next_tasks;
+pub mod patch_task;
+pub mod preview_quick_add;
+pub mod push_task_changes;
+pub mod quick_add_task;
+pub mod reassign_task;
+pub mod remove_task_member;
+pub mod render_task_content;
 pub mod repository;
+pub mod revert_task;
+pub mod set_member_completion;
+pub mod set_task_archived;
+pub mod sync_tasks;
+pub mod task_stats;
+pub mod transfer_tasks;
 pub mod update_task;
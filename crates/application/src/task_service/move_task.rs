@@ -0,0 +1,29 @@
+use domain::error::AppError;
+use domain::task::entity::Task;
+use domain::task::inputs::MoveTaskInput;
+
+use super::repository::TaskRepository;
+
+pub async fn move_task<R: TaskRepository + ?Sized>(
+    repo: &R,
+    input: MoveTaskInput,
+) -> Result<Task, AppError> {
+    if input.before.is_none() && input.after.is_none() {
+        return Err(AppError::validation(
+            "invalid_move",
+            "Either before or after must be provided",
+            None,
+        ));
+    }
+    if input.before.as_deref() == Some(input.task_id.as_str())
+        || input.after.as_deref() == Some(input.task_id.as_str())
+    {
+        return Err(AppError::validation(
+            "invalid_move",
+            "A task cannot be moved relative to itself",
+            None,
+        ));
+    }
+
+    repo.move_task(input).await
+}
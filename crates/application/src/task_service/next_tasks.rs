@@ -0,0 +1,193 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+use domain::error::AppError;
+use domain::task::entity::Task;
+use domain::task::filter::TaskQuery;
+use domain::task::inputs::{ListTasksInput, NextTasksInput, SnoozeFilter};
+use domain::task::next::{
+    ScoreFactor, ScoreReason, ScoreWeights, ScoredTask, AGE_HORIZON_DAYS, DUE_HORIZON_DAYS,
+    MAX_NEXT_CANDIDATES,
+};
+use domain::task::priority::TaskPriority;
+use domain::task::status::TaskStatus;
+
+use super::repository::TaskRepository;
+
+pub async fn next_tasks<R: TaskRepository + ?Sized>(
+    repo: &R,
+    input: NextTasksInput,
+) -> Result<Vec<ScoredTask>, AppError> {
+    input.weights.validate()?;
+
+    // Snoozed tasks stay in the running so the weights decide how far back
+    // they fall; archived tasks are out of sight and left out.
+    let tasks = repo
+        .list_tasks(ListTasksInput {
+            user_id: input.user_id,
+            page: 1,
+            limit: MAX_NEXT_CANDIDATES,
+            query: TaskQuery {
+                statuses: vec![TaskStatus::Pending, TaskStatus::InProgress],
+                snoozed: SnoozeFilter::Include,
+                ..Default::default()
+            },
+        })
+        .await?;
+
+    let mut ranked = rank_tasks(tasks, &input.weights, input.now);
+    ranked.truncate(usize::try_from(input.limit).unwrap_or(0));
+    Ok(ranked)
+}
+
+// Highest score first; ties go to the earlier due date, then the older task.
+pub fn rank_tasks(tasks: Vec<Task>, weights: &ScoreWeights, now: DateTime<Utc>) -> Vec<ScoredTask> {
+    let mut open_subtasks: HashMap<String, usize> = HashMap::new();
+    for task in &tasks {
+        if let Some(parent_task_id) = &task.parent_task_id {
+            if task.status != TaskStatus::Completed {
+                *open_subtasks.entry(parent_task_id.clone()).or_default() += 1;
+            }
+        }
+    }
+
+    let mut scored: Vec<ScoredTask> = tasks
+        .into_iter()
+        .map(|task| {
+            let blocking = open_subtasks.get(&task.task_id).copied().unwrap_or(0);
+            score_task(task, blocking, weights, now)
+        })
+        .collect();
+    scored.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| match (a.task.due_at, b.task.due_at) {
+                (Some(a), Some(b)) => a.cmp(&b),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            })
+            .then_with(|| a.task.created_at.cmp(&b.task.created_at))
+    });
+    scored
+}
+
+// Each factor is scaled to 0..1 and multiplied by its weight. A task counts
+// as blocked while it has open subtasks or is waiting on something.
+pub fn score_task(
+    task: Task,
+    open_subtasks: usize,
+    weights: &ScoreWeights,
+    now: DateTime<Utc>,
+) -> ScoredTask {
+    let mut reasons = Vec::new();
+    let mut add = |factor: ScoreFactor, weight: f64, value: f64, detail: String| {
+        if value != 0.0 && weight != 0.0 {
+            reasons.push(ScoreReason {
+                factor,
+                contribution: weight * value,
+                detail,
+            });
+        }
+    };
+
+    let (priority, label) = match task.priority {
+        Some(TaskPriority::Urgent) => (1.0, "Urgent priority"),
+        Some(TaskPriority::High) => (0.75, "High priority"),
+        Some(TaskPriority::Medium) => (0.5, "Medium priority"),
+        Some(TaskPriority::Low) => (0.25, "Low priority"),
+        None => (0.0, ""),
+    };
+    add(
+        ScoreFactor::Priority,
+        weights.priority,
+        priority,
+        label.to_string(),
+    );
+
+    if let Some(due_at) = task.due_at {
+        let horizon = Duration::days(DUE_HORIZON_DAYS);
+        if due_at <= now {
+            add(
+                ScoreFactor::Due,
+                weights.due,
+                1.0,
+                format!("Overdue by {}", describe(now - due_at)),
+            );
+        } else if due_at - now < horizon {
+            let left = due_at - now;
+            let value = 1.0 - left.num_seconds() as f64 / horizon.num_seconds() as f64;
+            add(
+                ScoreFactor::Due,
+                weights.due,
+                value,
+                format!("Due in {}", describe(left)),
+            );
+        }
+    }
+
+    if open_subtasks > 0 {
+        let noun = if open_subtasks == 1 {
+            "subtask"
+        } else {
+            "subtasks"
+        };
+        add(
+            ScoreFactor::Blocked,
+            -weights.blocked,
+            1.0,
+            format!("Blocked by {open_subtasks} open {noun}"),
+        );
+    } else if let Some(waiting_on) = &task.waiting_on {
+        add(
+            ScoreFactor::Blocked,
+            -weights.blocked,
+            1.0,
+            format!("Waiting on {waiting_on}"),
+        );
+    }
+
+    if let Some(snoozed_until) = task.snoozed_until.filter(|until| *until > now) {
+        add(
+            ScoreFactor::Snoozed,
+            -weights.snoozed,
+            1.0,
+            format!("Snoozed until {}", snoozed_until.to_rfc3339()),
+        );
+    }
+
+    let age = now - task.created_at;
+    let age_value = (age.num_seconds() as f64
+        / Duration::days(AGE_HORIZON_DAYS).num_seconds() as f64)
+        .clamp(0.0, 1.0);
+    add(
+        ScoreFactor::Age,
+        weights.age,
+        age_value,
+        format!("Open for {}", describe(age)),
+    );
+
+    let score = reasons.iter().map(|reason| reason.contribution).sum();
+    ScoredTask {
+        task,
+        score,
+        reasons,
+    }
+}
+
+fn describe(duration: Duration) -> String {
+    let (amount, unit) = if duration.num_days() >= 1 {
+        (duration.num_days(), "day")
+    } else if duration.num_hours() >= 1 {
+        (duration.num_hours(), "hour")
+    } else {
+        (duration.num_minutes().max(1), "minute")
+    };
+    if amount == 1 {
+        format!("1 {unit}")
+    } else {
+        format!("{amount} {unit}s")
+    }
+}
@@ -0,0 +1,62 @@
+use domain::error::AppError;
+use domain::patch::apply_patch;
+use domain::task::entity::Task;
+use domain::task::inputs::{GetTaskInput, PatchTaskInput, UpdateTaskInput};
+use domain::task::revision::TaskSnapshot;
+
+use super::repository::TaskRepository;
+
+pub async fn patch_task<R: TaskRepository + ?Sized>(
+    repo: &R,
+    input: PatchTaskInput,
+) -> Result<Task, AppError> {
+    let task = repo
+        .get_task(GetTaskInput {
+            user_id: input.user_id.clone(),
+            task_id: input.task_id.clone(),
+        })
+        .await?
+        .ok_or_else(|| AppError::not_found("Task", "Task not found"))?;
+
+    let version = input.version.unwrap_or(task.version);
+    if version != task.version {
+        return Err(AppError::version_conflict("Task", i64::from(task.version)));
+    }
+
+    let current = TaskSnapshot::from(&task);
+    let patched = apply_patch(&current, input.format, &input.patch)?;
+    patched.validate(input.max_content_chars)?;
+
+    if patched == current {
+        return Ok(task);
+    }
+
+    repo.update_task(UpdateTaskInput {
+        user_id: input.user_id,
+        task_id: input.task_id,
+        content: (patched.content != current.content).then_some(patched.content),
+        status: (patched.status != current.status).then_some(patched.status),
+        completed_at: (patched.completed_at != current.completed_at)
+            .then_some(patched.completed_at),
+        parent_task_id: (patched.parent_task_id != current.parent_task_id)
+            .then_some(patched.parent_task_id),
+        estimate_minutes: (patched.estimate_minutes != current.estimate_minutes)
+            .then_some(patched.estimate_minutes),
+        remaining_minutes: (patched.remaining_minutes != current.remaining_minutes)
+            .then_some(patched.remaining_minutes),
+        story_points: (patched.story_points != current.story_points)
+            .then_some(patched.story_points),
+        due_at: (patched.due_at != current.due_at).then_some(patched.due_at),
+        labels: (patched.labels != current.labels).then_some(patched.labels),
+        priority: (patched.priority != current.priority).then_some(patched.priority),
+        recurrence: (patched.recurrence != current.recurrence).then_some(patched.recurrence),
+        snoozed_until: (patched.snoozed_until != current.snoozed_until)
+            .then_some(patched.snoozed_until),
+        waiting_on: (patched.waiting_on != current.waiting_on).then_some(patched.waiting_on),
+        assignee_ids: (patched.assignee_ids != current.assignee_ids)
+            .then_some(patched.assignee_ids),
+        group: (patched.group != current.group).then_some(patched.group),
+        version,
+    })
+    .await
+}
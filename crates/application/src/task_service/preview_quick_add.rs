@@ -0,0 +1,19 @@
+use domain::error::AppError;
+use domain::task::inputs::QuickAddTaskInput;
+use domain::task::quick_add::{parse_quick_add, QuickAdd};
+use domain::user::timezone::parse_timezone;
+
+use crate::user_service::get_user_timezone::get_user_timezone;
+use crate::user_service::repository::UserRepository;
+
+pub async fn preview_quick_add<U: UserRepository + ?Sized>(
+    users: &U,
+    input: &QuickAddTaskInput,
+) -> Result<QuickAdd, AppError> {
+    let timezone = match &input.timezone {
+        Some(timezone) => parse_timezone(timezone)?,
+        None => get_user_timezone(users, &input.user_id).await?,
+    };
+
+    parse_quick_add(&input.text, input.now, timezone, input.max_content_chars)
+}
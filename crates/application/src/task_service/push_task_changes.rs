@@ -0,0 +1,67 @@
+use domain::error::AppError;
+use domain::task::inputs::{ApplySyncMutationInput, GetTaskInput, PushTaskChangesInput};
+use domain::task::sync::{SyncMutationResult, SyncOperation, MAX_SYNC_MUTATIONS};
+
+use super::repository::TaskRepository;
+
+// Mutations are applied one at a time, in order, each with its own version
+// check. A rejected mutation does not stop the ones after it; only a database
+// error aborts the push. A mutation id that was already applied returns its
+// saved result without running again.
+pub async fn push_task_changes<R: TaskRepository + ?Sized>(
+    repo: &R,
+    input: PushTaskChangesInput,
+) -> Result<Vec<SyncMutationResult>, AppError> {
+    if input.mutations.len() > MAX_SYNC_MUTATIONS {
+        return Err(AppError::validation(
+            "too_many_mutations",
+            format!("A push can contain at most {MAX_SYNC_MUTATIONS} mutations"),
+            Some("mutations".to_string()),
+        ));
+    }
+
+    let mut results = Vec::with_capacity(input.mutations.len());
+    for mutation in input.mutations {
+        let task_id = match &mutation.operation {
+            SyncOperation::Create(_) => None,
+            SyncOperation::Update(update) => Some(update.task_id.clone()),
+            SyncOperation::Delete(delete) => Some(delete.task_id.clone()),
+        };
+        let mutation_id = mutation.mutation_id.clone();
+        let outcome = repo
+            .apply_sync_mutation(ApplySyncMutationInput {
+                user_id: input.user_id.clone(),
+                mutation,
+            })
+            .await;
+
+        let result = match outcome {
+            Ok(task) => SyncMutationResult {
+                mutation_id,
+                task,
+                error: None,
+            },
+            Err(error @ AppError::Database { .. }) => return Err(error),
+            Err(error) => {
+                let current = match (&error, task_id) {
+                    (AppError::VersionConflict { .. }, Some(task_id)) => {
+                        repo.get_task(GetTaskInput {
+                            user_id: input.user_id.clone(),
+                            task_id,
+                        })
+                        .await?
+                    }
+                    _ => None,
+                };
+                SyncMutationResult {
+                    mutation_id,
+                    task: current,
+                    error: Some(error),
+                }
+            }
+        };
+        results.push(result);
+    }
+
+    Ok(results)
+}
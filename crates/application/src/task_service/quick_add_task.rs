@@ -0,0 +1,31 @@
+use domain::error::AppError;
+use domain::task::entity::Task;
+use domain::task::inputs::{CreateTaskInput, QuickAddTaskInput};
+
+use super::preview_quick_add::preview_quick_add;
+use super::repository::TaskRepository;
+use crate::user_service::repository::UserRepository;
+
+pub async fn quick_add_task<R, U>(
+    tasks: &R,
+    users: &U,
+    input: QuickAddTaskInput,
+) -> Result<Task, AppError>
+where
+    R: TaskRepository + ?Sized,
+    U: UserRepository + ?Sized,
+{
+    let parsed = preview_quick_add(users, &input).await?;
+
+    tasks
+        .create_task(CreateTaskInput {
+            user_id: input.user_id,
+            content: parsed.content,
+            due_at: parsed.due_at,
+            labels: parsed.labels,
+            priority: parsed.priority,
+            recurrence: parsed.recurrence,
+            ..Default::default()
+        })
+        .await
+}
@@ -0,0 +1,22 @@
+use domain::error::AppError;
+use domain::task::entity::Task;
+use domain::task::inputs::{ReassignTaskInput, UpdateTaskInput};
+use domain::task::validation::validate_assignees;
+
+use super::repository::TaskRepository;
+
+pub async fn reassign_task<R: TaskRepository + ?Sized>(
+    repo: &R,
+    input: ReassignTaskInput,
+) -> Result<Task, AppError> {
+    validate_assignees(&input.assignee_ids)?;
+
+    repo.update_task(UpdateTaskInput {
+        user_id: input.user_id,
+        task_id: input.task_id,
+        assignee_ids: Some(input.assignee_ids),
+        version: input.version,
+        ..Default::default()
+    })
+    .await
+}
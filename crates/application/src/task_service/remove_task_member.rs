@@ -0,0 +1,11 @@
+use domain::error::AppError;
+use domain::task::inputs::RemoveTaskMemberInput;
+
+use super::repository::TaskRepository;
+
+pub async fn remove_task_member<R: TaskRepository + ?Sized>(
+    repo: &R,
+    input: RemoveTaskMemberInput,
+) -> Result<(), AppError> {
+    repo.remove_task_member(input).await
+}
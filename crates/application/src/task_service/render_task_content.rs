@@ -0,0 +1,45 @@
+use domain::error::AppError;
+use domain::task::inputs::GetTaskInput;
+use domain::task::markdown::{render_markdown, TaskContent, TaskMention};
+use domain::user::inputs::GetUserByUsernameInput;
+
+use super::repository::TaskRepository;
+use crate::user_service::repository::UserRepository;
+
+pub async fn render_task_content<R, U>(
+    tasks: &R,
+    users: &U,
+    input: GetTaskInput,
+) -> Result<TaskContent, AppError>
+where
+    R: TaskRepository + ?Sized,
+    U: UserRepository + ?Sized,
+{
+    let task = tasks
+        .get_task(input)
+        .await?
+        .ok_or_else(|| AppError::not_found("Task", "Task not found"))?;
+
+    let rendered = render_markdown(&task.content);
+
+    // Unknown usernames are still highlighted in the HTML but not referenced.
+    let mut mentions = Vec::new();
+    for username in rendered.mentions {
+        if let Some(user) = users
+            .get_user_by_username(GetUserByUsernameInput { username })
+            .await?
+        {
+            mentions.push(TaskMention {
+                user_id: user.user_id,
+                username: user.username,
+            });
+        }
+    }
+
+    Ok(TaskContent {
+        content: task.content,
+        html: rendered.html,
+        mentions,
+        checklist: rendered.checklist,
+    })
+}
@@ -2,14 +2,54 @@ use async_trait::async_trait;
 use domain::error::AppError;
 use domain::task::entity::Task;
 use domain::task::inputs::{
-    CreateTaskInput, DeleteTaskInput, GetTaskInput, ListTasksInput, UpdateTaskInput,
+    AddTaskMemberInput, ApplySyncMutationInput, ArchiveCompletedTasksInput, CreateTaskInput,
+    CreateTaskTreeInput, CreateTasksInput, DeleteTaskInput, GetTaskInput, GetTaskRevisionInput,
+    ListTaskChangesInput, ListTaskRevisionsInput, ListTaskSubtreeInput, ListTasksInput,
+    MoveTaskInput, RemoveTaskMemberInput, SetMemberCompletionInput, SetTaskArchivedInput,
+    TaskStatsQuery, TransferTaskLinksInput, UpdateTaskInput,
 };
+use domain::task::revision::TaskRevision;
+use domain::task::stats::TaskStats;
+use domain::task::sync::TaskChanges;
+use domain::task::transfer::TaskTransfer;
 
 #[async_trait]
 pub trait TaskRepository: Send + Sync {
     async fn create_task(&self, input: CreateTaskInput) -> Result<Task, AppError>;
-    async fn update_task(&self, input: UpdateTaskInput) -> Result<i64, AppError>;
-    async fn delete_task(&self, input: DeleteTaskInput) -> Result<i64, AppError>;
+    async fn create_task_tree(&self, input: CreateTaskTreeInput) -> Result<Vec<Task>, AppError>;
+    async fn create_tasks(&self, input: CreateTasksInput) -> Result<Vec<Task>, AppError>;
+    async fn update_task(&self, input: UpdateTaskInput) -> Result<Task, AppError>;
+    async fn delete_task(&self, input: DeleteTaskInput) -> Result<(), AppError>;
     async fn get_task(&self, input: GetTaskInput) -> Result<Option<Task>, AppError>;
     async fn list_tasks(&self, input: ListTasksInput) -> Result<Vec<Task>, AppError>;
+    async fn move_task(&self, input: MoveTaskInput) -> Result<Task, AppError>;
+    async fn list_task_subtree(&self, input: ListTaskSubtreeInput) -> Result<Vec<Task>, AppError>;
+    async fn add_task_member(&self, input: AddTaskMemberInput) -> Result<(), AppError>;
+    async fn remove_task_member(&self, input: RemoveTaskMemberInput) -> Result<(), AppError>;
+    async fn list_task_revisions(
+        &self,
+        input: ListTaskRevisionsInput,
+    ) -> Result<Vec<TaskRevision>, AppError>;
+    async fn get_task_revision(
+        &self,
+        input: GetTaskRevisionInput,
+    ) -> Result<Option<TaskRevision>, AppError>;
+    async fn set_task_archived(&self, input: SetTaskArchivedInput) -> Result<Task, AppError>;
+    async fn set_member_completion(
+        &self,
+        input: SetMemberCompletionInput,
+    ) -> Result<Task, AppError>;
+    async fn archive_completed_tasks(
+        &self,
+        input: ArchiveCompletedTasksInput,
+    ) -> Result<u64, AppError>;
+    async fn task_stats(&self, input: TaskStatsQuery) -> Result<TaskStats, AppError>;
+    async fn list_task_changes(&self, input: ListTaskChangesInput)
+        -> Result<TaskChanges, AppError>;
+    async fn transfer_tasks(&self, input: TransferTaskLinksInput)
+        -> Result<TaskTransfer, AppError>;
+    async fn apply_sync_mutation(
+        &self,
+        input: ApplySyncMutationInput,
+    ) -> Result<Option<Task>, AppError>;
 }
@@ -0,0 +1,84 @@
+use domain::error::AppError;
+use domain::task::inputs::{GetTaskInput, GetTaskRevisionInput, RevertTaskInput, UpdateTaskInput};
+use domain::task::revision::RevertedTask;
+
+use super::repository::TaskRepository;
+
+pub async fn revert_task<R: TaskRepository + ?Sized>(
+    repo: &R,
+    input: RevertTaskInput,
+) -> Result<RevertedTask, AppError> {
+    let revision = repo
+        .get_task_revision(GetTaskRevisionInput {
+            user_id: input.user_id.clone(),
+            task_id: input.task_id.clone(),
+            version: input.target_version,
+        })
+        .await?
+        .ok_or_else(|| AppError::not_found("TaskRevision", "Task revision not found"))?;
+    let snapshot = revision.snapshot;
+
+    // The old parent may have been deleted and old assignees may have left the
+    // task since; those fields are left as they are rather than failing.
+    let mut skipped_fields = Vec::new();
+    let parent_task_id = match snapshot.parent_task_id {
+        Some(parent_task_id) => {
+            let parent = repo
+                .get_task(GetTaskInput {
+                    user_id: input.user_id.clone(),
+                    task_id: parent_task_id.clone(),
+                })
+                .await?;
+            if parent.is_some() {
+                Some(Some(parent_task_id))
+            } else {
+                skipped_fields.push("parent_task_id".to_string());
+                None
+            }
+        }
+        None => Some(None),
+    };
+
+    let mut assignee_ids = Vec::with_capacity(snapshot.assignee_ids.len());
+    for assignee_id in snapshot.assignee_ids {
+        let member = repo
+            .get_task(GetTaskInput {
+                user_id: assignee_id.clone(),
+                task_id: input.task_id.clone(),
+            })
+            .await?;
+        if member.is_some() {
+            assignee_ids.push(assignee_id);
+        } else if !skipped_fields.iter().any(|field| field == "assignee_ids") {
+            skipped_fields.push("assignee_ids".to_string());
+        }
+    }
+
+    let task = repo
+        .update_task(UpdateTaskInput {
+            user_id: input.user_id,
+            task_id: input.task_id,
+            content: Some(snapshot.content),
+            status: Some(snapshot.status),
+            completed_at: Some(snapshot.completed_at),
+            parent_task_id,
+            estimate_minutes: Some(snapshot.estimate_minutes),
+            remaining_minutes: Some(snapshot.remaining_minutes),
+            story_points: Some(snapshot.story_points),
+            due_at: Some(snapshot.due_at),
+            labels: Some(snapshot.labels),
+            priority: Some(snapshot.priority),
+            recurrence: Some(snapshot.recurrence),
+            snoozed_until: Some(snapshot.snoozed_until),
+            waiting_on: Some(snapshot.waiting_on),
+            assignee_ids: Some(assignee_ids),
+            group: Some(snapshot.group),
+            version: input.version,
+        })
+        .await?;
+
+    Ok(RevertedTask {
+        task,
+        skipped_fields,
+    })
+}
@@ -0,0 +1,12 @@
+use domain::error::AppError;
+use domain::task::entity::Task;
+use domain::task::inputs::SetMemberCompletionInput;
+
+use super::repository::TaskRepository;
+
+pub async fn set_member_completion<R: TaskRepository + ?Sized>(
+    repo: &R,
+    input: SetMemberCompletionInput,
+) -> Result<Task, AppError> {
+    repo.set_member_completion(input).await
+}
@@ -0,0 +1,12 @@
+use domain::error::AppError;
+use domain::task::entity::Task;
+use domain::task::inputs::SetTaskArchivedInput;
+
+use super::repository::TaskRepository;
+
+pub async fn set_task_archived<R: TaskRepository + ?Sized>(
+    repo: &R,
+    input: SetTaskArchivedInput,
+) -> Result<Task, AppError> {
+    repo.set_task_archived(input).await
+}
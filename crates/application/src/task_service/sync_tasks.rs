@@ -0,0 +1,19 @@
+use domain::error::AppError;
+use domain::task::inputs::{ListTaskChangesInput, SyncTasksInput};
+use domain::task::sync::{parse_sync_token, TaskChanges};
+
+use super::repository::TaskRepository;
+
+pub async fn sync_tasks<R: TaskRepository + ?Sized>(
+    repo: &R,
+    input: SyncTasksInput,
+) -> Result<TaskChanges, AppError> {
+    let since = input.since.as_deref().map(parse_sync_token).transpose()?;
+
+    repo.list_task_changes(ListTaskChangesInput {
+        user_id: input.user_id,
+        since,
+        limit: input.limit,
+    })
+    .await
+}
@@ -0,0 +1,33 @@
+use domain::error::AppError;
+use domain::task::inputs::{TaskStatsInput, TaskStatsQuery};
+use domain::task::stats::TaskStats;
+use domain::time_entry::validation::validate_report_range;
+
+use super::repository::TaskRepository;
+use crate::user_service::get_user_timezone::get_user_timezone;
+use crate::user_service::repository::UserRepository;
+
+pub async fn task_stats<R, U>(
+    tasks: &R,
+    users: &U,
+    input: TaskStatsInput,
+) -> Result<TaskStats, AppError>
+where
+    R: TaskRepository + ?Sized,
+    U: UserRepository + ?Sized,
+{
+    validate_report_range(input.from, input.to)?;
+
+    let timezone = get_user_timezone(users, &input.user_id).await?;
+
+    tasks
+        .task_stats(TaskStatsQuery {
+            user_id: input.user_id,
+            from: input.from,
+            to: input.to,
+            bucket: input.bucket,
+            timezone: timezone.name().to_string(),
+            now: input.now,
+        })
+        .await
+}
@@ -0,0 +1,100 @@
+use chrono_tz::Tz;
+use domain::error::AppError;
+use domain::task::inputs::{
+    ArchiveFilter, ListTasksInput, SnoozeFilter, TransferTaskLinksInput, TransferTasksInput,
+};
+use domain::task::transfer::{check_transfer, TaskTransfer, MAX_TRANSFER_TASKS};
+
+use super::repository::TaskRepository;
+use crate::user_service::get_user_timezone::get_user_timezone;
+use crate::user_service::repository::UserRepository;
+
+const TRANSFER_PAGE_SIZE: i64 = 500;
+
+pub async fn transfer_tasks<R, U>(
+    tasks: &R,
+    users: &U,
+    input: TransferTasksInput,
+) -> Result<TaskTransfer, AppError>
+where
+    R: TaskRepository + ?Sized,
+    U: UserRepository + ?Sized,
+{
+    check_transfer(
+        &input.actor_id,
+        input.is_admin,
+        &input.from_user_id,
+        &input.to_user_id,
+    )?;
+
+    let task_ids = match input.task_id {
+        Some(task_id) => vec![task_id],
+        None => matching_task_ids(tasks, users, &input).await?,
+    };
+
+    tasks
+        .transfer_tasks(TransferTaskLinksInput {
+            actor_id: input.actor_id,
+            from_user_id: input.from_user_id,
+            to_user_id: input.to_user_id,
+            task_ids,
+        })
+        .await
+}
+
+// The filter is read the way the tasks' owner would see it, including their
+// day boundaries for relative due dates.
+async fn matching_task_ids<R, U>(
+    tasks: &R,
+    users: &U,
+    input: &TransferTasksInput,
+) -> Result<Vec<String>, AppError>
+where
+    R: TaskRepository + ?Sized,
+    U: UserRepository + ?Sized,
+{
+    let timezone = if input.filter.needs_timezone() {
+        get_user_timezone(users, &input.from_user_id).await?
+    } else {
+        Tz::UTC
+    };
+    let mut query = input
+        .filter
+        .parse(&input.from_user_id, input.now, timezone)?;
+    // Unlike a task list, a transfer covers snoozed and archived tasks unless
+    // the filter says otherwise, the same as transferring everything.
+    if input.filter.include_snoozed.is_none() {
+        query.snoozed = SnoozeFilter::Include;
+    }
+    if input.filter.archived.is_none() {
+        query.archived = ArchiveFilter::Include;
+    }
+
+    let mut task_ids = Vec::new();
+    for page in 1.. {
+        let batch = tasks
+            .list_tasks(ListTasksInput {
+                user_id: input.from_user_id.clone(),
+                page,
+                limit: TRANSFER_PAGE_SIZE,
+                query: query.clone(),
+            })
+            .await?;
+        let done = (batch.len() as i64) < TRANSFER_PAGE_SIZE;
+        task_ids.extend(batch.into_iter().map(|task| task.task_id));
+        if task_ids.len() > MAX_TRANSFER_TASKS {
+            return Err(AppError::validation(
+                "too_many_tasks",
+                format!(
+                    "A transfer can move at most {MAX_TRANSFER_TASKS} tasks; narrow the filter"
+                ),
+                Some("filter".to_string()),
+            ));
+        }
+        if done {
+            break;
+        }
+    }
+
+    Ok(task_ids)
+}
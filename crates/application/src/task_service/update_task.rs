@@ -1,4 +1,5 @@
 use domain::error::AppError;
+use domain::task::entity::Task;
 use domain::task::inputs::UpdateTaskInput;
 
 use super::repository::TaskRepository;
@@ -6,6 +7,6 @@ use super::repository::TaskRepository;
 pub async fn update_task<R: TaskRepository + ?Sized>(
     repo: &R,
     input: UpdateTaskInput,
-) -> Result<i64, AppError> {
+) -> Result<Task, AppError> {
     repo.update_task(input).await
 }
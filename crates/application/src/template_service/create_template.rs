@@ -0,0 +1,15 @@
+use domain::error::AppError;
+use domain::template::entity::TaskTemplate;
+use domain::template::inputs::CreateTemplateInput;
+use domain::template::validation::validate_template;
+
+use super::repository::TemplateRepository;
+
+pub async fn create_template<R: TemplateRepository + ?Sized>(
+    repo: &R,
+    input: CreateTemplateInput,
+) -> Result<TaskTemplate, AppError> {
+    validate_template(&input.name, &input.tasks)?;
+
+    repo.create_template(input).await
+}
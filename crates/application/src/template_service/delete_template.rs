@@ -0,0 +1,11 @@
+use domain::error::AppError;
+use domain::template::inputs::DeleteTemplateInput;
+
+use super::repository::TemplateRepository;
+
+pub async fn delete_template<R: TemplateRepository + ?Sized>(
+    repo: &R,
+    input: DeleteTemplateInput,
+) -> Result<(), AppError> {
+    repo.delete_template(input).await
+}
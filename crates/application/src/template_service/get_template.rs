@@ -0,0 +1,14 @@
+use domain::error::AppError;
+use domain::template::entity::TaskTemplate;
+use domain::template::inputs::GetTemplateInput;
+
+use super::repository::TemplateRepository;
+
+pub async fn get_template<R: TemplateRepository + ?Sized>(
+    repo: &R,
+    input: GetTemplateInput,
+) -> Result<TaskTemplate, AppError> {
+    repo.get_template(input)
+        .await?
+        .ok_or_else(|| AppError::not_found("Template", "Template not found"))
+}
@@ -0,0 +1,48 @@
+use domain::error::AppError;
+use domain::task::entity::Task;
+use domain::task::inputs::{CreateTaskTreeInput, NewTaskNode};
+use domain::task::validation::{validate_content, validate_labels};
+use domain::template::inputs::{GetTemplateInput, InstantiateTemplateInput};
+use domain::template::render::render_tasks;
+
+use super::repository::TemplateRepository;
+use crate::task_service::repository::TaskRepository;
+
+pub async fn instantiate_template<T, R>(
+    templates: &T,
+    tasks: &R,
+    input: InstantiateTemplateInput,
+) -> Result<Vec<Task>, AppError>
+where
+    T: TemplateRepository + ?Sized,
+    R: TaskRepository + ?Sized,
+{
+    let template = templates
+        .get_template(GetTemplateInput {
+            user_id: input.user_id.clone(),
+            template_id: input.template_id,
+        })
+        .await?
+        .ok_or_else(|| AppError::not_found("Template", "Template not found"))?;
+
+    let nodes = render_tasks(&template.tasks, &input.variables, input.start_at)?;
+    validate_nodes(&nodes, input.max_content_chars)?;
+
+    tasks
+        .create_task_tree(CreateTaskTreeInput {
+            user_id: input.user_id,
+            parent_task_id: input.parent_task_id,
+            tasks: nodes,
+        })
+        .await
+}
+
+fn validate_nodes(nodes: &[NewTaskNode], max_content_chars: usize) -> Result<(), AppError> {
+    for node in nodes {
+        validate_content(&node.content, max_content_chars)?;
+        validate_labels(&node.labels)?;
+        validate_nodes(&node.subtasks, max_content_chars)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,12 @@
+use domain::error::AppError;
+use domain::template::entity::TaskTemplate;
+use domain::template::inputs::ListTemplatesInput;
+
+use super::repository::TemplateRepository;
+
+pub async fn list_templates<R: TemplateRepository + ?Sized>(
+    repo: &R,
+    input: ListTemplatesInput,
+) -> Result<Vec<TaskTemplate>, AppError> {
+    repo.list_templates(input).await
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use domain::error::AppError;
+use domain::template::entity::TaskTemplate;
+use domain::template::inputs::{
+    CreateTemplateInput, DeleteTemplateInput, GetTemplateInput, ListTemplatesInput,
+    UpdateTemplateInput,
+};
+
+#[async_trait]
+pub trait TemplateRepository: Send + Sync {
+    async fn create_template(&self, input: CreateTemplateInput) -> Result<TaskTemplate, AppError>;
+    async fn update_template(&self, input: UpdateTemplateInput) -> Result<TaskTemplate, AppError>;
+    async fn delete_template(&self, input: DeleteTemplateInput) -> Result<(), AppError>;
+    async fn get_template(&self, input: GetTemplateInput)
+        -> Result<Option<TaskTemplate>, AppError>;
+    async fn list_templates(
+        &self,
+        input: ListTemplatesInput,
+    ) -> Result<Vec<TaskTemplate>, AppError>;
+}
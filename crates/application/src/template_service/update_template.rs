@@ -0,0 +1,15 @@
+use domain::error::AppError;
+use domain::template::entity::TaskTemplate;
+use domain::template::inputs::UpdateTemplateInput;
+use domain::template::validation::validate_template;
+
+use super::repository::TemplateRepository;
+
+pub async fn update_template<R: TemplateRepository + ?Sized>(
+    repo: &R,
+    input: UpdateTemplateInput,
+) -> Result<TaskTemplate, AppError> {
+    validate_template(&input.name, &input.tasks)?;
+
+    repo.update_template(input).await
+}
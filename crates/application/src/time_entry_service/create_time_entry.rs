@@ -0,0 +1,17 @@
+use chrono::Utc;
+use domain::error::AppError;
+use domain::time_entry::entity::TimeEntry;
+use domain::time_entry::inputs::CreateTimeEntryInput;
+use domain::time_entry::validation::{validate_entry_range, validate_note};
+
+use super::repository::TimeEntryRepository;
+
+pub async fn create_time_entry<R: TimeEntryRepository + ?Sized>(
+    repo: &R,
+    input: CreateTimeEntryInput,
+) -> Result<TimeEntry, AppError> {
+    validate_entry_range(input.started_at, input.ended_at, Utc::now())?;
+    validate_note(input.note.as_deref())?;
+
+    repo.create_time_entry(input).await
+}
@@ -0,0 +1,11 @@
+use domain::error::AppError;
+use domain::time_entry::inputs::DeleteTimeEntryInput;
+
+use super::repository::TimeEntryRepository;
+
+pub async fn delete_time_entry<R: TimeEntryRepository + ?Sized>(
+    repo: &R,
+    input: DeleteTimeEntryInput,
+) -> Result<(), AppError> {
+    repo.delete_time_entry(input).await
+}
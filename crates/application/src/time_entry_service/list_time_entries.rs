@@ -0,0 +1,12 @@
+use domain::error::AppError;
+use domain::time_entry::entity::TimeEntry;
+use domain::time_entry::inputs::ListTimeEntriesInput;
+
+use super::repository::TimeEntryRepository;
+
+pub async fn list_time_entries<R: TimeEntryRepository + ?Sized>(
+    repo: &R,
+    input: ListTimeEntriesInput,
+) -> Result<Vec<TimeEntry>, AppError> {
+    repo.list_time_entries(input).await
+}
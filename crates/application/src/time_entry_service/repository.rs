@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use domain::error::AppError;
+use domain::time_entry::entity::TimeEntry;
+use domain::time_entry::inputs::{
+    CreateTimeEntryInput, DeleteTimeEntryInput, ListTimeEntriesInput, StartTimerInput,
+    StopTimerInput, TimeReportQuery,
+};
+use domain::time_entry::report::TimeReportRow;
+
+#[async_trait]
+pub trait TimeEntryRepository: Send + Sync {
+    async fn start_timer(&self, input: StartTimerInput) -> Result<TimeEntry, AppError>;
+    async fn stop_timer(&self, input: StopTimerInput) -> Result<TimeEntry, AppError>;
+    async fn create_time_entry(&self, input: CreateTimeEntryInput) -> Result<TimeEntry, AppError>;
+    async fn delete_time_entry(&self, input: DeleteTimeEntryInput) -> Result<(), AppError>;
+    async fn list_time_entries(
+        &self,
+        input: ListTimeEntriesInput,
+    ) -> Result<Vec<TimeEntry>, AppError>;
+    async fn time_report(&self, input: TimeReportQuery) -> Result<Vec<TimeReportRow>, AppError>;
+}
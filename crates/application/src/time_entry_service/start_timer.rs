@@ -0,0 +1,15 @@
+use domain::error::AppError;
+use domain::time_entry::entity::TimeEntry;
+use domain::time_entry::inputs::StartTimerInput;
+use domain::time_entry::validation::validate_note;
+
+use super::repository::TimeEntryRepository;
+
+pub async fn start_timer<R: TimeEntryRepository + ?Sized>(
+    repo: &R,
+    input: StartTimerInput,
+) -> Result<TimeEntry, AppError> {
+    validate_note(input.note.as_deref())?;
+
+    repo.start_timer(input).await
+}
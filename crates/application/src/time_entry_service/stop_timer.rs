@@ -0,0 +1,12 @@
+use domain::error::AppError;
+use domain::time_entry::entity::TimeEntry;
+use domain::time_entry::inputs::StopTimerInput;
+
+use super::repository::TimeEntryRepository;
+
+pub async fn stop_timer<R: TimeEntryRepository + ?Sized>(
+    repo: &R,
+    input: StopTimerInput,
+) -> Result<TimeEntry, AppError> {
+    repo.stop_timer(input).await
+}
@@ -0,0 +1,34 @@
+use domain::error::AppError;
+use domain::time_entry::inputs::{TimeReportInput, TimeReportQuery};
+use domain::time_entry::report::TimeReport;
+use domain::time_entry::validation::validate_report_range;
+
+use super::repository::TimeEntryRepository;
+use crate::user_service::get_user_timezone::get_user_timezone;
+use crate::user_service::repository::UserRepository;
+
+pub async fn time_report<R, U>(
+    repo: &R,
+    users: &U,
+    input: TimeReportInput,
+) -> Result<TimeReport, AppError>
+where
+    R: TimeEntryRepository + ?Sized,
+    U: UserRepository + ?Sized,
+{
+    validate_report_range(input.from, input.to)?;
+
+    let timezone = get_user_timezone(users, &input.user_id).await?;
+
+    let (from, to) = (input.from, input.to);
+    let rows = repo
+        .time_report(TimeReportQuery {
+            user_id: input.user_id,
+            from: input.from,
+            to: input.to,
+            timezone: timezone.name().to_string(),
+        })
+        .await?;
+
+    Ok(TimeReport::from_rows(from, to, rows))
+}
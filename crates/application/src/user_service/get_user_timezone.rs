@@ -0,0 +1,21 @@
+use chrono_tz::Tz;
+use domain::error::AppError;
+use domain::user::inputs::GetUserInput;
+use domain::user::timezone::{parse_timezone, DEFAULT_TIMEZONE};
+
+use super::repository::UserRepository;
+
+pub async fn get_user_timezone<U: UserRepository + ?Sized>(
+    users: &U,
+    user_id: &str,
+) -> Result<Tz, AppError> {
+    let timezone = users
+        .get_user(GetUserInput {
+            user_id: user_id.to_string(),
+        })
+        .await?
+        .map(|user| user.timezone)
+        .unwrap_or_else(|| DEFAULT_TIMEZONE.to_string());
+
+    parse_timezone(&timezone)
+}
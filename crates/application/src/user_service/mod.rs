@@ -3,6 +3,8 @@ pub mod delete_user;
 pub mod get_user;
 pub mod get_user_by_email;
 pub mod get_user_by_username;
+pub mod get_user_timezone;
 pub mod list_users;
+pub mod patch_user;
 pub mod repository;
 pub mod update_user;
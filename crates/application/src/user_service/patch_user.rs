@@ -0,0 +1,45 @@
+use domain::error::AppError;
+use domain::patch::apply_patch;
+use domain::user::entity::{User, UserProfile};
+use domain::user::inputs::{GetUserInput, PatchUserInput, UpdateUserInput};
+
+use super::repository::UserRepository;
+
+pub async fn patch_user<R: UserRepository + ?Sized>(
+    repo: &R,
+    input: PatchUserInput,
+) -> Result<User, AppError> {
+    let user = repo
+        .get_user(GetUserInput {
+            user_id: input.user_id.clone(),
+        })
+        .await?
+        .ok_or_else(|| AppError::not_found("User", "User not found"))?;
+
+    let expected_updated_at = input.expected_updated_at.unwrap_or(user.updated_at);
+    if expected_updated_at != user.updated_at {
+        return Err(AppError::version_conflict(
+            "User",
+            user.updated_at.timestamp_micros(),
+        ));
+    }
+
+    let current = UserProfile::from(&user);
+    let patched = apply_patch(&current, input.format, &input.patch)?;
+    patched.validate()?;
+
+    if patched == current {
+        return Ok(user);
+    }
+
+    repo.update_user(UpdateUserInput {
+        user_id: input.user_id,
+        username: Some(patched.username),
+        email: Some(patched.email),
+        name: Some(patched.name),
+        picture: Some(patched.picture),
+        timezone: Some(patched.timezone),
+        expected_updated_at: Some(expected_updated_at),
+    })
+    .await
+}
@@ -0,0 +1,15 @@
+use domain::error::AppError;
+use domain::view::entity::SavedView;
+use domain::view::inputs::CreateViewInput;
+use domain::view::validation::validate_view;
+
+use super::repository::ViewRepository;
+
+pub async fn create_view<R: ViewRepository + ?Sized>(
+    repo: &R,
+    input: CreateViewInput,
+) -> Result<SavedView, AppError> {
+    validate_view(&input.name, &input.filter)?;
+
+    repo.create_view(input).await
+}
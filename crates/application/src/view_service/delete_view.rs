@@ -0,0 +1,11 @@
+use domain::error::AppError;
+use domain::view::inputs::DeleteViewInput;
+
+use super::repository::ViewRepository;
+
+pub async fn delete_view<R: ViewRepository + ?Sized>(
+    repo: &R,
+    input: DeleteViewInput,
+) -> Result<(), AppError> {
+    repo.delete_view(input).await
+}
@@ -0,0 +1,14 @@
+use domain::error::AppError;
+use domain::view::entity::SavedView;
+use domain::view::inputs::GetViewInput;
+
+use super::repository::ViewRepository;
+
+pub async fn get_view<R: ViewRepository + ?Sized>(
+    repo: &R,
+    input: GetViewInput,
+) -> Result<SavedView, AppError> {
+    repo.get_view(input)
+        .await?
+        .ok_or_else(|| AppError::not_found("View", "View not found"))
+}
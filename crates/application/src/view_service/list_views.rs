@@ -0,0 +1,12 @@
+use domain::error::AppError;
+use domain::view::entity::SavedView;
+use domain::view::inputs::ListViewsInput;
+
+use super::repository::ViewRepository;
+
+pub async fn list_views<R: ViewRepository + ?Sized>(
+    repo: &R,
+    input: ListViewsInput,
+) -> Result<Vec<SavedView>, AppError> {
+    repo.list_views(input).await
+}
@@ -0,0 +1,7 @@
+pub mod create_view;
+pub mod delete_view;
+pub mod get_view;
+pub mod list_views;
+pub mod repository;
+pub mod run_view;
+pub mod update_view;
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use domain::error::AppError;
+use domain::view::entity::SavedView;
+use domain::view::inputs::{
+    CreateViewInput, DeleteViewInput, GetViewInput, ListViewsInput, UpdateViewInput,
+};
+
+#[async_trait]
+pub trait ViewRepository: Send + Sync {
+    async fn create_view(&self, input: CreateViewInput) -> Result<SavedView, AppError>;
+    async fn update_view(&self, input: UpdateViewInput) -> Result<SavedView, AppError>;
+    async fn delete_view(&self, input: DeleteViewInput) -> Result<(), AppError>;
+    async fn get_view(&self, input: GetViewInput) -> Result<Option<SavedView>, AppError>;
+    async fn list_views(&self, input: ListViewsInput) -> Result<Vec<SavedView>, AppError>;
+}
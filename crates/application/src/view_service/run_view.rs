@@ -0,0 +1,44 @@
+use domain::error::AppError;
+use domain::task::entity::Task;
+use domain::task::inputs::FilterTasksInput;
+use domain::view::inputs::{GetViewInput, RunViewInput};
+
+use super::get_view::get_view;
+use super::repository::ViewRepository;
+use crate::task_service::filter_tasks::filter_tasks;
+use crate::task_service::repository::TaskRepository;
+use crate::user_service::repository::UserRepository;
+
+pub async fn run_view<V, R, U>(
+    views: &V,
+    tasks: &R,
+    users: &U,
+    input: RunViewInput,
+) -> Result<Vec<Task>, AppError>
+where
+    V: ViewRepository + ?Sized,
+    R: TaskRepository + ?Sized,
+    U: UserRepository + ?Sized,
+{
+    let view = get_view(
+        views,
+        GetViewInput {
+            user_id: input.user_id.clone(),
+            view_id: input.view_id,
+        },
+    )
+    .await?;
+
+    filter_tasks(
+        tasks,
+        users,
+        FilterTasksInput {
+            user_id: input.user_id,
+            page: input.page,
+            limit: input.limit,
+            filter: view.filter,
+            now: input.now,
+        },
+    )
+    .await
+}
@@ -0,0 +1,15 @@
+use domain::error::AppError;
+use domain::view::entity::SavedView;
+use domain::view::inputs::UpdateViewInput;
+use domain::view::validation::validate_view;
+
+use super::repository::ViewRepository;
+
+pub async fn update_view<R: ViewRepository + ?Sized>(
+    repo: &R,
+    input: UpdateViewInput,
+) -> Result<SavedView, AppError> {
+    validate_view(&input.name, &input.filter)?;
+
+    repo.update_view(input).await
+}
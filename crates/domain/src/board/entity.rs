@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::task::entity::Task;
+use crate::task::status::TaskStatus;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Board {
+    pub board_id: String,
+    pub user_id: String,
+    pub name: String,
+    pub columns: Vec<BoardColumn>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoardColumn {
+    pub column_id: String,
+    pub name: String,
+    pub status: TaskStatus,
+    pub position: i32,
+    pub wip_limit: Option<i32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BoardColumnView {
+    pub column: BoardColumn,
+    pub tasks: Vec<Task>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BoardView {
+    pub board: Board,
+    pub columns: Vec<BoardColumnView>,
+}
+
+impl BoardView {
+    pub fn group(board: Board, tasks: Vec<Task>) -> Self {
+        let mut columns: Vec<BoardColumnView> = board
+            .columns
+            .iter()
+            .cloned()
+            .map(|column| BoardColumnView {
+                column,
+                tasks: Vec::new(),
+            })
+            .collect();
+        for task in tasks {
+            if let Some(view) = columns
+                .iter_mut()
+                .find(|view| view.column.status == task.status)
+            {
+                view.tasks.push(task);
+            }
+        }
+
+        Self { board, columns }
+    }
+}
@@ -0,0 +1,59 @@
+use serde::Deserialize;
+
+use crate::task::status::TaskStatus;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BoardColumnInput {
+    pub name: String,
+    pub status: TaskStatus,
+    pub wip_limit: Option<i32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateBoardInput {
+    pub user_id: String,
+    pub name: String,
+    pub columns: Vec<BoardColumnInput>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateBoardInput {
+    pub user_id: String,
+    pub board_id: String,
+    pub name: String,
+    pub columns: Vec<BoardColumnInput>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeleteBoardInput {
+    pub user_id: String,
+    pub board_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetBoardInput {
+    pub user_id: String,
+    pub board_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListBoardsInput {
+    pub user_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListBoardTasksInput {
+    pub user_id: String,
+    pub statuses: Vec<TaskStatus>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MoveCardInput {
+    pub user_id: String,
+    pub board_id: String,
+    pub task_id: String,
+    pub column_id: String,
+    pub version: i32,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
@@ -0,0 +1,55 @@
+use std::collections::HashSet;
+
+use crate::error::AppError;
+
+use super::inputs::BoardColumnInput;
+
+pub const MAX_BOARD_NAME_LENGTH: usize = 100;
+pub const MAX_BOARD_COLUMNS: usize = 20;
+
+pub fn validate_board(name: &str, columns: &[BoardColumnInput]) -> Result<(), AppError> {
+    if name.trim().is_empty() || name.chars().count() > MAX_BOARD_NAME_LENGTH {
+        return Err(AppError::validation(
+            "invalid_name",
+            "Board name must be 1-100 characters",
+            Some("name".to_string()),
+        ));
+    }
+    if columns.is_empty() || columns.len() > MAX_BOARD_COLUMNS {
+        return Err(AppError::validation(
+            "invalid_columns",
+            "A board must have 1-20 columns",
+            Some("columns".to_string()),
+        ));
+    }
+
+    let mut statuses = HashSet::new();
+    for column in columns {
+        if column.name.trim().is_empty() || column.name.chars().count() > MAX_BOARD_NAME_LENGTH {
+            return Err(AppError::validation(
+                "invalid_columns",
+                "Column names must be 1-100 characters",
+                Some("columns".to_string()),
+            ));
+        }
+        if !statuses.insert(column.status) {
+            return Err(AppError::validation(
+                "invalid_columns",
+                format!(
+                    "Status {} is mapped to more than one column",
+                    column.status.as_str()
+                ),
+                Some("columns".to_string()),
+            ));
+        }
+        if column.wip_limit.is_some_and(|limit| limit < 1) {
+            return Err(AppError::validation(
+                "invalid_columns",
+                "WIP limits must be positive",
+                Some("columns".to_string()),
+            ));
+        }
+    }
+
+    Ok(())
+}
@@ -10,6 +10,14 @@ pub enum AppError {
     Unauthorized { resource: String, message: String },
     #[error("Conflict: {resource}")]
     Conflict { resource: String, message: String },
+    #[error("Too many requests: {resource}")]
+    TooManyRequests { resource: String, message: String },
+    #[error("Version conflict: {resource}")]
+    VersionConflict {
+        resource: String,
+        message: String,
+        current_version: i64,
+    },
     #[error("Domain error: {domain}")]
     Domain { domain: String, message: String },
     #[error("Validation error: {code}")]
@@ -39,6 +47,14 @@ impl AppError {
         }
     }
 
+    pub fn version_conflict(resource: impl Into<String>, current_version: i64) -> Self {
+        Self::VersionConflict {
+            resource: resource.into(),
+            message: format!("Version mismatch; current version is {current_version}"),
+            current_version,
+        }
+    }
+
     pub fn database(message: impl Into<String>) -> Self {
         Self::Database {
             message: message.into(),
@@ -64,6 +80,8 @@ pub enum ErrorKind {
     Forbidden,
     Unauthorized,
     Conflict,
+    TooManyRequests,
+    VersionConflict,
     Domain,
     Validation,
     Database,
@@ -77,6 +95,8 @@ impl From<&AppError> for ErrorKind {
             AppError::Forbidden { .. } => ErrorKind::Forbidden,
             AppError::Unauthorized { .. } => ErrorKind::Unauthorized,
             AppError::Conflict { .. } => ErrorKind::Conflict,
+            AppError::TooManyRequests { .. } => ErrorKind::TooManyRequests,
+            AppError::VersionConflict { .. } => ErrorKind::VersionConflict,
             AppError::Domain { .. } => ErrorKind::Domain,
             AppError::Validation { .. } => ErrorKind::Validation,
             AppError::Database { .. } => ErrorKind::Database,
@@ -0,0 +1,11 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+// The plaintext token is only available when it is issued; the database keeps
+// a SHA-256 hash.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedToken {
+    pub user_id: String,
+    pub token: String,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,21 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RotateFeedTokenInput {
+    pub user_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RevokeFeedTokenInput {
+    pub user_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetFeedOwnerInput {
+    pub token: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TaskFeedInput {
+    pub token: String,
+}
@@ -1,3 +1,10 @@
+pub mod board;
 pub mod error;
+pub mod feed;
+pub mod patch;
+pub mod share;
 pub mod task;
+pub mod template;
+pub mod time_entry;
 pub mod user;
+pub mod view;
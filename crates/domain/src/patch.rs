@@ -0,0 +1,52 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum PatchFormat {
+    MergePatch,
+    JsonPatch,
+}
+
+pub fn apply_patch<T>(target: &T, format: PatchFormat, patch: &Value) -> Result<T, AppError>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let mut document = serde_json::to_value(target).map_err(invalid_patch)?;
+    let fields: Vec<String> = document
+        .as_object()
+        .map(|object| object.keys().cloned().collect())
+        .unwrap_or_default();
+
+    match format {
+        PatchFormat::MergePatch => json_patch::merge(&mut document, patch),
+        PatchFormat::JsonPatch => {
+            let operations: json_patch::Patch =
+                serde_json::from_value(patch.clone()).map_err(invalid_patch)?;
+            json_patch::patch(&mut document, &operations).map_err(invalid_patch)?;
+        }
+    }
+
+    let object = document.as_object_mut().ok_or_else(|| {
+        AppError::validation("invalid_patch", "Patch must produce an object", None)
+    })?;
+    if let Some(field) = object.keys().find(|key| !fields.contains(key)) {
+        return Err(AppError::validation(
+            "invalid_patch",
+            format!("Unknown field: {field}"),
+            Some(field.clone()),
+        ));
+    }
+    // Merge patch removes members set to null; restore them so optional fields clear.
+    for field in &fields {
+        object.entry(field.clone()).or_insert(Value::Null);
+    }
+
+    serde_json::from_value(document).map_err(invalid_patch)
+}
+
+fn invalid_patch(error: impl std::fmt::Display) -> AppError {
+    AppError::validation("invalid_patch", error.to_string(), None)
+}
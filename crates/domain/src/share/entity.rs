@@ -0,0 +1,46 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::task::entity::Task;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShareLink {
+    pub share_id: String,
+    pub task_id: String,
+    pub created_by: String,
+    pub expires_at: DateTime<Utc>,
+    pub has_password: bool,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ShareLink {
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && self.expires_at > now
+    }
+}
+
+// The plaintext token is only available when the link is issued; the
+// database keeps a SHA-256 hash.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssuedShareLink {
+    pub link: ShareLink,
+    pub token: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShareAccess {
+    pub share_id: String,
+    pub accessed_at: DateTime<Utc>,
+    pub granted: bool,
+    pub ip: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+// Subtasks are ordered depth-first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SharedTask {
+    pub expires_at: DateTime<Utc>,
+    pub task: Task,
+    pub subtasks: Vec<Task>,
+}
@@ -0,0 +1,71 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IssueShareLinkInput {
+    pub user_id: String,
+    pub task_id: String,
+    pub expires_in_days: Option<i64>,
+    pub password: Option<String>,
+    pub now: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateShareLinkInput {
+    pub user_id: String,
+    pub task_id: String,
+    pub expires_at: DateTime<Utc>,
+    pub password: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListShareLinksInput {
+    pub user_id: String,
+    pub task_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RevokeShareLinkInput {
+    pub user_id: String,
+    pub share_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListShareAccessInput {
+    pub user_id: String,
+    pub share_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FindShareLinkInput {
+    pub token: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckSharePasswordInput {
+    pub share_id: String,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CountFailedShareAccessInput {
+    pub share_id: String,
+    pub since: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecordShareAccessInput {
+    pub share_id: String,
+    pub granted: bool,
+    pub ip: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OpenShareLinkInput {
+    pub token: String,
+    pub password: Option<String>,
+    pub ip: Option<String>,
+    pub user_agent: Option<String>,
+    pub now: DateTime<Utc>,
+}
@@ -0,0 +1,35 @@
+use crate::error::AppError;
+
+pub const DEFAULT_SHARE_DAYS: i64 = 7;
+pub const MAX_SHARE_DAYS: i64 = 90;
+pub const MIN_SHARE_PASSWORD_LENGTH: usize = 8;
+pub const MAX_SHARE_PASSWORD_LENGTH: usize = 128;
+// After this many failed password attempts within the window, a link refuses
+// further attempts until the oldest of them ages out.
+pub const MAX_FAILED_SHARE_ATTEMPTS: i64 = 10;
+pub const SHARE_ATTEMPT_WINDOW_MINUTES: i64 = 15;
+
+pub fn validate_share_days(days: i64) -> Result<(), AppError> {
+    if !(1..=MAX_SHARE_DAYS).contains(&days) {
+        return Err(AppError::validation(
+            "invalid_expiry",
+            "Share links must expire within 1-90 days",
+            Some("expires_in_days".to_string()),
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_share_password(password: &str) -> Result<(), AppError> {
+    let length = password.chars().count();
+    if !(MIN_SHARE_PASSWORD_LENGTH..=MAX_SHARE_PASSWORD_LENGTH).contains(&length) {
+        return Err(AppError::validation(
+            "invalid_password",
+            "Password must be 8-128 characters",
+            Some("password".to_string()),
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use super::entity::Task;
+use super::inputs::NewTaskNode;
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct DuplicateOptions {
+    pub include_labels: bool,
+    pub include_subtasks: bool,
+    pub include_due_dates: bool,
+    // Moves the copy's due date here; subtasks keep their offset from it.
+    pub due_at: Option<DateTime<Utc>>,
+}
+
+impl Default for DuplicateOptions {
+    fn default() -> Self {
+        Self {
+            include_labels: true,
+            include_subtasks: true,
+            include_due_dates: true,
+            due_at: None,
+        }
+    }
+}
+
+// Builds the tree to create from a subtree listing whose first entry is the
+// task being copied. Offsets are measured from the original's due date, or
+// from when it was created if it has none. Returns None for an empty listing.
+pub fn duplicate_tree(
+    subtree: &[Task],
+    options: &DuplicateOptions,
+) -> Result<Option<NewTaskNode>, AppError> {
+    let Some(root) = subtree.first() else {
+        return Ok(None);
+    };
+    let shift = options
+        .due_at
+        .map(|due_at| due_at - root.due_at.unwrap_or(root.created_at));
+
+    let mut children: HashMap<&str, Vec<&Task>> = HashMap::new();
+    if options.include_subtasks {
+        for task in &subtree[1..] {
+            if let Some(parent_task_id) = task.parent_task_id.as_deref() {
+                children.entry(parent_task_id).or_default().push(task);
+            }
+        }
+    }
+
+    let due_at = |task: &Task| match shift {
+        Some(_) if task.task_id == root.task_id => Ok(options.due_at),
+        Some(shift) => task
+            .due_at
+            .map(|due_at| {
+                due_at.checked_add_signed(shift).ok_or_else(|| {
+                    AppError::validation(
+                        "invalid_due_at",
+                        "Subtask due dates would fall outside the supported range",
+                        Some("due_at".to_string()),
+                    )
+                })
+            })
+            .transpose(),
+        None if options.include_due_dates => Ok(task.due_at),
+        None => Ok(None),
+    };
+
+    type DueAt<'a> = dyn Fn(&Task) -> Result<Option<DateTime<Utc>>, AppError> + 'a;
+
+    fn build(
+        task: &Task,
+        children: &HashMap<&str, Vec<&Task>>,
+        options: &DuplicateOptions,
+        due_at: &DueAt,
+    ) -> Result<NewTaskNode, AppError> {
+        Ok(NewTaskNode {
+            content: task.content.clone(),
+            labels: if options.include_labels {
+                task.labels.clone()
+            } else {
+                Vec::new()
+            },
+            due_at: due_at(task)?,
+            subtasks: children
+                .get(task.task_id.as_str())
+                .map(|subtasks| {
+                    subtasks
+                        .iter()
+                        .map(|subtask| build(subtask, children, options, due_at))
+                        .collect::<Result<Vec<_>, _>>()
+                })
+                .transpose()?
+                .unwrap_or_default(),
+        })
+    }
+
+    build(root, &children, options, &due_at).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone, Utc};
+
+    use super::{duplicate_tree, DuplicateOptions};
+    use crate::task::entity::Task;
+    use crate::task::status::TaskStatus;
+
+    fn task(task_id: &str, parent_task_id: Option<&str>, due_in_days: Option<i64>) -> Task {
+        let created_at = Utc.with_ymd_and_hms(2025, 3, 1, 9, 0, 0).unwrap();
+        Task {
+            user_id: "user-1".to_string(),
+            task_id: task_id.to_string(),
+            content: format!("task {task_id}"),
+            status: TaskStatus::Completed,
+            completed_at: Some(created_at),
+            parent_task_id: parent_task_id.map(str::to_string),
+            estimate_minutes: None,
+            remaining_minutes: None,
+            story_points: None,
+            due_at: due_in_days.map(|days| created_at + Duration::days(days)),
+            labels: vec!["ops".to_string()],
+            priority: None,
+            recurrence: None,
+            snoozed_until: None,
+            waiting_on: None,
+            archived_at: None,
+            assignee_ids: Vec::new(),
+            group: None,
+            version: 4,
+            rank: "i".to_string(),
+            tracked_seconds: 0,
+            created_at,
+            updated_at: created_at,
+        }
+    }
+
+    #[test]
+    fn keeps_subtask_offsets_from_the_new_due_date() {
+        let subtree = vec![
+            task("root", None, Some(10)),
+            task("child", Some("root"), Some(7)),
+            task("grandchild", Some("child"), None),
+        ];
+        let due_at = Utc.with_ymd_and_hms(2025, 6, 1, 9, 0, 0).unwrap();
+
+        let copy = duplicate_tree(
+            &subtree,
+            &DuplicateOptions {
+                due_at: Some(due_at),
+                ..Default::default()
+            },
+        )
+        .expect("copy")
+        .expect("root");
+
+        assert_eq!(copy.due_at, Some(due_at));
+        assert_eq!(copy.subtasks[0].due_at, Some(due_at - Duration::days(3)));
+        assert_eq!(copy.subtasks[0].subtasks[0].content, "task grandchild");
+        assert_eq!(copy.subtasks[0].subtasks[0].due_at, None);
+    }
+
+    #[test]
+    fn leaves_out_what_is_not_included() {
+        let subtree = vec![
+            task("root", None, Some(10)),
+            task("child", Some("root"), Some(7)),
+        ];
+
+        let copy = duplicate_tree(
+            &subtree,
+            &DuplicateOptions {
+                include_labels: false,
+                include_subtasks: false,
+                include_due_dates: false,
+                due_at: None,
+            },
+        )
+        .expect("copy")
+        .expect("root");
+
+        assert!(copy.labels.is_empty());
+        assert!(copy.subtasks.is_empty());
+        assert_eq!(copy.due_at, None);
+        assert!(duplicate_tree(&[], &DuplicateOptions::default())
+            .expect("copy")
+            .is_none());
+    }
+
+    #[test]
+    fn rejects_shifts_past_the_end_of_time() {
+        let subtree = vec![
+            task("root", None, Some(0)),
+            task("child", Some("root"), Some(400)),
+        ];
+        let due_at = "+262142-12-01T00:00:00Z".parse().expect("date");
+
+        let result = duplicate_tree(
+            &subtree,
+            &DuplicateOptions {
+                due_at: Some(due_at),
+                ..Default::default()
+            },
+        );
+
+        assert!(result.is_err());
+    }
+}
@@ -1,13 +1,33 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use super::group::GroupProgress;
+use super::priority::TaskPriority;
+use super::status::TaskStatus;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Task {
     pub user_id: String,
     pub task_id: String,
     pub content: String,
+    pub status: TaskStatus,
     pub completed_at: Option<DateTime<Utc>>,
+    pub parent_task_id: Option<String>,
+    pub estimate_minutes: Option<i32>,
+    pub remaining_minutes: Option<i32>,
+    pub story_points: Option<i32>,
+    pub due_at: Option<DateTime<Utc>>,
+    pub labels: Vec<String>,
+    pub priority: Option<TaskPriority>,
+    pub recurrence: Option<String>,
+    pub snoozed_until: Option<DateTime<Utc>>,
+    pub waiting_on: Option<String>,
+    pub archived_at: Option<DateTime<Utc>>,
+    pub assignee_ids: Vec<String>,
+    pub group: Option<GroupProgress>,
     pub version: i32,
+    pub rank: String,
+    pub tracked_seconds: i64,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
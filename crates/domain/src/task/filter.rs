@@ -0,0 +1,365 @@
+use chrono::{DateTime, Datelike, Days, Duration, Months, NaiveDate, NaiveTime, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+use super::inputs::{ArchiveFilter, SnoozeFilter};
+use super::rank::{parse_sort, TaskSort};
+use super::status::TaskStatus;
+use super::validation::validate_labels;
+use crate::error::AppError;
+use crate::user::timezone::local_to_utc;
+
+pub const MAX_SEARCH_LENGTH: usize = 200;
+pub const MAX_RELATIVE_DAYS: i64 = 365;
+
+// The raw, unresolved form of a task query. Saved views store this so that
+// relative dates such as "next 7 days" are re-evaluated every time they run.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskFilter {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub q: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assigned_to: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_snoozed: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum DueFilter {
+    // Half-open range: from <= due_at < to.
+    Between {
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    },
+    None,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct TaskQuery {
+    pub statuses: Vec<TaskStatus>,
+    pub labels: Vec<String>,
+    pub due: Option<DueFilter>,
+    pub search: Option<String>,
+    pub sort: TaskSort,
+    pub assigned_to: Option<String>,
+    pub snoozed: SnoozeFilter,
+    pub archived: ArchiveFilter,
+}
+
+impl TaskFilter {
+    pub fn needs_timezone(&self) -> bool {
+        self.due.is_some()
+    }
+
+    pub fn validate(&self) -> Result<(), AppError> {
+        self.parse("", Utc::now(), Tz::UTC).map(|_| ())
+    }
+
+    pub fn parse(
+        &self,
+        user_id: &str,
+        now: DateTime<Utc>,
+        timezone: Tz,
+    ) -> Result<TaskQuery, AppError> {
+        let statuses = split_list(self.status.as_deref())
+            .into_iter()
+            .map(|status| {
+                TaskStatus::parse(&status.to_uppercase()).ok_or_else(|| {
+                    invalid(
+                        "invalid_status",
+                        "Status must be PENDING, IN_PROGRESS, or COMPLETED",
+                        "status",
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let labels = split_list(self.labels.as_deref());
+        validate_labels(&labels)?;
+
+        let due = self
+            .due
+            .as_deref()
+            .map(|expression| parse_due(expression, now, timezone))
+            .transpose()?;
+
+        let search = self
+            .q
+            .as_deref()
+            .map(str::trim)
+            .filter(|q| !q.is_empty())
+            .map(str::to_string);
+        if search
+            .as_ref()
+            .is_some_and(|q| q.chars().count() > MAX_SEARCH_LENGTH)
+        {
+            return Err(invalid(
+                "invalid_search",
+                &format!("Search text must be at most {MAX_SEARCH_LENGTH} characters"),
+                "q",
+            ));
+        }
+
+        let sort = match self.sort.as_deref() {
+            Some(sort) => parse_sort(sort).ok_or_else(|| {
+                invalid("invalid_sort", "Sort must be created_at or manual", "sort")
+            })?,
+            None => TaskSort::default(),
+        };
+
+        let archived = match self.archived.as_deref() {
+            None | Some("exclude") => ArchiveFilter::Active,
+            Some("include") => ArchiveFilter::Include,
+            Some("only") => ArchiveFilter::Only,
+            Some(_) => {
+                return Err(invalid(
+                    "invalid_archived",
+                    "Archived must be exclude, include, or only",
+                    "archived",
+                ))
+            }
+        };
+
+        let assigned_to = self.assigned_to.as_ref().map(|assignee| {
+            if assignee == "me" {
+                user_id.to_string()
+            } else {
+                assignee.clone()
+            }
+        });
+
+        Ok(TaskQuery {
+            statuses,
+            labels,
+            due,
+            search,
+            sort,
+            assigned_to,
+            snoozed: if self.include_snoozed.unwrap_or(false) {
+                SnoozeFilter::Include
+            } else {
+                SnoozeFilter::Awake
+            },
+            archived,
+        })
+    }
+}
+
+fn split_list(value: Option<&str>) -> Vec<String> {
+    value
+        .unwrap_or("")
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn invalid(code: &str, message: &str, field: &str) -> AppError {
+    AppError::validation(code, message, Some(field.to_string()))
+}
+
+// Accepts: none, overdue, today, tomorrow, yesterday, this/next week,
+// this/next month, next N days, last N days, and YYYY-MM-DD..YYYY-MM-DD with
+// either side optional. Day boundaries are midnight in the user's timezone.
+pub fn parse_due(
+    expression: &str,
+    now: DateTime<Utc>,
+    timezone: Tz,
+) -> Result<DueFilter, AppError> {
+    let expression = expression.trim().to_lowercase();
+    let today = now.with_timezone(&timezone).date_naive();
+    let start = |date: NaiveDate| local_to_utc(date.and_time(NaiveTime::MIN), timezone);
+    let days = |from: NaiveDate, count: u64| {
+        Some(DueFilter::Between {
+            from: Some(start(from)),
+            to: Some(start(from.checked_add_days(Days::new(count))?)),
+        })
+    };
+    let week_start = today - Duration::days(i64::from(today.weekday().num_days_from_monday()));
+    let month_start = today.with_day(1).unwrap_or(today);
+    let months = |from: NaiveDate| {
+        let to = from.checked_add_months(Months::new(1))?;
+        Some(DueFilter::Between {
+            from: Some(start(from)),
+            to: Some(start(to)),
+        })
+    };
+
+    let words: Vec<&str> = expression.split_whitespace().collect();
+    let parsed = match words.as_slice() {
+        ["none"] => Some(DueFilter::None),
+        ["overdue"] => Some(DueFilter::Between {
+            from: None,
+            to: Some(now),
+        }),
+        ["today"] => days(today, 1),
+        ["tomorrow"] => days(today + Duration::days(1), 1),
+        ["yesterday"] => days(today - Duration::days(1), 1),
+        ["this", "week"] => days(week_start, 7),
+        ["next", "week"] => days(week_start + Duration::days(7), 7),
+        ["this", "month"] => months(month_start),
+        ["next", "month"] => month_start
+            .checked_add_months(Months::new(1))
+            .and_then(months),
+        ["next", count, unit] if is_days(unit) => {
+            parse_count(count).map(|count| DueFilter::Between {
+                from: Some(now),
+                to: Some(start(today + Duration::days(count + 1))),
+            })
+        }
+        ["last", count, unit] if is_days(unit) => {
+            parse_count(count).map(|count| DueFilter::Between {
+                from: Some(start(today - Duration::days(count))),
+                to: Some(now),
+            })
+        }
+        // The day after the last date may not exist at the edge of the range.
+        [range] => parse_range(range).and_then(|(from, to)| {
+            let to = match to {
+                Some(to) => Some(start(to.checked_add_days(Days::new(1))?)),
+                None => None,
+            };
+            Some(DueFilter::Between {
+                from: from.map(start),
+                to,
+            })
+        }),
+        _ => None,
+    };
+
+    parsed.ok_or_else(|| {
+        invalid(
+            "invalid_due",
+            "Due must be none, overdue, today, tomorrow, yesterday, this week, next week, \
+             this month, next month, next N days, last N days, or YYYY-MM-DD..YYYY-MM-DD",
+            "due",
+        )
+    })
+}
+
+fn is_days(unit: &str) -> bool {
+    matches!(unit, "day" | "days")
+}
+
+fn parse_count(value: &str) -> Option<i64> {
+    value
+        .parse::<i64>()
+        .ok()
+        .filter(|count| (1..=MAX_RELATIVE_DAYS).contains(count))
+}
+
+fn parse_range(value: &str) -> Option<(Option<NaiveDate>, Option<NaiveDate>)> {
+    let (from, to) = value.split_once("..")?;
+    let date = |value: &str| -> Option<Option<NaiveDate>> {
+        if value.is_empty() {
+            Some(None)
+        } else {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d").ok().map(Some)
+        }
+    };
+    let (from, to) = (date(from)?, date(to)?);
+    if from.is_none() && to.is_none() || from.zip(to).is_some_and(|(from, to)| from > to) {
+        return None;
+    }
+
+    Some((from, to))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use chrono_tz::Tz;
+
+    use super::{parse_due, DueFilter, TaskFilter};
+    use crate::task::inputs::ArchiveFilter;
+    use crate::task::status::TaskStatus;
+
+    fn now() -> chrono::DateTime<Utc> {
+        // Wednesday 2025-03-12 09:00 UTC
+        Utc.with_ymd_and_hms(2025, 3, 12, 9, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn resolves_relative_ranges_in_the_users_timezone() {
+        let due = parse_due("next 7 days", now(), Tz::America__New_York).expect("parse");
+        assert_eq!(
+            due,
+            DueFilter::Between {
+                from: Some(now()),
+                // Midnight after 2025-03-19 in New York (EDT, UTC-4).
+                to: Some(Utc.with_ymd_and_hms(2025, 3, 20, 4, 0, 0).unwrap()),
+            }
+        );
+
+        let week = parse_due("this week", now(), Tz::UTC).expect("parse");
+        assert_eq!(
+            week,
+            DueFilter::Between {
+                from: Some(Utc.with_ymd_and_hms(2025, 3, 10, 0, 0, 0).unwrap()),
+                to: Some(Utc.with_ymd_and_hms(2025, 3, 17, 0, 0, 0).unwrap()),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_open_ended_date_ranges() {
+        let due = parse_due("..2025-03-31", now(), Tz::UTC).expect("parse");
+        assert_eq!(
+            due,
+            DueFilter::Between {
+                from: None,
+                to: Some(Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap()),
+            }
+        );
+        assert!(parse_due("2025-04-01..2025-03-01", now(), Tz::UTC).is_err());
+        assert!(parse_due("next 0 days", now(), Tz::UTC).is_err());
+        assert!(parse_due("soon", now(), Tz::UTC).is_err());
+    }
+
+    #[test]
+    fn rejects_ranges_ending_on_the_last_representable_date() {
+        assert!(parse_due("..+262142-12-31", now(), Tz::UTC).is_err());
+        assert!(parse_due("..+262142-12-30", now(), Tz::America__New_York).is_ok());
+        assert!(parse_due("+262142-12-31..", now(), Tz::UTC).is_ok());
+    }
+
+    #[test]
+    fn parses_the_full_filter() {
+        let filter = TaskFilter {
+            status: Some("pending, in_progress".to_string()),
+            labels: Some("finance,home".to_string()),
+            q: Some("  rent ".to_string()),
+            assigned_to: Some("me".to_string()),
+            archived: Some("include".to_string()),
+            ..Default::default()
+        };
+
+        let query = filter.parse("user-1", now(), Tz::UTC).expect("parse");
+
+        assert_eq!(
+            query.statuses,
+            vec![TaskStatus::Pending, TaskStatus::InProgress]
+        );
+        assert_eq!(query.labels, vec!["finance", "home"]);
+        assert_eq!(query.search.as_deref(), Some("rent"));
+        assert_eq!(query.assigned_to.as_deref(), Some("user-1"));
+        assert_eq!(query.archived, ArchiveFilter::Include);
+        assert!(TaskFilter {
+            status: Some("DONE".to_string()),
+            ..Default::default()
+        }
+        .validate()
+        .is_err());
+    }
+}
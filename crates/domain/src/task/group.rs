@@ -0,0 +1,65 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::status::TaskStatus;
+
+// Present only on group tasks, where every member completes the task on their
+// own. `member_completed_at` is the viewing member's own completion.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GroupProgress {
+    pub completed: i64,
+    pub total: i64,
+    pub member_completed_at: Option<DateTime<Utc>>,
+}
+
+impl GroupProgress {
+    pub fn is_complete(&self) -> bool {
+        self.total > 0 && self.completed >= self.total
+    }
+}
+
+// The status a group task moves to after its members' completions change, or
+// None to leave it alone. Only crossing the all-done line changes anything, so
+// a manual status set by a member sticks until the next completion changes.
+pub fn group_status(current: TaskStatus, progress: &GroupProgress) -> Option<TaskStatus> {
+    match (current, progress.is_complete()) {
+        (TaskStatus::Completed, true) => None,
+        (_, true) => Some(TaskStatus::Completed),
+        // A completed task has to be reopened to PENDING.
+        (TaskStatus::Completed, false) => Some(TaskStatus::Pending),
+        (_, false) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{group_status, GroupProgress};
+    use crate::task::status::TaskStatus;
+
+    fn progress(completed: i64, total: i64) -> GroupProgress {
+        GroupProgress {
+            completed,
+            total,
+            member_completed_at: None,
+        }
+    }
+
+    #[test]
+    fn completes_when_the_last_member_finishes() {
+        assert_eq!(
+            group_status(TaskStatus::InProgress, &progress(10, 10)),
+            Some(TaskStatus::Completed)
+        );
+        assert_eq!(group_status(TaskStatus::Pending, &progress(7, 10)), None);
+        assert_eq!(group_status(TaskStatus::Completed, &progress(3, 3)), None);
+    }
+
+    #[test]
+    fn reopens_when_a_member_is_no_longer_done() {
+        assert_eq!(
+            group_status(TaskStatus::Completed, &progress(9, 10)),
+            Some(TaskStatus::Pending)
+        );
+        assert_eq!(group_status(TaskStatus::Pending, &progress(0, 0)), None);
+    }
+}
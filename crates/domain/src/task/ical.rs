@@ -0,0 +1,189 @@
+use chrono::{DateTime, Utc};
+
+use super::entity::Task;
+use super::priority::TaskPriority;
+use super::recurrence::Recurrence;
+use super::status::TaskStatus;
+
+pub const ICAL_PRODID: &str = "-//Tasks//Task Feed//EN";
+const MAX_LINE_OCTETS: usize = 75;
+
+// RFC 5545 3.3.11: backslash, semicolon, comma and newlines are escaped.
+pub fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// Content lines are folded at 75 octets without splitting a UTF-8 sequence.
+pub fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + 8);
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+        folded.push(c);
+        width += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
+
+fn timestamp(at: DateTime<Utc>) -> String {
+    at.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn status(status: TaskStatus) -> &'static str {
+    match status {
+        TaskStatus::Pending => "NEEDS-ACTION",
+        TaskStatus::InProgress => "IN-PROCESS",
+        TaskStatus::Completed => "COMPLETED",
+    }
+}
+
+fn priority(priority: TaskPriority) -> u8 {
+    match priority {
+        TaskPriority::Urgent => 1,
+        TaskPriority::High => 3,
+        TaskPriority::Medium => 5,
+        TaskPriority::Low => 9,
+    }
+}
+
+pub fn format_vtodo(task: &Task) -> String {
+    let summary = task.content.lines().next().unwrap_or_default();
+    let mut lines = vec![
+        "BEGIN:VTODO".to_string(),
+        format!("UID:{}", task.task_id),
+        format!("DTSTAMP:{}", timestamp(task.updated_at)),
+        format!("CREATED:{}", timestamp(task.created_at)),
+        format!("LAST-MODIFIED:{}", timestamp(task.updated_at)),
+        format!("SEQUENCE:{}", task.version),
+        format!("SUMMARY:{}", escape_text(summary)),
+        format!("STATUS:{}", status(task.status)),
+    ];
+    if task.content.trim() != summary.trim() {
+        lines.push(format!("DESCRIPTION:{}", escape_text(&task.content)));
+    }
+    if let Some(due_at) = task.due_at {
+        lines.push(format!("DUE:{}", timestamp(due_at)));
+    }
+    if let Some(completed_at) = task.completed_at {
+        lines.push(format!("COMPLETED:{}", timestamp(completed_at)));
+    }
+    if let Some(value) = task.priority {
+        lines.push(format!("PRIORITY:{}", priority(value)));
+    }
+    if !task.labels.is_empty() {
+        let labels: Vec<String> = task.labels.iter().map(|label| escape_text(label)).collect();
+        lines.push(format!("CATEGORIES:{}", labels.join(",")));
+    }
+    // RRULE needs a DTSTART, which in turn must precede DUE.
+    if let Some(rule) = task.recurrence.as_deref().and_then(Recurrence::parse) {
+        if task.due_at.is_none_or(|due_at| due_at > task.created_at) {
+            lines.push(format!("DTSTART:{}", timestamp(task.created_at)));
+            lines.push(format!("RRULE:{rule}"));
+        }
+    }
+    lines.push("END:VTODO".to_string());
+
+    lines.iter().map(|line| fold_line(line)).collect()
+}
+
+pub fn format_calendar(name: &str, tasks: &[Task]) -> String {
+    let mut calendar = [
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:{ICAL_PRODID}"),
+        "CALSCALE:GREGORIAN".to_string(),
+        format!("X-WR-CALNAME:{}", escape_text(name)),
+    ]
+    .iter()
+    .map(|line| fold_line(line))
+    .collect::<String>();
+    for task in tasks {
+        calendar.push_str(&format_vtodo(task));
+    }
+    calendar.push_str("END:VCALENDAR\r\n");
+    calendar
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::{fold_line, format_vtodo};
+    use crate::task::entity::Task;
+    use crate::task::priority::TaskPriority;
+    use crate::task::status::TaskStatus;
+
+    #[test]
+    fn folds_long_lines_on_character_boundaries() {
+        let line = format!("SUMMARY:{}", "é".repeat(40));
+
+        let folded = fold_line(&line);
+
+        for part in folded.trim_end_matches("\r\n").split("\r\n") {
+            assert!(part.len() <= 75, "{part:?} is {} octets", part.len());
+        }
+        assert_eq!(folded.replace("\r\n ", ""), format!("{line}\r\n"));
+    }
+
+    #[test]
+    fn emits_status_due_priority_and_recurrence() {
+        let created_at = Utc.with_ymd_and_hms(2025, 3, 1, 9, 0, 0).unwrap();
+        let task = Task {
+            user_id: "user-1".to_string(),
+            task_id: "task-1".to_string(),
+            content: "Pay rent, again\nTransfer from savings; check balance".to_string(),
+            status: TaskStatus::InProgress,
+            completed_at: None,
+            parent_task_id: None,
+            estimate_minutes: None,
+            remaining_minutes: None,
+            story_points: None,
+            due_at: Some(Utc.with_ymd_and_hms(2025, 3, 5, 17, 0, 0).unwrap()),
+            labels: vec!["home".to_string(), "money".to_string()],
+            priority: Some(TaskPriority::High),
+            recurrence: Some("FREQ=MONTHLY;INTERVAL=1".to_string()),
+            snoozed_until: None,
+            waiting_on: None,
+            archived_at: None,
+            assignee_ids: Vec::new(),
+            group: None,
+            version: 4,
+            rank: "i".to_string(),
+            tracked_seconds: 0,
+            created_at,
+            updated_at: created_at,
+        };
+
+        let vtodo = format_vtodo(&task);
+
+        for line in [
+            "UID:task-1",
+            "SEQUENCE:4",
+            "SUMMARY:Pay rent\\, again",
+            "DESCRIPTION:Pay rent\\, again\\nTransfer from savings\\; check balance",
+            "STATUS:IN-PROCESS",
+            "DUE:20250305T170000Z",
+            "PRIORITY:3",
+            "CATEGORIES:home,money",
+            "DTSTART:20250301T090000Z",
+            "RRULE:FREQ=MONTHLY;INTERVAL=1",
+        ] {
+            assert!(vtodo.contains(&format!("{line}\r\n")), "missing {line}");
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::inputs::CreateTaskInput;
+use super::priority::TaskPriority;
+use super::status::TaskStatus;
+use super::validation::{validate_content, validate_labels, validate_waiting_on};
+use crate::error::AppError;
+
+pub const IMPORT_BATCH_SIZE: usize = 100;
+pub const MAX_IMPORT_ROWS: usize = 10_000;
+
+// A task as read from an import file, before validation. `line` is the
+// 1-based line (or JSON array position) the row came from.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportTask {
+    pub line: usize,
+    pub content: String,
+    pub status: Option<String>,
+    pub priority: Option<String>,
+    pub labels: Vec<String>,
+    pub due_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub waiting_on: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportLineError {
+    pub line: usize,
+    pub code: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportSummary {
+    pub dry_run: bool,
+    pub total: usize,
+    pub valid: usize,
+    pub imported: usize,
+    pub errors: Vec<ImportLineError>,
+    pub failure: Option<ImportFailure>,
+}
+
+// Why an import stopped before every valid row was created. Batches that
+// committed before the failure stay imported.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportFailure {
+    pub code: String,
+    pub message: String,
+}
+
+impl ImportLineError {
+    pub fn new(line: usize, code: &str, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            code: code.to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn from_error(line: usize, error: AppError) -> Self {
+        match error {
+            AppError::Validation { code, message, .. } => Self {
+                line,
+                code,
+                message,
+            },
+            other => Self::new(line, "invalid_row", other.to_string()),
+        }
+    }
+}
+
+impl From<AppError> for ImportFailure {
+    fn from(error: AppError) -> Self {
+        match error {
+            AppError::Validation { code, message, .. } => Self { code, message },
+            other => Self {
+                code: "import_failed".to_string(),
+                message: other.to_string(),
+            },
+        }
+    }
+}
+
+impl ImportTask {
+    pub fn into_create_input(
+        self,
+        user_id: &str,
+        max_content_chars: usize,
+        now: DateTime<Utc>,
+    ) -> Result<CreateTaskInput, AppError> {
+        let content = self.content.trim().to_string();
+        validate_content(&content, max_content_chars)?;
+        validate_labels(&self.labels)?;
+        validate_waiting_on(self.waiting_on.as_deref())?;
+
+        let status = match self.status.as_deref().map(str::trim) {
+            Some(status) if !status.is_empty() => TaskStatus::parse(&status.to_uppercase())
+                .ok_or_else(|| {
+                    AppError::validation(
+                        "invalid_status",
+                        "Status must be PENDING, IN_PROGRESS, or COMPLETED",
+                        Some("status".to_string()),
+                    )
+                })?,
+            _ if self.completed_at.is_some() => TaskStatus::Completed,
+            _ => TaskStatus::default(),
+        };
+        let priority = match self.priority.as_deref().map(str::trim) {
+            Some(priority) if !priority.is_empty() => Some(
+                TaskPriority::parse(&priority.to_uppercase()).ok_or_else(|| {
+                    AppError::validation(
+                        "invalid_priority",
+                        "Priority must be LOW, MEDIUM, HIGH, or URGENT",
+                        Some("priority".to_string()),
+                    )
+                })?,
+            ),
+            _ => None,
+        };
+        if self
+            .completed_at
+            .is_some_and(|completed_at| status != TaskStatus::Completed || completed_at > now)
+        {
+            return Err(AppError::validation(
+                "invalid_completed_at",
+                "completed_at requires COMPLETED status and cannot be in the future",
+                Some("completed_at".to_string()),
+            ));
+        }
+
+        Ok(CreateTaskInput {
+            user_id: user_id.to_string(),
+            content,
+            status,
+            due_at: self.due_at,
+            labels: self.labels,
+            priority,
+            completed_at: self.completed_at,
+            waiting_on: self.waiting_on,
+            ..Default::default()
+        })
+    }
+}
@@ -1,18 +1,57 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::Deserialize;
+use serde_json::Value;
 
-#[derive(Debug, Clone, Deserialize)]
+use super::duplicate::DuplicateOptions;
+use super::filter::{TaskFilter, TaskQuery};
+use super::import::{ImportLineError, ImportTask};
+use super::merge::MergeGranularity;
+use super::next::ScoreWeights;
+use super::priority::TaskPriority;
+use super::stats::StatsBucket;
+use super::status::TaskStatus;
+use super::sync::SyncMutation;
+use crate::patch::PatchFormat;
+
+#[derive(Debug, Clone, Default, Deserialize)]
 pub struct CreateTaskInput {
     pub user_id: String,
     pub content: String,
+    pub status: TaskStatus,
+    // Only honoured for COMPLETED tasks; defaults to the creation time.
+    pub completed_at: Option<DateTime<Utc>>,
+    pub parent_task_id: Option<String>,
+    pub estimate_minutes: Option<i32>,
+    pub remaining_minutes: Option<i32>,
+    pub story_points: Option<i32>,
+    pub due_at: Option<DateTime<Utc>>,
+    pub labels: Vec<String>,
+    pub priority: Option<TaskPriority>,
+    pub recurrence: Option<String>,
+    pub snoozed_until: Option<DateTime<Utc>>,
+    pub waiting_on: Option<String>,
+    pub group: bool,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 pub struct UpdateTaskInput {
     pub user_id: String,
     pub task_id: String,
     pub content: Option<String>,
+    pub status: Option<TaskStatus>,
     pub completed_at: Option<Option<DateTime<Utc>>>,
+    pub parent_task_id: Option<Option<String>>,
+    pub estimate_minutes: Option<Option<i32>>,
+    pub remaining_minutes: Option<Option<i32>>,
+    pub story_points: Option<Option<i32>>,
+    pub due_at: Option<Option<DateTime<Utc>>>,
+    pub labels: Option<Vec<String>>,
+    pub priority: Option<Option<TaskPriority>>,
+    pub recurrence: Option<Option<String>>,
+    pub snoozed_until: Option<Option<DateTime<Utc>>>,
+    pub waiting_on: Option<Option<String>>,
+    pub assignee_ids: Option<Vec<String>>,
+    pub group: Option<bool>,
     pub version: i32,
 }
 
@@ -20,6 +59,7 @@ pub struct UpdateTaskInput {
 pub struct DeleteTaskInput {
     pub user_id: String,
     pub task_id: String,
+    pub version: Option<i32>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -33,4 +73,252 @@ pub struct ListTasksInput {
     pub user_id: String,
     pub page: i64,
     pub limit: i64,
+    pub query: TaskQuery,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub enum SnoozeFilter {
+    #[default]
+    Awake,
+    Include,
+    Only,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub enum ArchiveFilter {
+    #[default]
+    Active,
+    Include,
+    Only,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SetTaskArchivedInput {
+    pub user_id: String,
+    pub task_id: String,
+    pub archived: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ArchiveCompletedTasksInput {
+    pub completed_before: DateTime<Utc>,
+    pub batch_size: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListTaskRevisionsInput {
+    pub user_id: String,
+    pub task_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetTaskRevisionInput {
+    pub user_id: String,
+    pub task_id: String,
+    pub version: i32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RevertTaskInput {
+    pub user_id: String,
+    pub task_id: String,
+    pub target_version: i32,
+    pub version: i32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PatchTaskInput {
+    pub user_id: String,
+    pub task_id: String,
+    pub format: PatchFormat,
+    pub patch: Value,
+    pub version: Option<i32>,
+    pub max_content_chars: usize,
+}
+
+// `content` is the client's edit of the task as it was at `base_version`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MergeTaskInput {
+    pub user_id: String,
+    pub task_id: String,
+    pub base_version: i32,
+    pub content: String,
+    pub granularity: MergeGranularity,
+    pub max_content_chars: usize,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MoveTaskInput {
+    pub user_id: String,
+    pub task_id: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListTaskSubtreeInput {
+    pub user_id: String,
+    pub task_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReassignTaskInput {
+    pub user_id: String,
+    pub task_id: String,
+    pub assignee_ids: Vec<String>,
+    pub version: i32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AddTaskMemberInput {
+    pub user_id: String,
+    pub task_id: String,
+    pub member_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RemoveTaskMemberInput {
+    pub user_id: String,
+    pub task_id: String,
+    pub member_id: String,
+}
+
+// Marks or clears the caller's own completion of a group task.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SetMemberCompletionInput {
+    pub user_id: String,
+    pub task_id: String,
+    pub completed: bool,
+}
+
+// Moves one task (`task_id`) or every task of `from_user_id` matching
+// `filter` to `to_user_id`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TransferTasksInput {
+    pub actor_id: String,
+    pub is_admin: bool,
+    pub from_user_id: String,
+    pub to_user_id: String,
+    pub task_id: Option<String>,
+    pub filter: TaskFilter,
+    pub now: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TransferTaskLinksInput {
+    pub actor_id: String,
+    pub from_user_id: String,
+    pub to_user_id: String,
+    pub task_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct NextTasksInput {
+    pub user_id: String,
+    pub limit: i64,
+    pub weights: ScoreWeights,
+    pub now: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DuplicateTaskInput {
+    pub user_id: String,
+    pub task_id: String,
+    pub options: DuplicateOptions,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct NewTaskNode {
+    pub content: String,
+    pub labels: Vec<String>,
+    pub due_at: Option<DateTime<Utc>>,
+    pub subtasks: Vec<NewTaskNode>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateTaskTreeInput {
+    pub user_id: String,
+    pub parent_task_id: Option<String>,
+    pub tasks: Vec<NewTaskNode>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct QuickAddTaskInput {
+    pub user_id: String,
+    pub text: String,
+    pub timezone: Option<String>,
+    pub now: DateTime<Utc>,
+    pub max_content_chars: usize,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FilterTasksInput {
+    pub user_id: String,
+    pub page: i64,
+    pub limit: i64,
+    pub filter: TaskFilter,
+    pub now: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TaskStatsInput {
+    pub user_id: String,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub bucket: StatsBucket,
+    pub now: DateTime<Utc>,
+}
+
+// from and to are calendar days in `timezone`, both inclusive.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TaskStatsQuery {
+    pub user_id: String,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub bucket: StatsBucket,
+    pub timezone: String,
+    pub now: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateTasksInput {
+    pub tasks: Vec<CreateTaskInput>,
+}
+
+// Rows that failed to parse arrive as errors so they are reported alongside
+// validation failures, in line order.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ImportTasksInput {
+    pub user_id: String,
+    pub rows: Vec<Result<ImportTask, ImportLineError>>,
+    pub dry_run: bool,
+    pub max_content_chars: usize,
+    pub now: DateTime<Utc>,
+}
+
+// `since` is the token from the previous sync; None asks for a full sync.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SyncTasksInput {
+    pub user_id: String,
+    pub since: Option<String>,
+    pub limit: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListTaskChangesInput {
+    pub user_id: String,
+    pub since: Option<i64>,
+    pub limit: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PushTaskChangesInput {
+    pub user_id: String,
+    pub mutations: Vec<SyncMutation>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApplySyncMutationInput {
+    pub user_id: String,
+    pub mutation: SyncMutation,
 }
@@ -0,0 +1,202 @@
+use pulldown_cmark::{html, CowStr, Event, Options, Parser, Tag, TagEnd};
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChecklistItem {
+    pub text: String,
+    pub checked: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedContent {
+    pub html: String,
+    pub mentions: Vec<String>,
+    pub checklist: Vec<ChecklistItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TaskMention {
+    pub user_id: String,
+    pub username: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskContent {
+    pub content: String,
+    pub html: String,
+    pub mentions: Vec<TaskMention>,
+    pub checklist: Vec<ChecklistItem>,
+}
+
+// Mentions are only recognised in plain text, so "@name" inside code spans,
+// code blocks, link destinations or email addresses is left untouched.
+pub fn render_markdown(content: &str) -> RenderedContent {
+    let options = Options::ENABLE_TASKLISTS | Options::ENABLE_STRIKETHROUGH;
+    let mut events = Vec::new();
+    let mut mentions: Vec<String> = Vec::new();
+    let mut checklist = Vec::new();
+    let mut item: Option<ChecklistItem> = None;
+    let mut in_code_block = false;
+
+    for event in Parser::new_ext(content, options) {
+        match &event {
+            Event::Start(Tag::CodeBlock(_)) => in_code_block = true,
+            Event::End(TagEnd::CodeBlock) => in_code_block = false,
+            Event::TaskListMarker(checked) => {
+                item = Some(ChecklistItem {
+                    text: String::new(),
+                    checked: *checked,
+                });
+            }
+            Event::Start(Tag::List(_)) | Event::End(TagEnd::Item) => {
+                if let Some(done) = item.take() {
+                    checklist.push(ChecklistItem {
+                        text: done.text.trim().to_string(),
+                        checked: done.checked,
+                    });
+                }
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some(current) = item.as_mut() {
+                    current.text.push_str(text);
+                }
+            }
+            Event::SoftBreak | Event::HardBreak => {
+                if let Some(current) = item.as_mut() {
+                    current.text.push(' ');
+                }
+            }
+            _ => {}
+        }
+
+        match event {
+            Event::Text(text) if !in_code_block => {
+                push_mentions(&text, &mut events, &mut mentions);
+            }
+            other => events.push(other),
+        }
+    }
+
+    let mut raw = String::new();
+    html::push_html(&mut raw, events.into_iter());
+
+    RenderedContent {
+        html: sanitize(&raw),
+        mentions,
+        checklist,
+    }
+}
+
+fn push_mentions<'a>(text: &str, events: &mut Vec<Event<'a>>, mentions: &mut Vec<String>) {
+    let mut rest = 0;
+    for (start, username) in find_mentions(text) {
+        if start > rest {
+            events.push(Event::Text(CowStr::from(text[rest..start].to_string())));
+        }
+        events.push(Event::InlineHtml(CowStr::from(format!(
+            "<span class=\"mention\" data-username=\"{}\">@{}</span>",
+            username, username
+        ))));
+        if !mentions.contains(&username) {
+            mentions.push(username.clone());
+        }
+        rest = start + 1 + username.len();
+    }
+    if rest < text.len() {
+        events.push(Event::Text(CowStr::from(text[rest..].to_string())));
+    }
+}
+
+fn find_mentions(text: &str) -> Vec<(usize, String)> {
+    let mut found = Vec::new();
+    let mut previous: Option<char> = None;
+
+    for (index, ch) in text.char_indices() {
+        let boundary = previous.is_none_or(|c| !is_username_char(c));
+        previous = Some(ch);
+        if ch != '@' || !boundary {
+            continue;
+        }
+        let username: String = text[index + 1..]
+            .chars()
+            .take_while(|c| is_username_char(*c))
+            .collect();
+        // A trailing dot is sentence punctuation, not part of the name.
+        let username = username.trim_end_matches('.').to_string();
+        if !username.is_empty() {
+            found.push((index, username));
+        }
+    }
+
+    found
+}
+
+fn is_username_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.')
+}
+
+fn sanitize(raw: &str) -> String {
+    ammonia::Builder::default()
+        .add_tags(["input", "span"])
+        .add_tag_attributes("input", ["checked"])
+        .add_tag_attributes("span", ["data-username"])
+        .add_allowed_classes("span", ["mention"])
+        .set_tag_attribute_value("input", "type", "checkbox")
+        .set_tag_attribute_value("input", "disabled", "")
+        .clean(raw)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_markdown_and_strips_unsafe_html() {
+        let rendered =
+            render_markdown("**Pay** rent <script>alert(1)</script> [x](javascript:alert(1))");
+
+        assert!(rendered.html.contains("<strong>Pay</strong>"));
+        assert!(!rendered.html.contains("<script"));
+        assert!(!rendered.html.contains("javascript:"));
+    }
+
+    #[test]
+    fn extracts_mentions_outside_code_and_emails() {
+        let rendered =
+            render_markdown("Ask @ada and @bob.smith. Mail ada@example.com, not `@carol` @ada");
+
+        assert_eq!(rendered.mentions, vec!["ada", "bob.smith"]);
+        assert!(rendered
+            .html
+            .contains("<span class=\"mention\" data-username=\"ada\">@ada</span>"));
+        assert!(rendered.html.contains("<code>@carol</code>"));
+    }
+
+    #[test]
+    fn collects_checklist_items() {
+        let rendered =
+            render_markdown("Trip\n\n- [ ] Book *hotel*\n- [x] Pack\n  - [ ] Socks\n- plain");
+
+        assert_eq!(
+            rendered.checklist,
+            vec![
+                ChecklistItem {
+                    text: "Book hotel".to_string(),
+                    checked: false,
+                },
+                ChecklistItem {
+                    text: "Pack".to_string(),
+                    checked: true,
+                },
+                ChecklistItem {
+                    text: "Socks".to_string(),
+                    checked: false,
+                },
+            ]
+        );
+        assert_eq!(rendered.html.matches("<input").count(), 3);
+        assert_eq!(rendered.html.matches("type=\"checkbox\"").count(), 3);
+        assert_eq!(rendered.html.matches("checked=\"\"").count(), 1);
+    }
+}
@@ -0,0 +1,209 @@
+use serde::{Deserialize, Serialize};
+
+use super::entity::Task;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MergeGranularity {
+    #[default]
+    Line,
+    Word,
+}
+
+impl MergeGranularity {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "line" => Some(Self::Line),
+            "word" => Some(Self::Word),
+            _ => None,
+        }
+    }
+}
+
+// One region where both sides changed the same part of the base differently.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MergeHunk {
+    pub base: String,
+    pub ours: String,
+    pub theirs: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextMerge {
+    Clean(String),
+    Conflict(Vec<MergeHunk>),
+}
+
+// `ours` is the client's edit and `theirs` the content currently stored.
+#[derive(Debug, Clone)]
+pub struct ContentConflict {
+    pub current_version: i32,
+    pub base: String,
+    pub ours: String,
+    pub theirs: String,
+    pub hunks: Vec<MergeHunk>,
+}
+
+#[derive(Debug, Clone)]
+pub enum TaskMerge {
+    Merged(Box<Task>),
+    Conflict(ContentConflict),
+}
+
+// diff3: regions between tokens that are unchanged on both sides take the one
+// side that changed, or conflict when both did.
+pub fn merge_text(
+    base: &str,
+    ours: &str,
+    theirs: &str,
+    granularity: MergeGranularity,
+) -> TextMerge {
+    let base = tokenize(base, granularity);
+    let ours = tokenize(ours, granularity);
+    let theirs = tokenize(theirs, granularity);
+
+    let to_ours = matches(&base, &ours);
+    let to_theirs = matches(&base, &theirs);
+
+    let mut merged = String::new();
+    let mut hunks = Vec::new();
+    let (mut b, mut o, mut t) = (0, 0, 0);
+    let stable = (0..base.len())
+        .filter_map(|index| Some((index, to_ours[index]?, to_theirs[index]?)))
+        .chain(std::iter::once((base.len(), ours.len(), theirs.len())));
+    for (next_b, next_o, next_t) in stable {
+        let base_chunk = &base[b..next_b];
+        let ours_chunk = &ours[o..next_o];
+        let theirs_chunk = &theirs[t..next_t];
+        if ours_chunk == base_chunk {
+            merged.extend(theirs_chunk.iter().copied());
+        } else if theirs_chunk == base_chunk || theirs_chunk == ours_chunk {
+            merged.extend(ours_chunk.iter().copied());
+        } else {
+            hunks.push(MergeHunk {
+                base: base_chunk.concat(),
+                ours: ours_chunk.concat(),
+                theirs: theirs_chunk.concat(),
+            });
+        }
+
+        if next_b < base.len() {
+            merged.push_str(base[next_b]);
+        }
+        (b, o, t) = (next_b + 1, next_o + 1, next_t + 1);
+    }
+
+    if hunks.is_empty() {
+        TextMerge::Clean(merged)
+    } else {
+        TextMerge::Conflict(hunks)
+    }
+}
+
+// Tokens concatenate back to the input: lines keep their newline and words
+// keep the whitespace runs between them as separate tokens.
+fn tokenize(text: &str, granularity: MergeGranularity) -> Vec<&str> {
+    match granularity {
+        MergeGranularity::Line => text.split_inclusive('\n').collect(),
+        MergeGranularity::Word => {
+            let mut tokens = Vec::new();
+            let mut start = 0;
+            let mut chars = text.char_indices().peekable();
+            while let Some((_, current)) = chars.next() {
+                let boundary = chars
+                    .peek()
+                    .map(|(index, next)| (*index, next.is_whitespace() != current.is_whitespace()));
+                match boundary {
+                    Some((index, true)) => {
+                        tokens.push(&text[start..index]);
+                        start = index;
+                    }
+                    Some((_, false)) => {}
+                    None => tokens.push(&text[start..]),
+                }
+            }
+            tokens
+        }
+    }
+}
+
+// For each token of `from`, its position in `to` along a longest common
+// subsequence.
+fn matches(from: &[&str], to: &[&str]) -> Vec<Option<usize>> {
+    let (n, m) = (from.len(), to.len());
+    let mut lengths = vec![0u32; (n + 1) * (m + 1)];
+    let at = |i: usize, j: usize| i * (m + 1) + j;
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lengths[at(i, j)] = if from[i] == to[j] {
+                lengths[at(i + 1, j + 1)] + 1
+            } else {
+                lengths[at(i + 1, j)].max(lengths[at(i, j + 1)])
+            };
+        }
+    }
+
+    let mut result = vec![None; n];
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if from[i] == to[j] {
+            result[i] = Some(j);
+            i += 1;
+            j += 1;
+        } else if lengths[at(i + 1, j)] >= lengths[at(i, j + 1)] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{merge_text, MergeGranularity, MergeHunk, TextMerge};
+
+    #[test]
+    fn merges_edits_to_different_lines() {
+        let base = "milk\neggs\nbread\ncoffee\n";
+        let ours = "oat milk\neggs\nbread\ncoffee\n";
+        let theirs = "milk\neggs\nbread\ncoffee beans\nbutter\n";
+
+        assert_eq!(
+            merge_text(base, ours, theirs, MergeGranularity::Line),
+            TextMerge::Clean("oat milk\neggs\nbread\ncoffee beans\nbutter\n".to_string())
+        );
+    }
+
+    #[test]
+    fn reports_conflicting_hunks() {
+        let base = "Call the bank\nPay rent\n";
+        let ours = "Call the bank\nPay rent on Friday\n";
+        let theirs = "Call the bank\nPay rent today\n";
+
+        assert_eq!(
+            merge_text(base, ours, theirs, MergeGranularity::Line),
+            TextMerge::Conflict(vec![MergeHunk {
+                base: "Pay rent\n".to_string(),
+                ours: "Pay rent on Friday\n".to_string(),
+                theirs: "Pay rent today\n".to_string(),
+            }])
+        );
+    }
+
+    #[test]
+    fn word_mode_merges_edits_within_a_line() {
+        let base = "Pay rent and call the bank";
+        let ours = "Pay the rent and call the bank";
+        let theirs = "Pay rent and email the bank";
+
+        assert_eq!(
+            merge_text(base, ours, theirs, MergeGranularity::Word),
+            TextMerge::Clean("Pay the rent and email the bank".to_string())
+        );
+        assert!(matches!(
+            merge_text(base, ours, theirs, MergeGranularity::Line),
+            TextMerge::Conflict(_)
+        ));
+    }
+}
@@ -1,2 +1,22 @@
+pub mod duplicate;
 pub mod entity;
+pub mod filter;
+pub mod group;
+pub mod ical;
+pub mod import;
 pub mod inputs;
+pub mod markdown;
+pub mod merge;
+pub mod next;
+pub mod priority;
+pub mod quick_add;
+pub mod rank;
+pub mod recurrence;
+pub mod revision;
+pub mod rollup;
+pub mod stats;
+pub mod status;
+pub mod sync;
+pub mod todo_txt;
+pub mod transfer;
+pub mod validation;
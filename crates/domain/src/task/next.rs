@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+
+use super::entity::Task;
+use crate::error::AppError;
+
+pub const DEFAULT_NEXT_LIMIT: i64 = 20;
+pub const MAX_NEXT_LIMIT: i64 = 100;
+// Only this many open tasks are considered; beyond it the oldest are skipped.
+pub const MAX_NEXT_CANDIDATES: i64 = 500;
+pub const MAX_SCORE_WEIGHT: f64 = 100.0;
+// Due dates further out than this add nothing; tasks older than the age
+// horizon get the full age bonus.
+pub const DUE_HORIZON_DAYS: i64 = 14;
+pub const AGE_HORIZON_DAYS: i64 = 30;
+
+// Priority, due and age raise a task's score; blocked and snoozed lower it.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ScoreWeights {
+    pub priority: f64,
+    pub due: f64,
+    pub blocked: f64,
+    pub snoozed: f64,
+    pub age: f64,
+}
+
+impl Default for ScoreWeights {
+    fn default() -> Self {
+        Self {
+            priority: 3.0,
+            due: 4.0,
+            blocked: 5.0,
+            snoozed: 6.0,
+            age: 1.0,
+        }
+    }
+}
+
+impl ScoreWeights {
+    pub fn validate(&self) -> Result<(), AppError> {
+        let weights = [
+            ("priority_weight", self.priority),
+            ("due_weight", self.due),
+            ("blocked_weight", self.blocked),
+            ("snoozed_weight", self.snoozed),
+            ("age_weight", self.age),
+        ];
+        for (field, weight) in weights {
+            if !(0.0..=MAX_SCORE_WEIGHT).contains(&weight) {
+                return Err(AppError::validation(
+                    "invalid_weight",
+                    "Weights must be between 0 and 100",
+                    Some(field.to_string()),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScoreFactor {
+    Priority,
+    Due,
+    Blocked,
+    Snoozed,
+    Age,
+}
+
+impl ScoreFactor {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Priority => "priority",
+            Self::Due => "due",
+            Self::Blocked => "blocked",
+            Self::Snoozed => "snoozed",
+            Self::Age => "age",
+        }
+    }
+}
+
+// One factor's share of a score, with a human-readable reason.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoreReason {
+    pub factor: ScoreFactor,
+    pub contribution: f64,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoredTask {
+    pub task: Task,
+    pub score: f64,
+    pub reasons: Vec<ScoreReason>,
+}
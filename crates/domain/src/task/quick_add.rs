@@ -0,0 +1,479 @@
+use chrono::{DateTime, Datelike, Duration, Months, NaiveDate, NaiveTime, Utc, Weekday};
+use chrono_tz::Tz;
+use serde::Serialize;
+
+use super::priority::TaskPriority;
+use super::recurrence::{Frequency, Recurrence, MAX_RECURRENCE_INTERVAL};
+use super::validation::{validate_content, validate_labels};
+use crate::error::AppError;
+use crate::user::timezone::local_to_utc;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct QuickAdd {
+    pub content: String,
+    pub due_at: Option<DateTime<Utc>>,
+    pub priority: Option<TaskPriority>,
+    pub labels: Vec<String>,
+    pub recurrence: Option<String>,
+}
+
+enum Due {
+    Date(NaiveDate),
+    At(DateTime<Utc>),
+}
+
+// Tokens are consumed left to right; anything not recognised as a date, time,
+// priority, label or recurrence stays in the content. Only the first date and
+// time are taken so a second "tomorrow" is kept as text.
+pub fn parse_quick_add(
+    text: &str,
+    now: DateTime<Utc>,
+    timezone: Tz,
+    max_content_chars: usize,
+) -> Result<QuickAdd, AppError> {
+    let today = now.with_timezone(&timezone).date_naive();
+    let tokens: Vec<&str> = text.split_whitespace().collect();
+    let lower: Vec<String> = tokens
+        .iter()
+        .map(|token| token.trim_end_matches(',').to_lowercase())
+        .collect();
+
+    let mut words = Vec::new();
+    let mut labels: Vec<String> = Vec::new();
+    let mut priority = None;
+    let mut recurrence = None;
+    let mut due = None;
+    let mut time = None;
+
+    let mut index = 0;
+    while index < tokens.len() {
+        let rest = &lower[index..];
+
+        if let Some(label) = tokens[index]
+            .strip_prefix('#')
+            .filter(|label| is_label(label))
+        {
+            if !labels.iter().any(|existing| existing == label) {
+                labels.push(label.to_string());
+            }
+            index += 1;
+            continue;
+        }
+        if priority.is_none() {
+            if let Some(parsed) = rest[0].strip_prefix('!').and_then(parse_priority) {
+                priority = Some(parsed);
+                index += 1;
+                continue;
+            }
+        }
+        if recurrence.is_none() {
+            if let Some((rule, anchor, used)) = match_recurrence(rest, today) {
+                recurrence = Some(rule.to_string());
+                if due.is_none() {
+                    due = anchor.map(Due::Date);
+                }
+                index += used;
+                continue;
+            }
+        }
+        if due.is_none() {
+            if let Some((parsed, used)) = match_due(rest, today, now) {
+                due = Some(parsed);
+                index += used;
+                continue;
+            }
+        }
+        if time.is_none() && !matches!(due, Some(Due::At(_))) {
+            if let Some((parsed, used)) = match_time(rest) {
+                time = Some(parsed);
+                index += used;
+                continue;
+            }
+        }
+
+        words.push(tokens[index]);
+        index += 1;
+    }
+
+    let content = words.join(" ");
+    validate_content(&content, max_content_chars)?;
+    validate_labels(&labels)?;
+
+    let due_at = match (due, time) {
+        (Some(Due::At(at)), _) => Some(at),
+        (Some(Due::Date(date)), Some(time)) => Some(local_to_utc(date.and_time(time), timezone)),
+        (Some(Due::Date(date)), None) => Some(local_to_utc(date.and_time(end_of_day()), timezone)),
+        (None, Some(time)) => {
+            let local_now = now.with_timezone(&timezone).naive_local();
+            let date = if today.and_time(time) > local_now {
+                today
+            } else {
+                today + Duration::days(1)
+            };
+            Some(local_to_utc(date.and_time(time), timezone))
+        }
+        (None, None) => None,
+    };
+
+    Ok(QuickAdd {
+        content,
+        due_at,
+        priority,
+        labels,
+        recurrence,
+    })
+}
+
+fn end_of_day() -> NaiveTime {
+    NaiveTime::from_hms_opt(23, 59, 0).unwrap_or_default()
+}
+
+fn is_label(label: &str) -> bool {
+    !label.is_empty()
+        && label
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '/' | '.'))
+}
+
+fn parse_priority(value: &str) -> Option<TaskPriority> {
+    match value {
+        "1" | "urgent" => Some(TaskPriority::Urgent),
+        "2" | "high" | "h" => Some(TaskPriority::High),
+        "3" | "medium" | "m" => Some(TaskPriority::Medium),
+        "4" | "low" | "l" => Some(TaskPriority::Low),
+        _ => None,
+    }
+}
+
+fn parse_weekday(value: &str) -> Option<Weekday> {
+    match value {
+        "monday" | "mon" => Some(Weekday::Mon),
+        "tuesday" | "tue" | "tues" => Some(Weekday::Tue),
+        "wednesday" | "wed" => Some(Weekday::Wed),
+        "thursday" | "thu" | "thurs" => Some(Weekday::Thu),
+        "friday" | "fri" => Some(Weekday::Fri),
+        "saturday" | "sat" => Some(Weekday::Sat),
+        "sunday" | "sun" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+fn parse_month(value: &str) -> Option<u32> {
+    let months = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ];
+    let prefix = value.get(..3)?;
+    let full = [
+        "january",
+        "february",
+        "march",
+        "april",
+        "may",
+        "june",
+        "july",
+        "august",
+        "september",
+        "october",
+        "november",
+        "december",
+    ];
+    let position = months.iter().position(|month| *month == prefix)?;
+    (value.len() == 3 || full[position] == value || value == "sept").then_some(position as u32 + 1)
+}
+
+fn parse_unit(value: &str) -> Option<Frequency> {
+    match value.trim_end_matches('s') {
+        "day" => Some(Frequency::Daily),
+        "week" => Some(Frequency::Weekly),
+        "month" => Some(Frequency::Monthly),
+        "year" => Some(Frequency::Yearly),
+        _ => None,
+    }
+}
+
+fn parse_day_of_month(value: &str) -> Option<u32> {
+    let digits = value.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+    let suffix = &value[digits.len()..];
+    if !matches!(suffix, "" | "st" | "nd" | "rd" | "th") {
+        return None;
+    }
+    digits.parse().ok().filter(|day| (1..=31).contains(day))
+}
+
+fn next_weekday(today: NaiveDate, weekday: Weekday) -> NaiveDate {
+    let ahead = (weekday.num_days_from_monday() + 7 - today.weekday().num_days_from_monday()) % 7;
+    today + Duration::days(if ahead == 0 { 7 } else { i64::from(ahead) })
+}
+
+fn add_interval(date: NaiveDate, frequency: Frequency, count: u32) -> Option<NaiveDate> {
+    match frequency {
+        Frequency::Daily => date.checked_add_signed(Duration::days(i64::from(count))),
+        Frequency::Weekly => date.checked_add_signed(Duration::weeks(i64::from(count))),
+        Frequency::Monthly => date.checked_add_months(Months::new(count)),
+        Frequency::Yearly => date.checked_add_months(Months::new(count * 12)),
+    }
+}
+
+fn match_recurrence(
+    tokens: &[String],
+    today: NaiveDate,
+) -> Option<(Recurrence, Option<NaiveDate>, usize)> {
+    let single = match tokens[0].as_str() {
+        "daily" => Some(Frequency::Daily),
+        "weekly" => Some(Frequency::Weekly),
+        "monthly" => Some(Frequency::Monthly),
+        "yearly" | "annually" => Some(Frequency::Yearly),
+        _ => None,
+    };
+    if let Some(frequency) = single {
+        return Some((Recurrence::new(frequency, 1), None, 1));
+    }
+    if tokens[0] != "every" {
+        return None;
+    }
+
+    let next = tokens.get(1)?;
+    if let Some(weekday) = parse_weekday(next) {
+        let recurrence = Recurrence {
+            by_day: Some(weekday),
+            ..Recurrence::new(Frequency::Weekly, 1)
+        };
+        return Some((recurrence, Some(next_weekday(today, weekday)), 2));
+    }
+    if let Some(frequency) = parse_unit(next) {
+        return Some((Recurrence::new(frequency, 1), None, 2));
+    }
+    let interval = match next.as_str() {
+        "other" => 2,
+        count => count.parse::<u32>().ok()?,
+    };
+    if !(1..=MAX_RECURRENCE_INTERVAL).contains(&interval) {
+        return None;
+    }
+    let frequency = parse_unit(tokens.get(2)?)?;
+
+    Some((Recurrence::new(frequency, interval), None, 3))
+}
+
+fn match_due(tokens: &[String], today: NaiveDate, now: DateTime<Utc>) -> Option<(Due, usize)> {
+    if matches!(tokens[0].as_str(), "on" | "by" | "due") && tokens.len() > 1 {
+        if let Some((due, used)) = match_due(&tokens[1..], today, now) {
+            return Some((due, used + 1));
+        }
+        return None;
+    }
+
+    let date = |date: NaiveDate, used: usize| Some((Due::Date(date), used));
+    match tokens[0].as_str() {
+        "today" | "tonight" => return date(today, 1),
+        "tomorrow" | "tmr" | "tmrw" => return date(today + Duration::days(1), 1),
+        _ => {}
+    }
+
+    if let Some(weekday) = parse_weekday(&tokens[0]) {
+        return date(next_weekday(today, weekday), 1);
+    }
+
+    if tokens[0] == "next" {
+        let next = tokens.get(1)?;
+        if let Some(weekday) = parse_weekday(next) {
+            return date(next_weekday(today, weekday), 2);
+        }
+        return match next.as_str() {
+            "week" => date(next_weekday(today, Weekday::Mon), 2),
+            "month" => date(today.with_day(1)?.checked_add_months(Months::new(1))?, 2),
+            "year" => date(NaiveDate::from_ymd_opt(today.year() + 1, 1, 1)?, 2),
+            _ => None,
+        };
+    }
+
+    if tokens[0] == "in" {
+        let count = match tokens.get(1)?.as_str() {
+            "a" | "an" => 1,
+            count => count.parse::<u32>().ok().filter(|count| *count <= 1000)?,
+        };
+        let unit = tokens.get(2)?.trim_end_matches('s');
+        let at = match unit {
+            "minute" | "min" => Some(now + Duration::minutes(i64::from(count))),
+            "hour" | "hr" => Some(now + Duration::hours(i64::from(count))),
+            _ => None,
+        };
+        if let Some(at) = at {
+            return Some((Due::At(at), 3));
+        }
+        return date(add_interval(today, parse_unit(unit)?, count)?, 3);
+    }
+
+    if let Ok(parsed) = NaiveDate::parse_from_str(&tokens[0], "%Y-%m-%d") {
+        return date(parsed, 1);
+    }
+
+    // "mar 5", "march 5th" and "5 march"; dates already past roll over to next year.
+    let (month, day) = match (
+        parse_month(&tokens[0]),
+        tokens.get(1).and_then(|token| parse_day_of_month(token)),
+    ) {
+        (Some(month), Some(day)) => (month, day),
+        _ => (
+            tokens.get(1).and_then(|token| parse_month(token))?,
+            parse_day_of_month(&tokens[0])?,
+        ),
+    };
+    let this_year = NaiveDate::from_ymd_opt(today.year(), month, day)?;
+    if this_year >= today {
+        date(this_year, 2)
+    } else {
+        date(NaiveDate::from_ymd_opt(today.year() + 1, month, day)?, 2)
+    }
+}
+
+fn match_time(tokens: &[String]) -> Option<(NaiveTime, usize)> {
+    if tokens[0] == "at" && tokens.len() > 1 {
+        if let Some((time, used)) = match_time(&tokens[1..]) {
+            return Some((time, used + 1));
+        }
+        let hour = tokens[1].parse::<u32>().ok().filter(|hour| *hour < 24)?;
+        return Some((NaiveTime::from_hms_opt(hour, 0, 0)?, 2));
+    }
+
+    match tokens[0].as_str() {
+        "noon" | "midday" => return Some((NaiveTime::from_hms_opt(12, 0, 0)?, 1)),
+        "midnight" => return Some((NaiveTime::from_hms_opt(0, 0, 0)?, 1)),
+        _ => {}
+    }
+
+    let (clock, meridiem, used) = match tokens[0]
+        .strip_suffix("am")
+        .map(|clock| (clock, Some(false)))
+        .or_else(|| {
+            tokens[0]
+                .strip_suffix("pm")
+                .map(|clock| (clock, Some(true)))
+        }) {
+        Some((clock, meridiem)) if !clock.is_empty() => (clock, meridiem, 1),
+        _ => match tokens.get(1).map(String::as_str) {
+            Some("am") => (tokens[0].as_str(), Some(false), 2),
+            Some("pm") => (tokens[0].as_str(), Some(true), 2),
+            _ => (tokens[0].as_str(), None, 1),
+        },
+    };
+
+    let (hour, minute) = match clock.split_once(':') {
+        Some((hour, minute)) if minute.len() == 2 => (hour.parse().ok()?, minute.parse().ok()?),
+        None if meridiem.is_some() => (clock.parse::<u32>().ok()?, 0),
+        _ => return None,
+    };
+    let hour = match meridiem {
+        Some(pm) if (1..=12).contains(&hour) => hour % 12 + if pm { 12 } else { 0 },
+        Some(_) => return None,
+        None => hour,
+    };
+
+    Some((NaiveTime::from_hms_opt(hour, minute, 0)?, used))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use chrono_tz::Tz;
+
+    use super::parse_quick_add;
+    use crate::task::priority::TaskPriority;
+    use crate::task::validation::DEFAULT_MAX_CONTENT_CHARS;
+
+    // Monday 2025-03-03 10:00 in Paris.
+    fn now() -> chrono::DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, 3, 9, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn extracts_all_parts_and_keeps_clean_content() {
+        let parsed = parse_quick_add(
+            "Pay rent tomorrow 9am #finance !high every month",
+            now(),
+            Tz::Europe__Paris,
+            DEFAULT_MAX_CONTENT_CHARS,
+        )
+        .expect("parse");
+
+        assert_eq!(parsed.content, "Pay rent");
+        assert_eq!(
+            parsed.due_at,
+            Some(Utc.with_ymd_and_hms(2025, 3, 4, 8, 0, 0).unwrap())
+        );
+        assert_eq!(parsed.priority, Some(TaskPriority::High));
+        assert_eq!(parsed.labels, vec!["finance".to_string()]);
+        assert_eq!(
+            parsed.recurrence.as_deref(),
+            Some("FREQ=MONTHLY;INTERVAL=1")
+        );
+    }
+
+    #[test]
+    fn resolves_dates_in_the_users_timezone() {
+        let parsed = parse_quick_add(
+            "Standup at 9:30",
+            now(),
+            Tz::America__New_York,
+            DEFAULT_MAX_CONTENT_CHARS,
+        )
+        .expect("parse");
+
+        // 09:00 UTC is 04:00 in New York, so 9:30 is still ahead today.
+        assert_eq!(
+            parsed.due_at,
+            Some(Utc.with_ymd_and_hms(2025, 3, 3, 14, 30, 0).unwrap())
+        );
+
+        let parsed = parse_quick_add(
+            "Review on friday",
+            now(),
+            Tz::Europe__Paris,
+            DEFAULT_MAX_CONTENT_CHARS,
+        )
+        .expect("parse");
+        assert_eq!(
+            parsed.due_at,
+            Some(Utc.with_ymd_and_hms(2025, 3, 7, 22, 59, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn leaves_plain_text_alone() {
+        let parsed = parse_quick_add(
+            "Email #1 about the 5 mats",
+            now(),
+            Tz::UTC,
+            DEFAULT_MAX_CONTENT_CHARS,
+        )
+        .expect("parse");
+
+        assert_eq!(parsed.content, "Email about the 5 mats");
+        assert_eq!(parsed.labels, vec!["1".to_string()]);
+        assert_eq!(parsed.due_at, None);
+        assert!(
+            parse_quick_add("#only tomorrow", now(), Tz::UTC, DEFAULT_MAX_CONTENT_CHARS).is_err()
+        );
+    }
+
+    #[test]
+    fn every_weekday_anchors_the_first_due_date() {
+        let parsed = parse_quick_add(
+            "Gym every wednesday 7pm",
+            now(),
+            Tz::UTC,
+            DEFAULT_MAX_CONTENT_CHARS,
+        )
+        .expect("parse");
+
+        assert_eq!(parsed.content, "Gym");
+        assert_eq!(
+            parsed.recurrence.as_deref(),
+            Some("FREQ=WEEKLY;INTERVAL=1;BYDAY=WE")
+        );
+        assert_eq!(
+            parsed.due_at,
+            Some(Utc.with_ymd_and_hms(2025, 3, 5, 19, 0, 0).unwrap())
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use serde::Deserialize;
+
+const DIGITS: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+const BASE: usize = DIGITS.len();
+
+pub const MAX_RANK_LENGTH: usize = 32;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub enum TaskSort {
+    #[default]
+    CreatedAt,
+    Manual,
+}
+
+pub fn parse_sort(value: &str) -> Option<TaskSort> {
+    match value {
+        "created_at" => Some(TaskSort::CreatedAt),
+        "manual" => Some(TaskSort::Manual),
+        _ => None,
+    }
+}
+
+pub fn rank_between(lower: Option<&str>, upper: Option<&str>) -> Option<String> {
+    let lower = digits_of(lower.unwrap_or(""))?;
+    let upper = match upper {
+        Some(upper) => Some(digits_of(upper)?),
+        None => None,
+    };
+    if let Some(upper) = &upper {
+        if lower >= *upper {
+            return None;
+        }
+    }
+
+    let rank = midpoint(&lower, upper.as_deref());
+    if rank.len() > MAX_RANK_LENGTH {
+        return None;
+    }
+
+    Some(
+        rank.into_iter()
+            .map(|digit| DIGITS[digit] as char)
+            .collect(),
+    )
+}
+
+pub fn spread_ranks(count: usize) -> Vec<String> {
+    let slots = count as u128 + 1;
+    let mut width = 4;
+    while (BASE as u128).pow(width) / slots < BASE as u128 {
+        width += 1;
+    }
+    let step = (BASE as u128).pow(width) / slots;
+
+    (1..slots)
+        .map(|slot| {
+            let mut value = slot * step;
+            let mut rank = vec![b'0'; width as usize];
+            for position in (0..width as usize).rev() {
+                rank[position] = DIGITS[(value % BASE as u128) as usize];
+                value /= BASE as u128;
+            }
+            while rank.last() == Some(&b'0') {
+                rank.pop();
+            }
+            String::from_utf8(rank).unwrap_or_default()
+        })
+        .collect()
+}
+
+fn digits_of(rank: &str) -> Option<Vec<usize>> {
+    let digits: Vec<usize> = rank
+        .bytes()
+        .map(|byte| DIGITS.iter().position(|digit| *digit == byte))
+        .collect::<Option<_>>()?;
+    if digits.last() == Some(&0) {
+        return None;
+    }
+    Some(digits)
+}
+
+// Both bounds are base-36 fractions without trailing zeros; an absent upper
+// bound stands for 1.0. The result sorts strictly between them.
+fn midpoint(lower: &[usize], upper: Option<&[usize]>) -> Vec<usize> {
+    if let Some(upper) = upper {
+        let shared = upper
+            .iter()
+            .enumerate()
+            .take_while(|(index, digit)| lower.get(*index).copied().unwrap_or(0) == **digit)
+            .count();
+        if shared > 0 {
+            let mut rank = upper[..shared].to_vec();
+            let rest_lower = lower.get(shared..).unwrap_or(&[]);
+            rank.extend(midpoint(rest_lower, Some(&upper[shared..])));
+            return rank;
+        }
+    }
+
+    let low = lower.first().copied().unwrap_or(0);
+    let high = upper
+        .and_then(|upper| upper.first().copied())
+        .unwrap_or(BASE);
+    if high - low > 1 {
+        return vec![(low + high).div_ceil(2)];
+    }
+
+    match upper {
+        Some(upper) if upper.len() > 1 => vec![upper[0]],
+        _ => {
+            let mut rank = vec![low];
+            rank.extend(midpoint(lower.get(1..).unwrap_or(&[]), None));
+            rank
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rank_between, spread_ranks, MAX_RANK_LENGTH};
+
+    #[test]
+    fn ranks_sort_between_bounds() {
+        let first = rank_between(None, None).expect("rank");
+        let before = rank_between(None, Some(&first)).expect("rank");
+        let after = rank_between(Some(&first), None).expect("rank");
+        let middle = rank_between(Some(&before), Some(&first)).expect("rank");
+
+        assert!(before < middle && middle < first && first < after);
+    }
+
+    #[test]
+    fn repeated_inserts_eventually_need_rebalance() {
+        let mut upper = rank_between(None, None).expect("rank");
+        let mut exhausted = false;
+        for _ in 0..500 {
+            match rank_between(None, Some(&upper)) {
+                Some(rank) => {
+                    assert!(rank < upper && rank.len() <= MAX_RANK_LENGTH);
+                    upper = rank;
+                }
+                None => {
+                    exhausted = true;
+                    break;
+                }
+            }
+        }
+
+        assert!(exhausted);
+    }
+
+    #[test]
+    fn spread_ranks_are_unique_and_ordered() {
+        let ranks = spread_ranks(2000);
+
+        assert_eq!(ranks.len(), 2000);
+        assert!(ranks.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(ranks.iter().all(|rank| !rank.ends_with('0')));
+    }
+}
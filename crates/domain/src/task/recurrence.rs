@@ -0,0 +1,116 @@
+use std::fmt;
+
+use chrono::Weekday;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl Frequency {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Daily => "DAILY",
+            Self::Weekly => "WEEKLY",
+            Self::Monthly => "MONTHLY",
+            Self::Yearly => "YEARLY",
+        }
+    }
+}
+
+// A small subset of RFC 5545 RRULE: FREQ, INTERVAL and a single BYDAY.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Recurrence {
+    pub frequency: Frequency,
+    pub interval: u32,
+    pub by_day: Option<Weekday>,
+}
+
+pub const MAX_RECURRENCE_INTERVAL: u32 = 365;
+
+impl Recurrence {
+    pub fn new(frequency: Frequency, interval: u32) -> Self {
+        Self {
+            frequency,
+            interval,
+            by_day: None,
+        }
+    }
+
+    pub fn parse(rule: &str) -> Option<Self> {
+        let mut frequency = None;
+        let mut interval = 1;
+        let mut by_day = None;
+        for part in rule.split(';') {
+            let (key, value) = part.split_once('=')?;
+            match key {
+                "FREQ" => {
+                    frequency = Some(match value {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => return None,
+                    })
+                }
+                "INTERVAL" => interval = value.parse().ok()?,
+                "BYDAY" => by_day = Some(parse_weekday_code(value)?),
+                _ => return None,
+            }
+        }
+        if !(1..=MAX_RECURRENCE_INTERVAL).contains(&interval) {
+            return None;
+        }
+
+        Some(Self {
+            frequency: frequency?,
+            interval,
+            by_day,
+        })
+    }
+}
+
+impl fmt::Display for Recurrence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "FREQ={};INTERVAL={}",
+            self.frequency.as_str(),
+            self.interval
+        )?;
+        if let Some(day) = self.by_day {
+            write!(f, ";BYDAY={}", weekday_code(day))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn weekday_code(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+fn parse_weekday_code(code: &str) -> Option<Weekday> {
+    [
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+        Weekday::Sun,
+    ]
+    .into_iter()
+    .find(|day| weekday_code(*day) == code)
+}
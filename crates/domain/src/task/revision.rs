@@ -0,0 +1,171 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::entity::Task;
+use super::priority::TaskPriority;
+use super::status::TaskStatus;
+use super::validation::{
+    validate_assignees, validate_content, validate_estimates, validate_labels, validate_recurrence,
+    validate_waiting_on,
+};
+use crate::error::AppError;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskSnapshot {
+    pub content: String,
+    #[serde(default)]
+    pub status: TaskStatus,
+    pub completed_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub parent_task_id: Option<String>,
+    #[serde(default)]
+    pub estimate_minutes: Option<i32>,
+    #[serde(default)]
+    pub remaining_minutes: Option<i32>,
+    #[serde(default)]
+    pub story_points: Option<i32>,
+    #[serde(default)]
+    pub due_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub priority: Option<TaskPriority>,
+    #[serde(default)]
+    pub recurrence: Option<String>,
+    #[serde(default)]
+    pub snoozed_until: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub waiting_on: Option<String>,
+    #[serde(default)]
+    pub assignee_ids: Vec<String>,
+    #[serde(default)]
+    pub group: bool,
+}
+
+impl TaskSnapshot {
+    pub fn validate(&self, max_content_chars: usize) -> Result<(), AppError> {
+        validate_content(&self.content, max_content_chars)?;
+        validate_labels(&self.labels)?;
+        validate_recurrence(self.recurrence.as_deref())?;
+        validate_waiting_on(self.waiting_on.as_deref())?;
+        validate_assignees(&self.assignee_ids)?;
+        validate_estimates(
+            self.estimate_minutes,
+            self.remaining_minutes,
+            self.story_points,
+        )
+    }
+
+    pub fn diff(&self, next: &TaskSnapshot) -> Vec<TaskFieldChange> {
+        self.fields()
+            .into_iter()
+            .zip(next.fields())
+            .filter(|((_, old_value), (_, new_value))| old_value != new_value)
+            .map(|((field, old_value), (_, new_value))| {
+                TaskFieldChange::new(field, old_value, new_value)
+            })
+            .collect()
+    }
+
+    fn fields(&self) -> Vec<(&'static str, Option<String>)> {
+        vec![
+            ("content", Some(self.content.clone())),
+            ("status", Some(self.status.as_str().to_string())),
+            ("completed_at", self.completed_at.map(|dt| dt.to_rfc3339())),
+            ("parent_task_id", self.parent_task_id.clone()),
+            (
+                "estimate_minutes",
+                self.estimate_minutes.map(|value| value.to_string()),
+            ),
+            (
+                "remaining_minutes",
+                self.remaining_minutes.map(|value| value.to_string()),
+            ),
+            (
+                "story_points",
+                self.story_points.map(|value| value.to_string()),
+            ),
+            ("due_at", self.due_at.map(|dt| dt.to_rfc3339())),
+            (
+                "labels",
+                (!self.labels.is_empty()).then(|| self.labels.join(",")),
+            ),
+            (
+                "priority",
+                self.priority.map(|priority| priority.as_str().to_string()),
+            ),
+            ("recurrence", self.recurrence.clone()),
+            (
+                "snoozed_until",
+                self.snoozed_until.map(|dt| dt.to_rfc3339()),
+            ),
+            ("waiting_on", self.waiting_on.clone()),
+            (
+                "assignee_ids",
+                (!self.assignee_ids.is_empty()).then(|| self.assignee_ids.join(",")),
+            ),
+            ("group", self.group.then(|| "true".to_string())),
+        ]
+    }
+}
+
+impl From<&Task> for TaskSnapshot {
+    fn from(task: &Task) -> Self {
+        Self {
+            content: task.content.clone(),
+            status: task.status,
+            completed_at: task.completed_at,
+            parent_task_id: task.parent_task_id.clone(),
+            estimate_minutes: task.estimate_minutes,
+            remaining_minutes: task.remaining_minutes,
+            story_points: task.story_points,
+            due_at: task.due_at,
+            labels: task.labels.clone(),
+            priority: task.priority,
+            recurrence: task.recurrence.clone(),
+            snoozed_until: task.snoozed_until,
+            waiting_on: task.waiting_on.clone(),
+            assignee_ids: task.assignee_ids.clone(),
+            group: task.group.is_some(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskFieldChange {
+    pub field: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+}
+
+impl TaskFieldChange {
+    pub fn new(
+        field: impl Into<String>,
+        old_value: Option<String>,
+        new_value: Option<String>,
+    ) -> Self {
+        Self {
+            field: field.into(),
+            old_value,
+            new_value,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskRevision {
+    pub task_id: String,
+    pub version: i32,
+    pub changed_by: String,
+    pub changes: Vec<TaskFieldChange>,
+    pub snapshot: TaskSnapshot,
+    pub created_at: DateTime<Utc>,
+}
+
+// A revert restores what it still can: relationship fields pointing at tasks
+// or members that are gone keep their current value and are listed here.
+#[derive(Debug, Clone)]
+pub struct RevertedTask {
+    pub task: Task,
+    pub skipped_fields: Vec<String>,
+}
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+use super::entity::Task;
+use super::status::TaskStatus;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskRollup {
+    pub task_id: String,
+    pub task_count: i64,
+    pub completed_count: i64,
+    pub estimate_minutes: i64,
+    pub remaining_minutes: i64,
+    pub story_points: i64,
+    pub completed_story_points: i64,
+}
+
+impl TaskRollup {
+    // Open tasks without an explicit remaining value still owe their full
+    // estimate; completed tasks owe nothing.
+    pub fn from_tasks(task_id: &str, tasks: &[Task]) -> Self {
+        let mut rollup = Self {
+            task_id: task_id.to_string(),
+            task_count: 0,
+            completed_count: 0,
+            estimate_minutes: 0,
+            remaining_minutes: 0,
+            story_points: 0,
+            completed_story_points: 0,
+        };
+        for task in tasks {
+            let completed = task.status == TaskStatus::Completed;
+            let points = i64::from(task.story_points.unwrap_or(0));
+            rollup.task_count += 1;
+            rollup.estimate_minutes += i64::from(task.estimate_minutes.unwrap_or(0));
+            rollup.story_points += points;
+            if completed {
+                rollup.completed_count += 1;
+                rollup.completed_story_points += points;
+            } else {
+                rollup.remaining_minutes += i64::from(
+                    task.remaining_minutes
+                        .or(task.estimate_minutes)
+                        .unwrap_or(0),
+                );
+            }
+        }
+        rollup
+    }
+}
@@ -0,0 +1,58 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::status::TaskStatus;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StatsBucket {
+    #[default]
+    Day,
+    Week,
+}
+
+impl StatsBucket {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "day" => Some(Self::Day),
+            "week" => Some(Self::Week),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Day => "day",
+            Self::Week => "week",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusCount {
+    pub status: TaskStatus,
+    pub count: i64,
+}
+
+// Weeks start on Monday, matching Postgres date_trunc('week', ...).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThroughputBucket {
+    pub start: NaiveDate,
+    pub created: i64,
+    pub completed: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskStats {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub timezone: String,
+    pub bucket: StatsBucket,
+    pub by_status: Vec<StatusCount>,
+    pub throughput: Vec<ThroughputBucket>,
+    pub created: i64,
+    pub completed: i64,
+    pub average_lead_time_seconds: Option<i64>,
+    pub overdue: i64,
+    pub completed_late: i64,
+}
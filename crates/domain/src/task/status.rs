@@ -0,0 +1,149 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TaskStatus {
+    #[default]
+    Pending,
+    InProgress,
+    Completed,
+}
+
+impl TaskStatus {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "PENDING" => Some(Self::Pending),
+            "IN_PROGRESS" => Some(Self::InProgress),
+            "COMPLETED" => Some(Self::Completed),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "PENDING",
+            Self::InProgress => "IN_PROGRESS",
+            Self::Completed => "COMPLETED",
+        }
+    }
+
+    // A completed task has to be reopened to PENDING before work resumes on it.
+    pub fn can_transition_to(&self, next: TaskStatus) -> bool {
+        !matches!((self, next), (Self::Completed, Self::InProgress))
+    }
+
+    pub fn transition_to(&self, next: TaskStatus) -> Result<TaskStatus, AppError> {
+        if !self.can_transition_to(next) {
+            return Err(AppError::conflict(
+                "Task",
+                format!(
+                    "Cannot move task from {} to {}",
+                    self.as_str(),
+                    next.as_str()
+                ),
+            ));
+        }
+
+        Ok(next)
+    }
+}
+
+// Keeps status and completed_at consistent when either one changes. Setting
+// completed_at implies COMPLETED and clearing it reopens a completed task, so
+// clearing it alongside a new status goes through PENDING in the same update.
+pub fn resolve_completion(
+    current_status: TaskStatus,
+    current_completed_at: Option<DateTime<Utc>>,
+    status: Option<TaskStatus>,
+    completed_at: Option<Option<DateTime<Utc>>>,
+    now: DateTime<Utc>,
+) -> Result<(TaskStatus, Option<DateTime<Utc>>), AppError> {
+    let next = match (status, completed_at) {
+        (Some(status), _) => status,
+        (None, Some(Some(_))) => TaskStatus::Completed,
+        (None, Some(None)) if current_status == TaskStatus::Completed => TaskStatus::Pending,
+        (None, _) => current_status,
+    };
+    let from = match completed_at {
+        Some(None) if current_status == TaskStatus::Completed => {
+            current_status.transition_to(TaskStatus::Pending)?
+        }
+        _ => current_status,
+    };
+    let next = from.transition_to(next)?;
+
+    let completed_at = match (next, completed_at) {
+        (TaskStatus::Completed, Some(Some(at))) => Some(at),
+        (TaskStatus::Completed, _) => current_completed_at.or(Some(now)),
+        _ => None,
+    };
+
+    Ok((next, completed_at))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::{resolve_completion, TaskStatus};
+
+    #[test]
+    fn completing_sets_completed_at_once() {
+        let now = Utc::now();
+        let (status, completed_at) = resolve_completion(
+            TaskStatus::InProgress,
+            None,
+            Some(TaskStatus::Completed),
+            None,
+            now,
+        )
+        .expect("transition");
+
+        assert_eq!(status, TaskStatus::Completed);
+        assert_eq!(completed_at, Some(now));
+    }
+
+    #[test]
+    fn clearing_completed_at_reopens_task() {
+        let now = Utc::now();
+        let (status, completed_at) =
+            resolve_completion(TaskStatus::Completed, Some(now), None, Some(None), now)
+                .expect("transition");
+
+        assert_eq!(status, TaskStatus::Pending);
+        assert_eq!(completed_at, None);
+    }
+
+    #[test]
+    fn completed_task_cannot_resume_without_reopening() {
+        let now = Utc::now();
+        let result = resolve_completion(
+            TaskStatus::Completed,
+            Some(now),
+            Some(TaskStatus::InProgress),
+            None,
+            now,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn clearing_completed_at_lets_a_completed_task_resume() {
+        let now = Utc::now();
+        let (status, completed_at) = resolve_completion(
+            TaskStatus::Completed,
+            Some(now),
+            Some(TaskStatus::InProgress),
+            Some(None),
+            now,
+        )
+        .expect("transition");
+
+        assert_eq!(status, TaskStatus::InProgress);
+        assert_eq!(completed_at, None);
+    }
+}
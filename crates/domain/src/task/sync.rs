@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+use super::entity::Task;
+use super::inputs::{CreateTaskInput, DeleteTaskInput, UpdateTaskInput};
+use crate::error::AppError;
+
+pub const DEFAULT_SYNC_LIMIT: i64 = 500;
+pub const MAX_SYNC_LIMIT: i64 = 1000;
+pub const MAX_SYNC_MUTATIONS: usize = 100;
+
+// Everything that changed for one user after a change token, in change
+// order. `deleted` holds tombstones: tasks that were deleted or that the user
+// can no longer see.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskChanges {
+    pub tasks: Vec<Task>,
+    pub deleted: Vec<String>,
+    pub next_since: i64,
+    pub has_more: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub enum SyncOperation {
+    Create(CreateTaskInput),
+    Update(UpdateTaskInput),
+    Delete(DeleteTaskInput),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SyncMutation {
+    pub mutation_id: String,
+    pub operation: SyncOperation,
+}
+
+// `task` is the task after the mutation, or the current server copy when the
+// mutation lost a version check.
+#[derive(Debug)]
+pub struct SyncMutationResult {
+    pub mutation_id: String,
+    pub task: Option<Task>,
+    pub error: Option<AppError>,
+}
+
+// Tokens are opaque to clients; today they are the decimal change sequence.
+pub fn format_sync_token(seq: i64) -> String {
+    seq.to_string()
+}
+
+pub fn parse_sync_token(token: &str) -> Result<i64, AppError> {
+    token
+        .parse::<i64>()
+        .ok()
+        .filter(|seq| *seq >= 0)
+        .ok_or_else(|| {
+            AppError::validation(
+                "invalid_since",
+                "Since must be a token returned by a previous sync",
+                Some("since".to_string()),
+            )
+        })
+}
@@ -0,0 +1,200 @@
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+
+use super::entity::Task;
+use super::import::ImportTask;
+use super::priority::TaskPriority;
+use super::status::TaskStatus;
+use crate::error::AppError;
+
+// todo.txt has four useful priority letters; anything past D imports as LOW.
+fn priority_letter(priority: TaskPriority) -> char {
+    match priority {
+        TaskPriority::Urgent => 'A',
+        TaskPriority::High => 'B',
+        TaskPriority::Medium => 'C',
+        TaskPriority::Low => 'D',
+    }
+}
+
+fn letter_priority(letter: char) -> TaskPriority {
+    match letter {
+        'A' => TaskPriority::Urgent,
+        'B' => TaskPriority::High,
+        'C' => TaskPriority::Medium,
+        _ => TaskPriority::Low,
+    }
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()
+}
+
+fn midnight_utc(date: NaiveDate) -> DateTime<Utc> {
+    date.and_time(NaiveTime::MIN).and_utc()
+}
+
+// Labels become +tags; whitespace inside a label is replaced with '_' since
+// tags end at the first space. Completed tasks keep their priority as pri:X.
+pub fn format_todo_txt(task: &Task) -> String {
+    let mut parts = Vec::new();
+    let completed = task.status == TaskStatus::Completed;
+    if completed {
+        parts.push("x".to_string());
+        if let Some(completed_at) = task.completed_at {
+            parts.push(completed_at.format("%Y-%m-%d").to_string());
+        }
+    } else if let Some(priority) = task.priority {
+        parts.push(format!("({})", priority_letter(priority)));
+    }
+    parts.push(task.created_at.format("%Y-%m-%d").to_string());
+    parts.push(
+        task.content
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" "),
+    );
+    for label in &task.labels {
+        parts.push(format!(
+            "+{}",
+            label.split_whitespace().collect::<Vec<_>>().join("_")
+        ));
+    }
+    if let Some(due_at) = task.due_at {
+        parts.push(format!("due:{}", due_at.format("%Y-%m-%d")));
+    }
+    if let Some(priority) = task.priority.filter(|_| completed) {
+        parts.push(format!("pri:{}", priority_letter(priority)));
+    }
+
+    parts.join(" ")
+}
+
+pub fn parse_todo_txt(line: usize, text: &str) -> Result<ImportTask, AppError> {
+    let mut words = text.split_whitespace().peekable();
+    let mut task = ImportTask {
+        line,
+        ..Default::default()
+    };
+
+    if words.peek() == Some(&"x") {
+        words.next();
+        task.status = Some(TaskStatus::Completed.as_str().to_string());
+        if let Some(date) = words.peek().and_then(|word| parse_date(word)) {
+            words.next();
+            task.completed_at = Some(midnight_utc(date));
+        }
+    } else if let Some(letter) = words.peek().and_then(|word| {
+        let mut chars = word.chars();
+        match (chars.next(), chars.next(), chars.next(), chars.next()) {
+            (Some('('), Some(letter), Some(')'), None) if letter.is_ascii_uppercase() => {
+                Some(letter)
+            }
+            _ => None,
+        }
+    }) {
+        words.next();
+        task.priority = Some(letter_priority(letter).as_str().to_string());
+    }
+    // Creation date; tasks are always created "now" on import.
+    if words.peek().is_some_and(|word| parse_date(word).is_some()) {
+        words.next();
+    }
+
+    let mut content = Vec::new();
+    for word in words {
+        if let Some(label) = word
+            .strip_prefix('+')
+            .or_else(|| word.strip_prefix('@'))
+            .filter(|label| !label.is_empty())
+        {
+            if !task.labels.iter().any(|existing| existing == label) {
+                task.labels.push(label.to_string());
+            }
+        } else if let Some(due) = word.strip_prefix("due:") {
+            let date = parse_date(due).ok_or_else(|| {
+                AppError::validation(
+                    "invalid_due_at",
+                    "due: must be a YYYY-MM-DD date",
+                    Some("due_at".to_string()),
+                )
+            })?;
+            task.due_at = Some(midnight_utc(date));
+        } else if let Some(letter) = word
+            .strip_prefix("pri:")
+            .and_then(|pri| pri.chars().next())
+            .filter(char::is_ascii_uppercase)
+        {
+            task.priority = Some(letter_priority(letter).as_str().to_string());
+        } else {
+            content.push(word);
+        }
+    }
+    task.content = content.join(" ");
+
+    Ok(task)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::{format_todo_txt, parse_todo_txt};
+    use crate::task::entity::Task;
+    use crate::task::priority::TaskPriority;
+    use crate::task::status::TaskStatus;
+
+    #[test]
+    fn parses_priority_tags_and_due_dates() {
+        let task = parse_todo_txt(3, "(A) 2025-03-01 Pay rent +home @phone due:2025-03-05")
+            .expect("parse");
+
+        assert_eq!(task.line, 3);
+        assert_eq!(task.content, "Pay rent");
+        assert_eq!(task.priority.as_deref(), Some("URGENT"));
+        assert_eq!(task.labels, vec!["home", "phone"]);
+        assert_eq!(
+            task.due_at,
+            Some(Utc.with_ymd_and_hms(2025, 3, 5, 0, 0, 0).unwrap())
+        );
+        assert!(parse_todo_txt(1, "Pay rent due:friday").is_err());
+    }
+
+    #[test]
+    fn round_trips_completed_tasks() {
+        let created_at = Utc.with_ymd_and_hms(2025, 3, 1, 9, 0, 0).unwrap();
+        let task = Task {
+            user_id: "user-1".to_string(),
+            task_id: "task-1".to_string(),
+            content: "File taxes".to_string(),
+            status: TaskStatus::Completed,
+            completed_at: Some(Utc.with_ymd_and_hms(2025, 3, 4, 18, 0, 0).unwrap()),
+            parent_task_id: None,
+            estimate_minutes: None,
+            remaining_minutes: None,
+            story_points: None,
+            due_at: None,
+            labels: vec!["finance".to_string()],
+            priority: Some(TaskPriority::High),
+            recurrence: None,
+            snoozed_until: None,
+            waiting_on: None,
+            archived_at: None,
+            assignee_ids: Vec::new(),
+            group: None,
+            version: 0,
+            rank: "i".to_string(),
+            tracked_seconds: 0,
+            created_at,
+            updated_at: created_at,
+        };
+
+        let line = format_todo_txt(&task);
+        assert_eq!(line, "x 2025-03-04 2025-03-01 File taxes +finance pri:B");
+
+        let parsed = parse_todo_txt(1, &line).expect("parse");
+        assert_eq!(parsed.status.as_deref(), Some("COMPLETED"));
+        assert_eq!(parsed.priority.as_deref(), Some("HIGH"));
+        assert_eq!(parsed.content, "File taxes");
+        assert_eq!(parsed.labels, vec!["finance"]);
+    }
+}
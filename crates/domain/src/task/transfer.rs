@@ -0,0 +1,70 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+// Upper bound on one filtered transfer, so a broad filter cannot hold locks
+// on an unbounded number of tasks.
+pub const MAX_TRANSFER_TASKS: usize = 5000;
+
+// The audit record of one transfer; `task_ids` lists the tasks that moved.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskTransfer {
+    pub transfer_id: String,
+    pub actor_id: String,
+    pub from_user_id: String,
+    pub to_user_id: String,
+    pub task_ids: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+// Users may hand over their own tasks; admins may move anyone's.
+pub fn check_transfer(
+    actor_id: &str,
+    is_admin: bool,
+    from_user_id: &str,
+    to_user_id: &str,
+) -> Result<(), AppError> {
+    if to_user_id.trim().is_empty() {
+        return Err(AppError::validation(
+            "invalid_transfer",
+            "to_user_id is required",
+            Some("to_user_id".to_string()),
+        ));
+    }
+    if from_user_id == to_user_id {
+        return Err(AppError::validation(
+            "invalid_transfer",
+            "Tasks cannot be transferred to the same user",
+            Some("to_user_id".to_string()),
+        ));
+    }
+    if actor_id != from_user_id && !is_admin {
+        return Err(AppError::Forbidden {
+            resource: "Task".to_string(),
+            message: "Only the tasks' owner or an admin can transfer them".to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_transfer;
+    use crate::error::AppError;
+
+    #[test]
+    fn allows_owners_and_admins_only() {
+        assert!(check_transfer("alice", false, "alice", "bob").is_ok());
+        assert!(check_transfer("admin", true, "alice", "bob").is_ok());
+        assert!(matches!(
+            check_transfer("carol", false, "alice", "bob"),
+            Err(AppError::Forbidden { .. })
+        ));
+        assert!(matches!(
+            check_transfer("alice", false, "alice", "alice"),
+            Err(AppError::Validation { .. })
+        ));
+    }
+}
@@ -0,0 +1,148 @@
+use chrono::{DateTime, Datelike, Utc};
+
+use super::recurrence::Recurrence;
+use crate::error::AppError;
+
+pub const DEFAULT_MAX_CONTENT_CHARS: usize = 1000;
+pub const MAX_ESTIMATE_MINUTES: i32 = 525_600;
+pub const MAX_STORY_POINTS: i32 = 1000;
+pub const MAX_ASSIGNEES: usize = 20;
+pub const MAX_LABELS: usize = 20;
+pub const MAX_LABEL_LENGTH: usize = 50;
+pub const MAX_WAITING_ON_LENGTH: usize = 200;
+// Dates clients send as anchors for computed dates; keeping them well inside
+// chrono's range leaves room for the offsets added to them.
+pub const MIN_DATE_YEAR: i32 = 1900;
+pub const MAX_DATE_YEAR: i32 = 9999;
+
+pub fn validate_content(content: &str, max_chars: usize) -> Result<(), AppError> {
+    if content.trim().is_empty() || content.chars().count() > max_chars {
+        return Err(AppError::validation(
+            "invalid_content",
+            format!("Content must be 1-{} characters", max_chars),
+            Some("content".to_string()),
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_estimates(
+    estimate_minutes: Option<i32>,
+    remaining_minutes: Option<i32>,
+    story_points: Option<i32>,
+) -> Result<(), AppError> {
+    for (field, value) in [
+        ("estimate_minutes", estimate_minutes),
+        ("remaining_minutes", remaining_minutes),
+    ] {
+        if value.is_some_and(|minutes| !(0..=MAX_ESTIMATE_MINUTES).contains(&minutes)) {
+            return Err(AppError::validation(
+                "invalid_estimate",
+                format!("{field} must be between 0 and {MAX_ESTIMATE_MINUTES}"),
+                Some(field.to_string()),
+            ));
+        }
+    }
+    if story_points.is_some_and(|points| !(0..=MAX_STORY_POINTS).contains(&points)) {
+        return Err(AppError::validation(
+            "invalid_estimate",
+            format!("story_points must be between 0 and {MAX_STORY_POINTS}"),
+            Some("story_points".to_string()),
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_assignees(assignee_ids: &[String]) -> Result<(), AppError> {
+    let invalid = |message: &str| {
+        AppError::validation(
+            "invalid_assignee",
+            message,
+            Some("assignee_ids".to_string()),
+        )
+    };
+
+    if assignee_ids.len() > MAX_ASSIGNEES {
+        return Err(invalid(&format!(
+            "A task can have at most {MAX_ASSIGNEES} assignees"
+        )));
+    }
+    if assignee_ids.iter().any(|id| id.trim().is_empty()) {
+        return Err(invalid("Assignee ids must not be empty"));
+    }
+    let mut sorted: Vec<&String> = assignee_ids.iter().collect();
+    sorted.sort();
+    if sorted.windows(2).any(|pair| pair[0] == pair[1]) {
+        return Err(invalid("Assignee ids must be unique"));
+    }
+
+    Ok(())
+}
+
+pub fn validate_labels(labels: &[String]) -> Result<(), AppError> {
+    let invalid =
+        |message: &str| AppError::validation("invalid_labels", message, Some("labels".to_string()));
+
+    if labels.len() > MAX_LABELS {
+        return Err(invalid(&format!(
+            "A task can have at most {MAX_LABELS} labels"
+        )));
+    }
+    if labels.iter().any(|label| {
+        label.trim().is_empty()
+            || label.trim() != label
+            || label.contains(',')
+            || label.chars().count() > MAX_LABEL_LENGTH
+    }) {
+        return Err(invalid(&format!(
+            "Labels must be 1-{MAX_LABEL_LENGTH} characters without commas or surrounding whitespace"
+        )));
+    }
+    let mut sorted: Vec<&String> = labels.iter().collect();
+    sorted.sort();
+    if sorted.windows(2).any(|pair| pair[0] == pair[1]) {
+        return Err(invalid("Labels must be unique"));
+    }
+
+    Ok(())
+}
+
+pub fn validate_date_range(at: DateTime<Utc>, field: &str) -> Result<(), AppError> {
+    if !(MIN_DATE_YEAR..=MAX_DATE_YEAR).contains(&at.year()) {
+        return Err(AppError::validation(
+            "invalid_date",
+            format!("{field} must be between the years {MIN_DATE_YEAR} and {MAX_DATE_YEAR}"),
+            Some(field.to_string()),
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_recurrence(recurrence: Option<&str>) -> Result<(), AppError> {
+    if recurrence.is_some_and(|rule| Recurrence::parse(rule).is_none()) {
+        return Err(AppError::validation(
+            "invalid_recurrence",
+            "Recurrence must be a rule like FREQ=WEEKLY;INTERVAL=1",
+            Some("recurrence".to_string()),
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_waiting_on(waiting_on: Option<&str>) -> Result<(), AppError> {
+    if let Some(waiting_on) = waiting_on {
+        if waiting_on.trim().is_empty() || waiting_on.chars().count() > MAX_WAITING_ON_LENGTH {
+            return Err(AppError::validation(
+                "invalid_waiting_on",
+                format!("Waiting on must be 1-{} characters", MAX_WAITING_ON_LENGTH),
+                Some("waiting_on".to_string()),
+            ));
+        }
+    }
+
+    Ok(())
+}
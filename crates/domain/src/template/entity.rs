@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskTemplate {
+    pub template_id: String,
+    pub user_id: String,
+    pub name: String,
+    pub tasks: Vec<TemplateTask>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateTask {
+    pub content: String,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub due_offset_days: Option<i32>,
+    #[serde(default)]
+    pub subtasks: Vec<TemplateTask>,
+}
@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use super::entity::TemplateTask;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateTemplateInput {
+    pub user_id: String,
+    pub name: String,
+    pub tasks: Vec<TemplateTask>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateTemplateInput {
+    pub user_id: String,
+    pub template_id: String,
+    pub name: String,
+    pub tasks: Vec<TemplateTask>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeleteTemplateInput {
+    pub user_id: String,
+    pub template_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetTemplateInput {
+    pub user_id: String,
+    pub template_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListTemplatesInput {
+    pub user_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct InstantiateTemplateInput {
+    pub user_id: String,
+    pub template_id: String,
+    pub variables: HashMap<String, String>,
+    pub start_at: DateTime<Utc>,
+    pub parent_task_id: Option<String>,
+    pub max_content_chars: usize,
+}
@@ -0,0 +1,4 @@
+pub mod entity;
+pub mod inputs;
+pub mod render;
+pub mod validation;
@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+
+use super::entity::TemplateTask;
+use crate::error::AppError;
+use crate::task::inputs::NewTaskNode;
+
+enum Segment<'a> {
+    Text(&'a str),
+    Variable(&'a str),
+}
+
+fn parse(text: &str) -> Result<Vec<Segment<'_>>, AppError> {
+    let invalid = || {
+        AppError::validation(
+            "invalid_placeholder",
+            format!("Invalid placeholder in \"{text}\""),
+            Some("tasks".to_string()),
+        )
+    };
+
+    let mut segments = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        segments.push(Segment::Text(&rest[..start]));
+        let end = rest[start..].find("}}").ok_or_else(invalid)? + start;
+        let name = rest[start + 2..end].trim();
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(invalid());
+        }
+        segments.push(Segment::Variable(name));
+        rest = &rest[end + 2..];
+    }
+    segments.push(Segment::Text(rest));
+
+    Ok(segments)
+}
+
+pub fn placeholder_names(text: &str) -> Result<Vec<&str>, AppError> {
+    Ok(parse(text)?
+        .into_iter()
+        .filter_map(|segment| match segment {
+            Segment::Variable(name) => Some(name),
+            Segment::Text(_) => None,
+        })
+        .collect())
+}
+
+pub fn render_placeholders(
+    text: &str,
+    variables: &HashMap<String, String>,
+) -> Result<String, AppError> {
+    let mut rendered = String::with_capacity(text.len());
+    for segment in parse(text)? {
+        match segment {
+            Segment::Text(text) => rendered.push_str(text),
+            Segment::Variable(name) => {
+                let value = variables.get(name).ok_or_else(|| {
+                    AppError::validation(
+                        "missing_variable",
+                        format!("No value provided for variable {name}"),
+                        Some("variables".to_string()),
+                    )
+                })?;
+                rendered.push_str(value);
+            }
+        }
+    }
+
+    Ok(rendered)
+}
+
+pub fn render_tasks(
+    tasks: &[TemplateTask],
+    variables: &HashMap<String, String>,
+    start_at: DateTime<Utc>,
+) -> Result<Vec<NewTaskNode>, AppError> {
+    tasks
+        .iter()
+        .map(|task| {
+            Ok(NewTaskNode {
+                content: render_placeholders(&task.content, variables)?,
+                labels: task
+                    .labels
+                    .iter()
+                    .map(|label| render_placeholders(label, variables))
+                    .collect::<Result<_, _>>()?,
+                due_at: task
+                    .due_offset_days
+                    .map(|days| {
+                        start_at
+                            .checked_add_signed(Duration::days(i64::from(days)))
+                            .ok_or_else(|| {
+                                AppError::validation(
+                                    "invalid_start_at",
+                                    "Due dates would fall outside the supported range",
+                                    Some("start_at".to_string()),
+                                )
+                            })
+                    })
+                    .transpose()?,
+                subtasks: render_tasks(&task.subtasks, variables, start_at)?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use chrono::{Duration, Utc};
+
+    use super::{render_placeholders, render_tasks};
+    use crate::template::entity::TemplateTask;
+
+    fn variables() -> HashMap<String, String> {
+        HashMap::from([("name".to_string(), "Ada".to_string())])
+    }
+
+    #[test]
+    fn substitutes_variables() {
+        let rendered =
+            render_placeholders("Welcome {{ name }} ({{name}})", &variables()).expect("render");
+
+        assert_eq!(rendered, "Welcome Ada (Ada)");
+    }
+
+    #[test]
+    fn rejects_missing_and_malformed_placeholders() {
+        assert!(render_placeholders("Hi {{ team }}", &variables()).is_err());
+        assert!(render_placeholders("Hi {{ name", &variables()).is_err());
+        assert!(render_placeholders("Hi {{ na-me }}", &variables()).is_err());
+    }
+
+    #[test]
+    fn renders_tree_with_due_offsets() {
+        let start_at = Utc::now();
+        let tasks = vec![TemplateTask {
+            content: "Onboard {{name}}".to_string(),
+            labels: vec!["hr".to_string()],
+            due_offset_days: Some(7),
+            subtasks: vec![TemplateTask {
+                content: "Laptop for {{name}}".to_string(),
+                labels: Vec::new(),
+                due_offset_days: None,
+                subtasks: Vec::new(),
+            }],
+        }];
+
+        let nodes = render_tasks(&tasks, &variables(), start_at).expect("render");
+
+        assert_eq!(nodes[0].content, "Onboard Ada");
+        assert_eq!(nodes[0].due_at, Some(start_at + Duration::days(7)));
+        assert_eq!(nodes[0].subtasks[0].content, "Laptop for Ada");
+        assert_eq!(nodes[0].subtasks[0].due_at, None);
+    }
+
+    #[test]
+    fn rejects_offsets_past_the_end_of_time() {
+        let tasks = vec![TemplateTask {
+            content: "Renew".to_string(),
+            labels: Vec::new(),
+            due_offset_days: Some(400),
+            subtasks: Vec::new(),
+        }];
+        let start_at = "+262142-12-01T00:00:00Z".parse().expect("date");
+
+        assert!(render_tasks(&tasks, &variables(), start_at).is_err());
+    }
+}
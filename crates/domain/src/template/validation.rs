@@ -0,0 +1,60 @@
+use crate::error::AppError;
+
+use super::entity::TemplateTask;
+use super::render::placeholder_names;
+
+pub const MAX_TEMPLATE_NAME_LENGTH: usize = 100;
+pub const MAX_TEMPLATE_TASKS: usize = 200;
+pub const MAX_TEMPLATE_DEPTH: usize = 5;
+pub const MAX_DUE_OFFSET_DAYS: i32 = 3650;
+
+pub fn validate_template(name: &str, tasks: &[TemplateTask]) -> Result<(), AppError> {
+    if name.trim().is_empty() || name.chars().count() > MAX_TEMPLATE_NAME_LENGTH {
+        return Err(AppError::validation(
+            "invalid_name",
+            "Template name must be 1-100 characters",
+            Some("name".to_string()),
+        ));
+    }
+    if tasks.is_empty() {
+        return Err(AppError::validation(
+            "invalid_tasks",
+            "A template must contain at least one task",
+            Some("tasks".to_string()),
+        ));
+    }
+
+    let mut count = 0;
+    validate_tasks(tasks, 1, &mut count)
+}
+
+fn validate_tasks(tasks: &[TemplateTask], depth: usize, count: &mut usize) -> Result<(), AppError> {
+    let invalid =
+        |message: &str| AppError::validation("invalid_tasks", message, Some("tasks".to_string()));
+
+    if depth > MAX_TEMPLATE_DEPTH {
+        return Err(invalid("Templates can be nested at most 5 levels deep"));
+    }
+    for task in tasks {
+        *count += 1;
+        if *count > MAX_TEMPLATE_TASKS {
+            return Err(invalid("A template can contain at most 200 tasks"));
+        }
+        if task.content.trim().is_empty() {
+            return Err(invalid("Template task content must not be empty"));
+        }
+        if task
+            .due_offset_days
+            .is_some_and(|days| days.abs() > MAX_DUE_OFFSET_DAYS)
+        {
+            return Err(invalid("Due offsets must be within 3650 days"));
+        }
+        placeholder_names(&task.content)?;
+        for label in &task.labels {
+            placeholder_names(label)?;
+        }
+        validate_tasks(&task.subtasks, depth + 1, count)?;
+    }
+
+    Ok(())
+}
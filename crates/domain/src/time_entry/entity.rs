@@ -0,0 +1,20 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeEntry {
+    pub entry_id: String,
+    pub task_id: String,
+    pub user_id: String,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TimeEntry {
+    pub fn duration_seconds(&self) -> Option<i64> {
+        self.ended_at
+            .map(|ended_at| (ended_at - self.started_at).num_seconds())
+    }
+}
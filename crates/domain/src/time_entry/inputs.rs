@@ -0,0 +1,50 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StartTimerInput {
+    pub user_id: String,
+    pub task_id: String,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StopTimerInput {
+    pub user_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateTimeEntryInput {
+    pub user_id: String,
+    pub task_id: String,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeleteTimeEntryInput {
+    pub user_id: String,
+    pub entry_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListTimeEntriesInput {
+    pub user_id: String,
+    pub task_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TimeReportInput {
+    pub user_id: String,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TimeReportQuery {
+    pub user_id: String,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub timezone: String,
+}
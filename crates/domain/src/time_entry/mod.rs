@@ -0,0 +1,4 @@
+pub mod entity;
+pub mod inputs;
+pub mod report;
+pub mod validation;
@@ -0,0 +1,71 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeReportRow {
+    pub day: NaiveDate,
+    pub task_id: String,
+    pub content: String,
+    pub seconds: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayTotal {
+    pub day: NaiveDate,
+    pub seconds: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskTotal {
+    pub task_id: String,
+    pub content: String,
+    pub seconds: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeReport {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub total_seconds: i64,
+    pub by_day: Vec<DayTotal>,
+    pub by_task: Vec<TaskTotal>,
+    pub rows: Vec<TimeReportRow>,
+}
+
+impl TimeReport {
+    // Rows arrive ordered by day; task totals are ordered by time spent.
+    pub fn from_rows(from: NaiveDate, to: NaiveDate, rows: Vec<TimeReportRow>) -> Self {
+        let mut by_day: Vec<DayTotal> = Vec::new();
+        let mut by_task: Vec<TaskTotal> = Vec::new();
+        for row in &rows {
+            match by_day.last_mut() {
+                Some(total) if total.day == row.day => total.seconds += row.seconds,
+                _ => by_day.push(DayTotal {
+                    day: row.day,
+                    seconds: row.seconds,
+                }),
+            }
+            match by_task
+                .iter_mut()
+                .find(|total| total.task_id == row.task_id)
+            {
+                Some(total) => total.seconds += row.seconds,
+                None => by_task.push(TaskTotal {
+                    task_id: row.task_id.clone(),
+                    content: row.content.clone(),
+                    seconds: row.seconds,
+                }),
+            }
+        }
+        by_task.sort_by_key(|total| std::cmp::Reverse(total.seconds));
+
+        Self {
+            from,
+            to,
+            total_seconds: by_day.iter().map(|total| total.seconds).sum(),
+            by_day,
+            by_task,
+            rows,
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+
+use crate::error::AppError;
+
+pub const MAX_NOTE_LENGTH: usize = 500;
+pub const MAX_ENTRY_HOURS: i64 = 24;
+pub const MAX_REPORT_DAYS: i64 = 366;
+
+pub fn validate_note(note: Option<&str>) -> Result<(), AppError> {
+    if note.is_some_and(|note| note.chars().count() > MAX_NOTE_LENGTH) {
+        return Err(AppError::validation(
+            "invalid_note",
+            "Note must be at most 500 characters",
+            Some("note".to_string()),
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_entry_range(
+    started_at: DateTime<Utc>,
+    ended_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Result<(), AppError> {
+    if ended_at <= started_at {
+        return Err(AppError::validation(
+            "invalid_time_range",
+            "ended_at must be after started_at",
+            Some("ended_at".to_string()),
+        ));
+    }
+    if ended_at - started_at > Duration::hours(MAX_ENTRY_HOURS) {
+        return Err(AppError::validation(
+            "invalid_time_range",
+            "A time entry cannot exceed 24 hours",
+            Some("ended_at".to_string()),
+        ));
+    }
+    if ended_at > now {
+        return Err(AppError::validation(
+            "invalid_time_range",
+            "Time entries cannot end in the future",
+            Some("ended_at".to_string()),
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_report_range(from: NaiveDate, to: NaiveDate) -> Result<(), AppError> {
+    if to < from || (to - from).num_days() >= MAX_REPORT_DAYS {
+        return Err(AppError::validation(
+            "invalid_date_range",
+            "Report range must be 1-366 days with from on or before to",
+            None,
+        ));
+    }
+
+    Ok(())
+}
@@ -1,6 +1,9 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use super::timezone::{parse_timezone, DEFAULT_TIMEZONE};
+use crate::error::AppError;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct User {
     pub user_id: String,
@@ -9,6 +12,55 @@ pub struct User {
     pub email: String,
     pub name: Option<String>,
     pub picture: Option<String>,
+    pub timezone: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserProfile {
+    pub username: String,
+    pub email: String,
+    pub name: Option<String>,
+    pub picture: Option<String>,
+    #[serde(default = "default_timezone")]
+    pub timezone: String,
+}
+
+fn default_timezone() -> String {
+    DEFAULT_TIMEZONE.to_string()
+}
+
+impl UserProfile {
+    pub fn validate(&self) -> Result<(), AppError> {
+        if self.username.trim().is_empty() {
+            return Err(AppError::validation(
+                "invalid_username",
+                "Username is required",
+                Some("username".to_string()),
+            ));
+        }
+        if self.email.trim().is_empty() {
+            return Err(AppError::validation(
+                "invalid_email",
+                "Email is required",
+                Some("email".to_string()),
+            ));
+        }
+        parse_timezone(&self.timezone)?;
+
+        Ok(())
+    }
+}
+
+impl From<&User> for UserProfile {
+    fn from(user: &User) -> Self {
+        Self {
+            username: user.username.clone(),
+            email: user.email.clone(),
+            name: user.name.clone(),
+            picture: user.picture.clone(),
+            timezone: user.timezone.clone(),
+        }
+    }
+}
@@ -1,4 +1,8 @@
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
+use serde_json::Value;
+
+use crate::patch::PatchFormat;
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct CreateUserInput {
@@ -8,6 +12,7 @@ pub struct CreateUserInput {
     pub email: String,
     pub name: Option<String>,
     pub picture: Option<String>,
+    pub timezone: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -17,11 +22,14 @@ pub struct UpdateUserInput {
     pub email: Option<String>,
     pub name: Option<Option<String>>,
     pub picture: Option<Option<String>>,
+    pub timezone: Option<String>,
+    pub expected_updated_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct DeleteUserInput {
     pub user_id: String,
+    pub expected_updated_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -45,3 +53,11 @@ pub struct ListUsersInput {
     pub page: Option<i64>,
     pub limit: Option<i64>,
 }
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PatchUserInput {
+    pub user_id: String,
+    pub format: PatchFormat,
+    pub patch: Value,
+    pub expected_updated_at: Option<DateTime<Utc>>,
+}
@@ -1,2 +1,3 @@
 pub mod entity;
 pub mod inputs;
+pub mod timezone;
@@ -0,0 +1,31 @@
+use chrono::{DateTime, Duration, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use crate::error::AppError;
+
+pub const DEFAULT_TIMEZONE: &str = "UTC";
+
+pub fn parse_timezone(timezone: &str) -> Result<Tz, AppError> {
+    timezone.parse::<Tz>().map_err(|_| {
+        AppError::validation(
+            "invalid_timezone",
+            "Timezone must be an IANA name such as Europe/Paris",
+            Some("timezone".to_string()),
+        )
+    })
+}
+
+// Local times that fall into a DST gap are pushed forward an hour; ambiguous
+// times resolve to the earlier instant.
+pub fn local_to_utc(local: NaiveDateTime, timezone: Tz) -> DateTime<Utc> {
+    timezone
+        .from_local_datetime(&local)
+        .earliest()
+        .or_else(|| {
+            timezone
+                .from_local_datetime(&(local + Duration::hours(1)))
+                .earliest()
+        })
+        .map(|at| at.with_timezone(&Utc))
+        .unwrap_or_else(|| Utc.from_utc_datetime(&local))
+}
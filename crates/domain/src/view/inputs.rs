@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::task::filter::TaskFilter;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateViewInput {
+    pub user_id: String,
+    pub name: String,
+    pub filter: TaskFilter,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateViewInput {
+    pub user_id: String,
+    pub view_id: String,
+    pub name: String,
+    pub filter: TaskFilter,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeleteViewInput {
+    pub user_id: String,
+    pub view_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetViewInput {
+    pub user_id: String,
+    pub view_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListViewsInput {
+    pub user_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RunViewInput {
+    pub user_id: String,
+    pub view_id: String,
+    pub page: i64,
+    pub limit: i64,
+    pub now: DateTime<Utc>,
+}
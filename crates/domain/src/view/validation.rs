@@ -0,0 +1,16 @@
+use crate::error::AppError;
+use crate::task::filter::TaskFilter;
+
+pub const MAX_VIEW_NAME_LENGTH: usize = 100;
+
+pub fn validate_view(name: &str, filter: &TaskFilter) -> Result<(), AppError> {
+    if name.trim().is_empty() || name.chars().count() > MAX_VIEW_NAME_LENGTH {
+        return Err(AppError::validation(
+            "invalid_name",
+            "View name must be 1-100 characters",
+            Some("name".to_string()),
+        ));
+    }
+
+    filter.validate()
+}
@@ -0,0 +1,38 @@
+use domain::board::entity::Board;
+use domain::board::inputs::CreateBoardInput;
+use domain::error::AppError;
+use uuid::Uuid;
+
+use super::repository::{fetch_board, save_columns, BoardRepositoryImpl};
+
+pub async fn create_board(
+    repo: &BoardRepositoryImpl,
+    input: CreateBoardInput,
+) -> Result<Board, AppError> {
+    let mut tx = repo
+        .pool
+        .begin()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    let board_id = sqlx::query_scalar::<_, Uuid>(
+        "INSERT INTO boards (user_id, name) VALUES ($1, $2) RETURNING board_id",
+    )
+    .bind(&input.user_id)
+    .bind(input.name.trim())
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    save_columns(&mut tx, board_id, &input.columns).await?;
+
+    let board = fetch_board(&mut tx, &input.user_id, board_id)
+        .await?
+        .ok_or_else(|| AppError::database("Created board could not be read back"))?;
+
+    tx.commit()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(board)
+}
@@ -0,0 +1,23 @@
+use domain::board::inputs::DeleteBoardInput;
+use domain::error::AppError;
+
+use super::repository::{parse_board_id, BoardRepositoryImpl};
+
+pub async fn delete_board(
+    repo: &BoardRepositoryImpl,
+    input: DeleteBoardInput,
+) -> Result<(), AppError> {
+    let board_id = parse_board_id(&input.board_id)?;
+
+    let deleted = sqlx::query("DELETE FROM boards WHERE user_id = $1 AND board_id = $2")
+        .bind(&input.user_id)
+        .bind(board_id)
+        .execute(&repo.pool)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+    if deleted.rows_affected() == 0 {
+        return Err(AppError::not_found("Board", "Board not found"));
+    }
+
+    Ok(())
+}
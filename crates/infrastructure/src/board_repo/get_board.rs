@@ -0,0 +1,20 @@
+use domain::board::entity::Board;
+use domain::board::inputs::GetBoardInput;
+use domain::error::AppError;
+
+use super::repository::{fetch_board, parse_board_id, BoardRepositoryImpl};
+
+pub async fn get_board(
+    repo: &BoardRepositoryImpl,
+    input: GetBoardInput,
+) -> Result<Option<Board>, AppError> {
+    let board_id = parse_board_id(&input.board_id)?;
+
+    let mut conn = repo
+        .pool
+        .acquire()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    fetch_board(&mut conn, &input.user_id, board_id).await
+}
@@ -0,0 +1,38 @@
+use domain::board::inputs::ListBoardTasksInput;
+use domain::error::AppError;
+use domain::task::entity::Task;
+
+use super::repository::BoardRepositoryImpl;
+use crate::task_repo::repository::{TaskRow, TASK_COLUMNS};
+
+pub async fn list_board_tasks(
+    repo: &BoardRepositoryImpl,
+    input: ListBoardTasksInput,
+) -> Result<Vec<Task>, AppError> {
+    let statuses: Vec<&str> = input
+        .statuses
+        .iter()
+        .map(|status| status.as_str())
+        .collect();
+
+    let sql = format!(
+        r#"
+            SELECT {TASK_COLUMNS}
+            FROM tasks t
+            JOIN tasks_users tu ON t.task_id = tu.task_id
+            WHERE tu.user_id = $1 AND t.status = ANY($2) AND t.archived_at IS NULL
+            ORDER BY tu.rank ASC
+            "#
+    );
+    let rows = sqlx::query_as::<_, TaskRow>(&sql)
+        .bind(&input.user_id)
+        .bind(&statuses)
+        .fetch_all(&repo.pool)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| row.into_task(input.user_id.clone()))
+        .collect())
+}
@@ -0,0 +1,26 @@
+use domain::board::entity::Board;
+use domain::board::inputs::ListBoardsInput;
+use domain::error::AppError;
+
+use super::repository::{attach_columns, BoardRepositoryImpl, BoardRow, BOARD_COLUMNS};
+
+pub async fn list_boards(
+    repo: &BoardRepositoryImpl,
+    input: ListBoardsInput,
+) -> Result<Vec<Board>, AppError> {
+    let mut conn = repo
+        .pool
+        .acquire()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    let sql =
+        format!("SELECT {BOARD_COLUMNS} FROM boards b WHERE b.user_id = $1 ORDER BY b.created_at");
+    let rows = sqlx::query_as::<_, BoardRow>(&sql)
+        .bind(&input.user_id)
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    attach_columns(&mut conn, rows).await
+}
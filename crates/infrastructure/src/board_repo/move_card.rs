@@ -0,0 +1,96 @@
+use domain::board::inputs::MoveCardInput;
+use domain::error::AppError;
+use domain::task::entity::Task;
+use domain::task::inputs::{MoveTaskInput, UpdateTaskInput};
+
+use super::repository::{fetch_board, parse_board_id, BoardRepositoryImpl};
+use crate::task_repo::move_task::move_task_in;
+use crate::task_repo::ranking::lock_ordering;
+use crate::task_repo::repository::{fetch_task, parse_task_id};
+use crate::task_repo::update_task::update_task_in;
+
+pub async fn move_card(repo: &BoardRepositoryImpl, input: MoveCardInput) -> Result<Task, AppError> {
+    let board_id = parse_board_id(&input.board_id)?;
+    let task_id = parse_task_id(&input.task_id)?;
+
+    let mut tx = repo
+        .pool
+        .begin()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    // The ordering lock also serializes WIP counts for this user. Archived
+    // tasks are left off the board, so they do not count towards the limit.
+    lock_ordering(&mut tx, &input.user_id).await?;
+
+    let board = fetch_board(&mut tx, &input.user_id, board_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("Board", "Board not found"))?;
+    let column = board
+        .columns
+        .into_iter()
+        .find(|column| column.column_id == input.column_id)
+        .ok_or_else(|| AppError::not_found("BoardColumn", "Board column not found"))?;
+
+    let mut task = fetch_task(&mut tx, &input.user_id, task_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("Task", "Task not found"))?;
+    if task.version != input.version {
+        return Err(AppError::version_conflict("Task", i64::from(task.version)));
+    }
+
+    if task.status != column.status {
+        if let Some(wip_limit) = column.wip_limit {
+            let count = sqlx::query_scalar::<_, i64>(
+                r#"
+                    SELECT COUNT(*)
+                    FROM tasks t
+                    JOIN tasks_users tu ON t.task_id = tu.task_id
+                    WHERE tu.user_id = $1 AND t.status = $2 AND t.archived_at IS NULL
+                    "#,
+            )
+            .bind(&input.user_id)
+            .bind(column.status.as_str())
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|error| AppError::database(error.to_string()))?;
+            if count >= i64::from(wip_limit) {
+                return Err(AppError::conflict(
+                    "BoardColumn",
+                    format!("Column {} is at its WIP limit of {wip_limit}", column.name),
+                ));
+            }
+        }
+
+        task = update_task_in(
+            &mut tx,
+            UpdateTaskInput {
+                user_id: input.user_id.clone(),
+                task_id: input.task_id.clone(),
+                status: Some(column.status),
+                version: input.version,
+                ..Default::default()
+            },
+        )
+        .await?;
+    }
+
+    if input.before.is_some() || input.after.is_some() {
+        task = move_task_in(
+            &mut tx,
+            MoveTaskInput {
+                user_id: input.user_id,
+                task_id: input.task_id,
+                before: input.before,
+                after: input.after,
+            },
+        )
+        .await?;
+    }
+
+    tx.commit()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(task)
+}
@@ -0,0 +1,205 @@
+use application::board_service::repository::BoardRepository;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::board::entity::{Board, BoardColumn};
+use domain::board::inputs::{
+    BoardColumnInput, CreateBoardInput, DeleteBoardInput, GetBoardInput, ListBoardTasksInput,
+    ListBoardsInput, MoveCardInput, UpdateBoardInput,
+};
+use domain::error::AppError;
+use domain::task::entity::Task;
+use domain::task::status::TaskStatus;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use super::{
+    create_board, delete_board, get_board, list_board_tasks, list_boards, move_card, update_board,
+};
+
+pub(crate) const BOARD_COLUMNS: &str = "b.board_id, b.user_id, b.name, b.created_at, b.updated_at";
+
+#[derive(Clone)]
+pub struct BoardRepositoryImpl {
+    pub(crate) pool: PgPool,
+}
+
+impl BoardRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+pub(crate) struct BoardRow {
+    pub board_id: Uuid,
+    pub user_id: String,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl BoardRow {
+    pub(crate) fn into_board(self, columns: Vec<BoardColumn>) -> Board {
+        Board {
+            board_id: self.board_id.to_string(),
+            user_id: self.user_id,
+            name: self.name,
+            columns,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+pub(crate) struct BoardColumnRow {
+    pub board_id: Uuid,
+    pub column_id: Uuid,
+    pub name: String,
+    pub status: String,
+    pub position: i32,
+    pub wip_limit: Option<i32>,
+}
+
+impl BoardColumnRow {
+    fn into_column(self) -> BoardColumn {
+        BoardColumn {
+            column_id: self.column_id.to_string(),
+            name: self.name,
+            status: TaskStatus::parse(&self.status).unwrap_or_default(),
+            position: self.position,
+            wip_limit: self.wip_limit,
+        }
+    }
+}
+
+pub(crate) fn parse_board_id(board_id: &str) -> Result<Uuid, AppError> {
+    Uuid::parse_str(board_id)
+        .map_err(|_| AppError::validation("invalid_board_id", "Invalid board id", None))
+}
+
+pub(crate) async fn attach_columns(
+    conn: &mut PgConnection,
+    rows: Vec<BoardRow>,
+) -> Result<Vec<Board>, AppError> {
+    let board_ids: Vec<Uuid> = rows.iter().map(|row| row.board_id).collect();
+    let columns = sqlx::query_as::<_, BoardColumnRow>(
+        r#"
+            SELECT board_id, column_id, name, status, position, wip_limit
+            FROM board_columns
+            WHERE board_id = ANY($1)
+            ORDER BY position
+            "#,
+    )
+    .bind(&board_ids)
+    .fetch_all(conn)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    let mut boards: Vec<Board> = rows
+        .into_iter()
+        .map(|row| row.into_board(Vec::new()))
+        .collect();
+    for column in columns {
+        let board_id = column.board_id.to_string();
+        if let Some(board) = boards.iter_mut().find(|board| board.board_id == board_id) {
+            board.columns.push(column.into_column());
+        }
+    }
+
+    Ok(boards)
+}
+
+pub(crate) async fn fetch_board(
+    conn: &mut PgConnection,
+    user_id: &str,
+    board_id: Uuid,
+) -> Result<Option<Board>, AppError> {
+    let sql =
+        format!("SELECT {BOARD_COLUMNS} FROM boards b WHERE b.user_id = $1 AND b.board_id = $2");
+    let row = sqlx::query_as::<_, BoardRow>(&sql)
+        .bind(user_id)
+        .bind(board_id)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    match row {
+        Some(row) => Ok(attach_columns(conn, vec![row]).await?.pop()),
+        None => Ok(None),
+    }
+}
+
+// Columns are matched by status so their ids survive renames and reordering.
+pub(crate) async fn save_columns(
+    conn: &mut PgConnection,
+    board_id: Uuid,
+    columns: &[BoardColumnInput],
+) -> Result<(), AppError> {
+    let statuses: Vec<&str> = columns
+        .iter()
+        .map(|column| column.status.as_str())
+        .collect();
+    let names: Vec<&str> = columns.iter().map(|column| column.name.trim()).collect();
+    let positions: Vec<i32> = (0..columns.len() as i32).collect();
+    let wip_limits: Vec<Option<i32>> = columns.iter().map(|column| column.wip_limit).collect();
+
+    sqlx::query("DELETE FROM board_columns WHERE board_id = $1 AND status <> ALL($2)")
+        .bind(board_id)
+        .bind(&statuses)
+        .execute(&mut *conn)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    sqlx::query(
+        r#"
+            INSERT INTO board_columns (board_id, name, status, position, wip_limit)
+            SELECT $1, name, status, position, wip_limit
+            FROM UNNEST($2::text[], $3::text[], $4::int[], $5::int[])
+                AS input(name, status, position, wip_limit)
+            ON CONFLICT (board_id, status) DO UPDATE
+            SET name = EXCLUDED.name, position = EXCLUDED.position, wip_limit = EXCLUDED.wip_limit
+            "#,
+    )
+    .bind(board_id)
+    .bind(&names)
+    .bind(&statuses)
+    .bind(&positions)
+    .bind(&wip_limits)
+    .execute(conn)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl BoardRepository for BoardRepositoryImpl {
+    async fn create_board(&self, input: CreateBoardInput) -> Result<Board, AppError> {
+        create_board::create_board(self, input).await
+    }
+
+    async fn update_board(&self, input: UpdateBoardInput) -> Result<Board, AppError> {
+        update_board::update_board(self, input).await
+    }
+
+    async fn delete_board(&self, input: DeleteBoardInput) -> Result<(), AppError> {
+        delete_board::delete_board(self, input).await
+    }
+
+    async fn get_board(&self, input: GetBoardInput) -> Result<Option<Board>, AppError> {
+        get_board::get_board(self, input).await
+    }
+
+    async fn list_boards(&self, input: ListBoardsInput) -> Result<Vec<Board>, AppError> {
+        list_boards::list_boards(self, input).await
+    }
+
+    async fn list_board_tasks(&self, input: ListBoardTasksInput) -> Result<Vec<Task>, AppError> {
+        list_board_tasks::list_board_tasks(self, input).await
+    }
+
+    async fn move_card(&self, input: MoveCardInput) -> Result<Task, AppError> {
+        move_card::move_card(self, input).await
+    }
+}
@@ -0,0 +1,43 @@
+use domain::board::entity::Board;
+use domain::board::inputs::UpdateBoardInput;
+use domain::error::AppError;
+
+use super::repository::{fetch_board, parse_board_id, save_columns, BoardRepositoryImpl};
+
+pub async fn update_board(
+    repo: &BoardRepositoryImpl,
+    input: UpdateBoardInput,
+) -> Result<Board, AppError> {
+    let board_id = parse_board_id(&input.board_id)?;
+
+    let mut tx = repo
+        .pool
+        .begin()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    let updated = sqlx::query(
+        "UPDATE boards SET name = $3, updated_at = NOW() WHERE user_id = $1 AND board_id = $2",
+    )
+    .bind(&input.user_id)
+    .bind(board_id)
+    .bind(input.name.trim())
+    .execute(&mut *tx)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+    if updated.rows_affected() == 0 {
+        return Err(AppError::not_found("Board", "Board not found"));
+    }
+
+    save_columns(&mut tx, board_id, &input.columns).await?;
+
+    let board = fetch_board(&mut tx, &input.user_id, board_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("Board", "Board not found"))?;
+
+    tx.commit()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(board)
+}
@@ -0,0 +1,16 @@
+use domain::error::AppError;
+use domain::feed::inputs::GetFeedOwnerInput;
+
+use super::repository::{FeedRepositoryImpl, TOKEN_HASH};
+
+pub async fn get_feed_owner(
+    repo: &FeedRepositoryImpl,
+    input: GetFeedOwnerInput,
+) -> Result<Option<String>, AppError> {
+    let sql = format!("SELECT user_id FROM feed_tokens WHERE token_hash = {TOKEN_HASH}");
+    sqlx::query_scalar::<_, String>(&sql)
+        .bind(&input.token)
+        .fetch_optional(&repo.pool)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))
+}
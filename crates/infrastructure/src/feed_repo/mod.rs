@@ -0,0 +1,4 @@
+pub mod get_feed_owner;
+pub mod repository;
+pub mod revoke_feed_token;
+pub mod rotate_feed_token;
@@ -0,0 +1,38 @@
+use application::feed_service::repository::FeedRepository;
+use async_trait::async_trait;
+use domain::error::AppError;
+use domain::feed::entity::FeedToken;
+use domain::feed::inputs::{GetFeedOwnerInput, RevokeFeedTokenInput, RotateFeedTokenInput};
+use sqlx::PgPool;
+
+use super::{get_feed_owner, revoke_feed_token, rotate_feed_token};
+
+// Tokens are compared by SHA-256 digest so a database leak does not expose
+// working feed or share URLs.
+pub(crate) const TOKEN_HASH: &str = "encode(sha256(convert_to($1, 'UTF8')), 'hex')";
+
+#[derive(Clone)]
+pub struct FeedRepositoryImpl {
+    pub(crate) pool: PgPool,
+}
+
+impl FeedRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl FeedRepository for FeedRepositoryImpl {
+    async fn rotate_feed_token(&self, input: RotateFeedTokenInput) -> Result<FeedToken, AppError> {
+        rotate_feed_token::rotate_feed_token(self, input).await
+    }
+
+    async fn revoke_feed_token(&self, input: RevokeFeedTokenInput) -> Result<(), AppError> {
+        revoke_feed_token::revoke_feed_token(self, input).await
+    }
+
+    async fn get_feed_owner(&self, input: GetFeedOwnerInput) -> Result<Option<String>, AppError> {
+        get_feed_owner::get_feed_owner(self, input).await
+    }
+}
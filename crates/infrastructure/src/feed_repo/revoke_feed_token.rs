@@ -0,0 +1,20 @@
+use domain::error::AppError;
+use domain::feed::inputs::RevokeFeedTokenInput;
+
+use super::repository::FeedRepositoryImpl;
+
+pub async fn revoke_feed_token(
+    repo: &FeedRepositoryImpl,
+    input: RevokeFeedTokenInput,
+) -> Result<(), AppError> {
+    let deleted = sqlx::query("DELETE FROM feed_tokens WHERE user_id = $1")
+        .bind(&input.user_id)
+        .execute(&repo.pool)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+    if deleted.rows_affected() == 0 {
+        return Err(AppError::not_found("Feed", "Feed not found"));
+    }
+
+    Ok(())
+}
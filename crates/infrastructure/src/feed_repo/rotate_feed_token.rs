@@ -0,0 +1,41 @@
+use chrono::{DateTime, Utc};
+use domain::error::AppError;
+use domain::feed::entity::FeedToken;
+use domain::feed::inputs::RotateFeedTokenInput;
+
+use super::repository::{FeedRepositoryImpl, TOKEN_HASH};
+
+// Issuing a new token replaces the old one, so rotating also revokes.
+pub async fn rotate_feed_token(
+    repo: &FeedRepositoryImpl,
+    input: RotateFeedTokenInput,
+) -> Result<FeedToken, AppError> {
+    let token = sqlx::query_scalar::<_, String>(
+        "SELECT replace(gen_random_uuid()::text || gen_random_uuid()::text, '-', '')",
+    )
+    .fetch_one(&repo.pool)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    let sql = format!(
+        r#"
+            INSERT INTO feed_tokens (user_id, token_hash, created_at)
+            VALUES ($2, {TOKEN_HASH}, NOW())
+            ON CONFLICT (user_id)
+            DO UPDATE SET token_hash = EXCLUDED.token_hash, created_at = EXCLUDED.created_at
+            RETURNING created_at
+            "#
+    );
+    let created_at = sqlx::query_scalar::<_, DateTime<Utc>>(&sql)
+        .bind(&token)
+        .bind(&input.user_id)
+        .fetch_one(&repo.pool)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(FeedToken {
+        user_id: input.user_id,
+        token,
+        created_at,
+    })
+}
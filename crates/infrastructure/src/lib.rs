@@ -1,3 +1,9 @@
+pub mod board_repo;
 pub mod db;
+pub mod feed_repo;
+pub mod share_repo;
 pub mod task_repo;
+pub mod template_repo;
+pub mod time_entry_repo;
 pub mod user_repo;
+pub mod view_repo;
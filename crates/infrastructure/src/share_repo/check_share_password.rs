@@ -0,0 +1,22 @@
+use domain::error::AppError;
+use domain::share::inputs::CheckSharePasswordInput;
+
+use super::repository::{parse_share_id, ShareRepositoryImpl};
+
+pub async fn check_share_password(
+    repo: &ShareRepositoryImpl,
+    input: CheckSharePasswordInput,
+) -> Result<bool, AppError> {
+    let share_id = parse_share_id(&input.share_id)?;
+
+    let matches = sqlx::query_scalar::<_, Option<bool>>(
+        "SELECT password_hash = crypt($2, password_hash) FROM share_links WHERE share_id = $1",
+    )
+    .bind(share_id)
+    .bind(&input.password)
+    .fetch_optional(&repo.pool)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(matches.flatten().unwrap_or(false))
+}
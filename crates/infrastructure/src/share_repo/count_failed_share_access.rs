@@ -0,0 +1,24 @@
+use domain::error::AppError;
+use domain::share::inputs::CountFailedShareAccessInput;
+
+use super::repository::{parse_share_id, ShareRepositoryImpl};
+
+pub async fn count_failed_share_access(
+    repo: &ShareRepositoryImpl,
+    input: CountFailedShareAccessInput,
+) -> Result<i64, AppError> {
+    let share_id = parse_share_id(&input.share_id)?;
+
+    sqlx::query_scalar::<_, i64>(
+        r#"
+            SELECT COUNT(*)
+            FROM share_link_access
+            WHERE share_id = $1 AND NOT granted AND accessed_at >= $2
+            "#,
+    )
+    .bind(share_id)
+    .bind(input.since)
+    .fetch_one(&repo.pool)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))
+}
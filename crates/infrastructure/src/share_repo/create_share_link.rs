@@ -0,0 +1,51 @@
+use domain::error::AppError;
+use domain::share::entity::IssuedShareLink;
+use domain::share::inputs::CreateShareLinkInput;
+
+use super::repository::{ShareLinkRow, ShareRepositoryImpl, SHARE_LINK_COLUMNS};
+use crate::feed_repo::repository::TOKEN_HASH;
+use crate::task_repo::repository::parse_task_id;
+
+pub async fn create_share_link(
+    repo: &ShareRepositoryImpl,
+    input: CreateShareLinkInput,
+) -> Result<IssuedShareLink, AppError> {
+    let task_id = parse_task_id(&input.task_id)?;
+
+    let token = sqlx::query_scalar::<_, String>(
+        "SELECT replace(gen_random_uuid()::text || gen_random_uuid()::text, '-', '')",
+    )
+    .fetch_one(&repo.pool)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    let sql = format!(
+        r#"
+            INSERT INTO share_links AS s (task_id, created_by, token_hash, password_hash, expires_at)
+            SELECT
+                tu.task_id,
+                tu.user_id,
+                {TOKEN_HASH},
+                CASE WHEN $4::text IS NULL THEN NULL ELSE crypt($4, gen_salt('bf')) END,
+                $5
+            FROM tasks_users tu
+            WHERE tu.user_id = $2 AND tu.task_id = $3
+            RETURNING {SHARE_LINK_COLUMNS}
+            "#
+    );
+    let row = sqlx::query_as::<_, ShareLinkRow>(&sql)
+        .bind(&token)
+        .bind(&input.user_id)
+        .bind(task_id)
+        .bind(&input.password)
+        .bind(input.expires_at)
+        .fetch_optional(&repo.pool)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?
+        .ok_or_else(|| AppError::not_found("Task", "Task not found"))?;
+
+    Ok(IssuedShareLink {
+        link: row.into_link(),
+        token,
+    })
+}
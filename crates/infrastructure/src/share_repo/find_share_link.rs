@@ -0,0 +1,21 @@
+use domain::error::AppError;
+use domain::share::entity::ShareLink;
+use domain::share::inputs::FindShareLinkInput;
+
+use super::repository::{ShareLinkRow, ShareRepositoryImpl, SHARE_LINK_COLUMNS};
+use crate::feed_repo::repository::TOKEN_HASH;
+
+pub async fn find_share_link(
+    repo: &ShareRepositoryImpl,
+    input: FindShareLinkInput,
+) -> Result<Option<ShareLink>, AppError> {
+    let sql =
+        format!("SELECT {SHARE_LINK_COLUMNS} FROM share_links s WHERE s.token_hash = {TOKEN_HASH}");
+    let row = sqlx::query_as::<_, ShareLinkRow>(&sql)
+        .bind(&input.token)
+        .fetch_optional(&repo.pool)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(row.map(ShareLinkRow::into_link))
+}
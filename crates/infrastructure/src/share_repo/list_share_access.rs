@@ -0,0 +1,50 @@
+use domain::error::AppError;
+use domain::share::entity::ShareAccess;
+use domain::share::inputs::ListShareAccessInput;
+
+use super::repository::{parse_share_id, ShareAccessRow, ShareRepositoryImpl};
+
+const MAX_ACCESS_ENTRIES: i64 = 500;
+
+pub async fn list_share_access(
+    repo: &ShareRepositoryImpl,
+    input: ListShareAccessInput,
+) -> Result<Vec<ShareAccess>, AppError> {
+    let share_id = parse_share_id(&input.share_id)?;
+
+    let visible = sqlx::query_scalar::<_, bool>(
+        r#"
+            SELECT EXISTS (
+                SELECT 1
+                FROM share_links s
+                JOIN tasks_users tu ON tu.task_id = s.task_id
+                WHERE tu.user_id = $1 AND s.share_id = $2
+            )
+            "#,
+    )
+    .bind(&input.user_id)
+    .bind(share_id)
+    .fetch_one(&repo.pool)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+    if !visible {
+        return Err(AppError::not_found("ShareLink", "Share link not found"));
+    }
+
+    let rows = sqlx::query_as::<_, ShareAccessRow>(
+        r#"
+            SELECT share_id, accessed_at, granted, ip, user_agent
+            FROM share_link_access
+            WHERE share_id = $1
+            ORDER BY accessed_at DESC
+            LIMIT $2
+            "#,
+    )
+    .bind(share_id)
+    .bind(MAX_ACCESS_ENTRIES)
+    .fetch_all(&repo.pool)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(rows.into_iter().map(ShareAccessRow::into_access).collect())
+}
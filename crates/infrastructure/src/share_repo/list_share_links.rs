@@ -0,0 +1,31 @@
+use domain::error::AppError;
+use domain::share::entity::ShareLink;
+use domain::share::inputs::ListShareLinksInput;
+
+use super::repository::{ShareLinkRow, ShareRepositoryImpl, SHARE_LINK_COLUMNS};
+use crate::task_repo::repository::parse_task_id;
+
+pub async fn list_share_links(
+    repo: &ShareRepositoryImpl,
+    input: ListShareLinksInput,
+) -> Result<Vec<ShareLink>, AppError> {
+    let task_id = parse_task_id(&input.task_id)?;
+
+    let sql = format!(
+        r#"
+            SELECT {SHARE_LINK_COLUMNS}
+            FROM share_links s
+            JOIN tasks_users tu ON tu.task_id = s.task_id
+            WHERE tu.user_id = $1 AND s.task_id = $2
+            ORDER BY s.created_at DESC
+            "#
+    );
+    let rows = sqlx::query_as::<_, ShareLinkRow>(&sql)
+        .bind(&input.user_id)
+        .bind(task_id)
+        .fetch_all(&repo.pool)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(rows.into_iter().map(ShareLinkRow::into_link).collect())
+}
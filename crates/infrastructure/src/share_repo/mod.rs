@@ -0,0 +1,9 @@
+pub mod check_share_password;
+pub mod count_failed_share_access;
+pub mod create_share_link;
+pub mod find_share_link;
+pub mod list_share_access;
+pub mod list_share_links;
+pub mod record_share_access;
+pub mod repository;
+pub mod revoke_share_link;
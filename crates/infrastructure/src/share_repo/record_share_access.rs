@@ -0,0 +1,27 @@
+use domain::error::AppError;
+use domain::share::inputs::RecordShareAccessInput;
+
+use super::repository::{parse_share_id, ShareRepositoryImpl};
+
+pub async fn record_share_access(
+    repo: &ShareRepositoryImpl,
+    input: RecordShareAccessInput,
+) -> Result<(), AppError> {
+    let share_id = parse_share_id(&input.share_id)?;
+
+    sqlx::query(
+        r#"
+            INSERT INTO share_link_access (share_id, granted, ip, user_agent)
+            VALUES ($1, $2, $3, $4)
+            "#,
+    )
+    .bind(share_id)
+    .bind(input.granted)
+    .bind(&input.ip)
+    .bind(&input.user_agent)
+    .execute(&repo.pool)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(())
+}
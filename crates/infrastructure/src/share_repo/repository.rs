@@ -0,0 +1,131 @@
+use application::share_service::repository::ShareRepository;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::error::AppError;
+use domain::share::entity::{IssuedShareLink, ShareAccess, ShareLink};
+use domain::share::inputs::{
+    CheckSharePasswordInput, CountFailedShareAccessInput, CreateShareLinkInput, FindShareLinkInput,
+    ListShareAccessInput, ListShareLinksInput, RecordShareAccessInput, RevokeShareLinkInput,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::{
+    check_share_password, count_failed_share_access, create_share_link, find_share_link,
+    list_share_access, list_share_links, record_share_access, revoke_share_link,
+};
+
+pub(crate) const SHARE_LINK_COLUMNS: &str = "s.share_id, s.task_id, s.created_by, s.expires_at, \
+     s.password_hash IS NOT NULL AS has_password, s.revoked_at, s.created_at";
+
+#[derive(Clone)]
+pub struct ShareRepositoryImpl {
+    pub(crate) pool: PgPool,
+}
+
+impl ShareRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+pub(crate) struct ShareLinkRow {
+    pub share_id: Uuid,
+    pub task_id: Uuid,
+    pub created_by: String,
+    pub expires_at: DateTime<Utc>,
+    pub has_password: bool,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ShareLinkRow {
+    pub(crate) fn into_link(self) -> ShareLink {
+        ShareLink {
+            share_id: self.share_id.to_string(),
+            task_id: self.task_id.to_string(),
+            created_by: self.created_by,
+            expires_at: self.expires_at,
+            has_password: self.has_password,
+            revoked_at: self.revoked_at,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+pub(crate) struct ShareAccessRow {
+    pub share_id: Uuid,
+    pub accessed_at: DateTime<Utc>,
+    pub granted: bool,
+    pub ip: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+impl ShareAccessRow {
+    pub(crate) fn into_access(self) -> ShareAccess {
+        ShareAccess {
+            share_id: self.share_id.to_string(),
+            accessed_at: self.accessed_at,
+            granted: self.granted,
+            ip: self.ip,
+            user_agent: self.user_agent,
+        }
+    }
+}
+
+pub(crate) fn parse_share_id(share_id: &str) -> Result<Uuid, AppError> {
+    Uuid::parse_str(share_id)
+        .map_err(|_| AppError::validation("invalid_share_id", "Invalid share link id", None))
+}
+
+#[async_trait]
+impl ShareRepository for ShareRepositoryImpl {
+    async fn create_share_link(
+        &self,
+        input: CreateShareLinkInput,
+    ) -> Result<IssuedShareLink, AppError> {
+        create_share_link::create_share_link(self, input).await
+    }
+
+    async fn list_share_links(
+        &self,
+        input: ListShareLinksInput,
+    ) -> Result<Vec<ShareLink>, AppError> {
+        list_share_links::list_share_links(self, input).await
+    }
+
+    async fn revoke_share_link(&self, input: RevokeShareLinkInput) -> Result<ShareLink, AppError> {
+        revoke_share_link::revoke_share_link(self, input).await
+    }
+
+    async fn list_share_access(
+        &self,
+        input: ListShareAccessInput,
+    ) -> Result<Vec<ShareAccess>, AppError> {
+        list_share_access::list_share_access(self, input).await
+    }
+
+    async fn find_share_link(
+        &self,
+        input: FindShareLinkInput,
+    ) -> Result<Option<ShareLink>, AppError> {
+        find_share_link::find_share_link(self, input).await
+    }
+
+    async fn check_share_password(&self, input: CheckSharePasswordInput) -> Result<bool, AppError> {
+        check_share_password::check_share_password(self, input).await
+    }
+
+    async fn count_failed_share_access(
+        &self,
+        input: CountFailedShareAccessInput,
+    ) -> Result<i64, AppError> {
+        count_failed_share_access::count_failed_share_access(self, input).await
+    }
+
+    async fn record_share_access(&self, input: RecordShareAccessInput) -> Result<(), AppError> {
+        record_share_access::record_share_access(self, input).await
+    }
+}
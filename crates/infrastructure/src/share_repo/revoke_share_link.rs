@@ -0,0 +1,33 @@
+use domain::error::AppError;
+use domain::share::entity::ShareLink;
+use domain::share::inputs::RevokeShareLinkInput;
+
+use super::repository::{parse_share_id, ShareLinkRow, ShareRepositoryImpl, SHARE_LINK_COLUMNS};
+
+// Any member of the shared task may revoke; revoking twice keeps the first
+// timestamp.
+pub async fn revoke_share_link(
+    repo: &ShareRepositoryImpl,
+    input: RevokeShareLinkInput,
+) -> Result<ShareLink, AppError> {
+    let share_id = parse_share_id(&input.share_id)?;
+
+    let sql = format!(
+        r#"
+            UPDATE share_links AS s
+            SET revoked_at = COALESCE(s.revoked_at, NOW())
+            FROM tasks_users tu
+            WHERE tu.task_id = s.task_id AND tu.user_id = $1 AND s.share_id = $2
+            RETURNING {SHARE_LINK_COLUMNS}
+            "#
+    );
+    let row = sqlx::query_as::<_, ShareLinkRow>(&sql)
+        .bind(&input.user_id)
+        .bind(share_id)
+        .fetch_optional(&repo.pool)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?
+        .ok_or_else(|| AppError::not_found("ShareLink", "Share link not found"))?;
+
+    Ok(row.into_link())
+}
@@ -0,0 +1,53 @@
+use domain::error::AppError;
+use domain::task::inputs::AddTaskMemberInput;
+
+use super::ranking::{append_rank, lock_ordering};
+use super::repository::{
+    lock_member_task, parse_task_id, sync_group_completion, TaskRepositoryImpl,
+};
+
+pub async fn add_task_member(
+    repo: &TaskRepositoryImpl,
+    input: AddTaskMemberInput,
+) -> Result<(), AppError> {
+    let task_id = parse_task_id(&input.task_id)?;
+
+    let mut tx = repo
+        .pool
+        .begin()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    lock_member_task(&mut tx, &input.user_id, task_id).await?;
+
+    lock_ordering(&mut tx, &input.member_id).await?;
+    let rank = append_rank(&mut tx, &input.member_id).await?;
+
+    sqlx::query(
+        r#"
+            INSERT INTO tasks_users (task_id, user_id, rank)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (task_id, user_id) DO NOTHING
+            "#,
+    )
+    .bind(task_id)
+    .bind(&input.member_id)
+    .bind(&rank)
+    .execute(&mut *tx)
+    .await
+    .map_err(|error| match &error {
+        sqlx::Error::Database(db_error) if db_error.code().as_deref() == Some("23503") => {
+            AppError::not_found("User", "User not found")
+        }
+        _ => AppError::database(error.to_string()),
+    })?;
+
+    // A new member has not finished yet, which reopens a completed group task.
+    sync_group_completion(&mut tx, &input.user_id, task_id, &input.user_id).await?;
+
+    tx.commit()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use domain::error::AppError;
+use domain::task::entity::Task;
+use domain::task::inputs::ApplySyncMutationInput;
+use domain::task::sync::SyncOperation;
+use sqlx::types::Json;
+
+use super::create_task::create_task_in;
+use super::delete_task::delete_task_in;
+use super::repository::TaskRepositoryImpl;
+use super::update_task::update_task_in;
+
+// The mutation id is claimed before the mutation runs and its result saved in
+// the same transaction. A concurrent retry waits on the claim and then reads
+// the saved result; a rejected mutation rolls back its claim and may be retried.
+pub async fn apply_sync_mutation(
+    repo: &TaskRepositoryImpl,
+    input: ApplySyncMutationInput,
+) -> Result<Option<Task>, AppError> {
+    let mut tx = repo
+        .pool
+        .begin()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    let claimed = sqlx::query(
+        r#"
+            INSERT INTO sync_mutations (user_id, mutation_id)
+            VALUES ($1, $2)
+            ON CONFLICT (user_id, mutation_id) DO NOTHING
+            "#,
+    )
+    .bind(&input.user_id)
+    .bind(&input.mutation.mutation_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?
+    .rows_affected()
+        == 1;
+
+    if !claimed {
+        let task = sqlx::query_scalar::<_, Option<Json<Task>>>(
+            r#"SELECT task FROM sync_mutations WHERE user_id = $1 AND mutation_id = $2"#,
+        )
+        .bind(&input.user_id)
+        .bind(&input.mutation.mutation_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+        return Ok(task.map(|Json(task)| task));
+    }
+
+    let task = match input.mutation.operation {
+        SyncOperation::Create(create) => Some(create_task_in(&mut tx, create).await?),
+        SyncOperation::Update(update) => Some(update_task_in(&mut tx, update).await?),
+        SyncOperation::Delete(delete) => {
+            delete_task_in(&mut tx, delete).await?;
+            None
+        }
+    };
+
+    sqlx::query(r#"UPDATE sync_mutations SET task = $3 WHERE user_id = $1 AND mutation_id = $2"#)
+        .bind(&input.user_id)
+        .bind(&input.mutation.mutation_id)
+        .bind(task.as_ref().map(Json))
+        .execute(&mut *tx)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    tx.commit()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(task)
+}
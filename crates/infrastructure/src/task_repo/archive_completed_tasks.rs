@@ -0,0 +1,33 @@
+use domain::error::AppError;
+use domain::task::inputs::ArchiveCompletedTasksInput;
+
+use super::repository::TaskRepositoryImpl;
+
+pub async fn archive_completed_tasks(
+    repo: &TaskRepositoryImpl,
+    input: ArchiveCompletedTasksInput,
+) -> Result<u64, AppError> {
+    let result = sqlx::query(
+        r#"
+            UPDATE tasks
+            SET archived_at = NOW(), updated_at = NOW(), version = version + 1
+            WHERE task_id IN (
+                SELECT task_id
+                FROM tasks
+                WHERE archived_at IS NULL
+                  AND status = 'COMPLETED'
+                  AND completed_at < $1
+                ORDER BY completed_at
+                LIMIT $2
+                FOR UPDATE SKIP LOCKED
+            )
+            "#,
+    )
+    .bind(input.completed_before)
+    .bind(input.batch_size.max(1))
+    .execute(&repo.pool)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(result.rows_affected())
+}
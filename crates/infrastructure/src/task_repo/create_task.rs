@@ -1,8 +1,14 @@
 use domain::error::AppError;
 use domain::task::entity::Task;
 use domain::task::inputs::CreateTaskInput;
+use domain::task::revision::TaskFieldChange;
+use sqlx::PgConnection;
+use uuid::Uuid;
 
-use super::repository::{TaskRepositoryImpl, TaskRow};
+use super::ranking::{append_rank, lock_ordering};
+use super::repository::{
+    check_parent, fetch_task, insert_task_revision, parse_task_id, TaskRepositoryImpl,
+};
 
 pub async fn create_task(
     repo: &TaskRepositoryImpl,
@@ -14,28 +20,80 @@ pub async fn create_task(
         .await
         .map_err(|error| AppError::database(error.to_string()))?;
 
-    let row = sqlx::query_as::<_, TaskRow>(
+    let task = create_task_in(&mut tx, input).await?;
+
+    tx.commit()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(task)
+}
+
+pub(crate) async fn create_task_in(
+    conn: &mut PgConnection,
+    input: CreateTaskInput,
+) -> Result<Task, AppError> {
+    let parent_task_id = input
+        .parent_task_id
+        .as_deref()
+        .map(parse_task_id)
+        .transpose()?;
+    if let Some(parent_task_id) = parent_task_id {
+        check_parent(&mut *conn, &input.user_id, None, parent_task_id).await?;
+    }
+
+    let task_id = sqlx::query_scalar::<_, Uuid>(
         r#"
-            INSERT INTO tasks (content, status, completed_at, version, created_at, updated_at)
-            VALUES ($1, 'PENDING', NULL, 0, NOW(), NOW())
-            RETURNING task_id, content, completed_at, version, created_at, updated_at
+            INSERT INTO tasks (
+                content, status, completed_at, parent_task_id, estimate_minutes,
+                remaining_minutes, story_points, due_at, labels, priority, recurrence,
+                snoozed_until, waiting_on, is_group, version, created_at, updated_at
+            )
+            VALUES (
+                $1, $2, CASE WHEN $2 = 'COMPLETED' THEN COALESCE($13, NOW()) END, $3, $4, $5, $6,
+                $7, $8, $9, $10, $11, $12, $14, 0, NOW(), NOW()
+            )
+            RETURNING task_id
             "#,
     )
     .bind(&input.content)
-    .fetch_one(&mut *tx)
+    .bind(input.status.as_str())
+    .bind(parent_task_id)
+    .bind(input.estimate_minutes)
+    .bind(input.remaining_minutes)
+    .bind(input.story_points)
+    .bind(input.due_at)
+    .bind(&input.labels)
+    .bind(input.priority.map(|priority| priority.as_str()))
+    .bind(&input.recurrence)
+    .bind(input.snoozed_until)
+    .bind(&input.waiting_on)
+    .bind(input.completed_at)
+    .bind(input.group)
+    .fetch_one(&mut *conn)
     .await
     .map_err(|error| AppError::database(error.to_string()))?;
 
-    sqlx::query(r#"INSERT INTO tasks_users (task_id, user_id) VALUES ($1, $2)"#)
-        .bind(row.task_id)
+    lock_ordering(&mut *conn, &input.user_id).await?;
+    let rank = append_rank(&mut *conn, &input.user_id).await?;
+
+    sqlx::query(r#"INSERT INTO tasks_users (task_id, user_id, rank) VALUES ($1, $2, $3)"#)
+        .bind(task_id)
         .bind(&input.user_id)
-        .execute(&mut *tx)
+        .bind(&rank)
+        .execute(&mut *conn)
         .await
         .map_err(|error| AppError::database(error.to_string()))?;
 
-    tx.commit()
-        .await
-        .map_err(|error| AppError::database(error.to_string()))?;
+    let task = fetch_task(&mut *conn, &input.user_id, task_id)
+        .await?
+        .ok_or_else(|| AppError::database("Created task could not be read back"))?;
+    let changes = [TaskFieldChange::new(
+        "content",
+        None,
+        Some(task.content.clone()),
+    )];
+    insert_task_revision(&mut *conn, &task, &task.user_id, &changes).await?;
 
-    Ok(row.into_task(input.user_id))
+    Ok(task)
 }
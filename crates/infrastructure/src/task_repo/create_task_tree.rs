@@ -0,0 +1,57 @@
+use domain::error::AppError;
+use domain::task::entity::Task;
+use domain::task::inputs::{CreateTaskInput, CreateTaskTreeInput, NewTaskNode};
+use domain::task::status::TaskStatus;
+
+use super::create_task::create_task_in;
+use super::repository::TaskRepositoryImpl;
+
+pub async fn create_task_tree(
+    repo: &TaskRepositoryImpl,
+    input: CreateTaskTreeInput,
+) -> Result<Vec<Task>, AppError> {
+    let mut tx = repo
+        .pool
+        .begin()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    // Depth-first in template order so ranks follow the tree as written.
+    let mut pending: Vec<(NewTaskNode, Option<String>)> = input
+        .tasks
+        .into_iter()
+        .rev()
+        .map(|node| (node, input.parent_task_id.clone()))
+        .collect();
+    let mut created = Vec::new();
+
+    while let Some((node, parent_task_id)) = pending.pop() {
+        let task = create_task_in(
+            &mut tx,
+            CreateTaskInput {
+                user_id: input.user_id.clone(),
+                content: node.content,
+                status: TaskStatus::Pending,
+                parent_task_id,
+                due_at: node.due_at,
+                labels: node.labels,
+                ..Default::default()
+            },
+        )
+        .await?;
+
+        pending.extend(
+            node.subtasks
+                .into_iter()
+                .rev()
+                .map(|subtask| (subtask, Some(task.task_id.clone()))),
+        );
+        created.push(task);
+    }
+
+    tx.commit()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(created)
+}
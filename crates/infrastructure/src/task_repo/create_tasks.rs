@@ -0,0 +1,28 @@
+use domain::error::AppError;
+use domain::task::entity::Task;
+use domain::task::inputs::CreateTasksInput;
+
+use super::create_task::create_task_in;
+use super::repository::TaskRepositoryImpl;
+
+pub async fn create_tasks(
+    repo: &TaskRepositoryImpl,
+    input: CreateTasksInput,
+) -> Result<Vec<Task>, AppError> {
+    let mut tx = repo
+        .pool
+        .begin()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    let mut created = Vec::with_capacity(input.tasks.len());
+    for task in input.tasks {
+        created.push(create_task_in(&mut tx, task).await?);
+    }
+
+    tx.commit()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(created)
+}
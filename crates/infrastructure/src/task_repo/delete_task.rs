@@ -1,30 +1,64 @@
 use domain::error::AppError;
 use domain::task::inputs::DeleteTaskInput;
-use uuid::Uuid;
+use sqlx::PgConnection;
 
-use super::repository::TaskRepositoryImpl;
+use super::repository::{parse_task_id, TaskRepositoryImpl};
 
 pub async fn delete_task(
     repo: &TaskRepositoryImpl,
     input: DeleteTaskInput,
-) -> Result<i64, AppError> {
-    let task_id = Uuid::parse_str(&input.task_id)
-        .map_err(|_| AppError::validation("invalid_task_id", "Invalid task id", None))?;
+) -> Result<(), AppError> {
+    let mut tx = repo
+        .pool
+        .begin()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
 
-    let result = sqlx::query(
+    delete_task_in(&mut tx, input).await?;
+
+    tx.commit()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(())
+}
+
+pub(crate) async fn delete_task_in(
+    conn: &mut PgConnection,
+    input: DeleteTaskInput,
+) -> Result<(), AppError> {
+    let task_id = parse_task_id(&input.task_id)?;
+
+    let current_version = sqlx::query_scalar::<_, i32>(
         r#"
-            DELETE FROM tasks
-            USING tasks_users tu
-            WHERE tasks.task_id = tu.task_id
-              AND tu.user_id = $1
-              AND tasks.task_id = $2
+            SELECT t.version
+            FROM tasks t
+            JOIN tasks_users tu ON t.task_id = tu.task_id
+            WHERE tu.user_id = $1 AND t.task_id = $2
+            FOR UPDATE OF t
             "#,
     )
     .bind(&input.user_id)
     .bind(task_id)
-    .execute(&repo.pool)
+    .fetch_optional(&mut *conn)
     .await
-    .map_err(|error| AppError::database(error.to_string()))?;
+    .map_err(|error| AppError::database(error.to_string()))?
+    .ok_or_else(|| AppError::not_found("Task", "Task not found"))?;
+
+    if let Some(version) = input.version {
+        if version != current_version {
+            return Err(AppError::version_conflict(
+                "Task",
+                i64::from(current_version),
+            ));
+        }
+    }
+
+    sqlx::query("DELETE FROM tasks WHERE task_id = $1")
+        .bind(task_id)
+        .execute(&mut *conn)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
 
-    Ok(result.rows_affected() as i64)
+    Ok(())
 }
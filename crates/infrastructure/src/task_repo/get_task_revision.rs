@@ -0,0 +1,32 @@
+use domain::error::AppError;
+use domain::task::inputs::GetTaskRevisionInput;
+use domain::task::revision::TaskRevision;
+
+use super::repository::{
+    parse_task_id, TaskRepositoryImpl, TaskRevisionRow, TASK_REVISION_COLUMNS,
+};
+
+pub async fn get_task_revision(
+    repo: &TaskRepositoryImpl,
+    input: GetTaskRevisionInput,
+) -> Result<Option<TaskRevision>, AppError> {
+    let task_id = parse_task_id(&input.task_id)?;
+
+    let sql = format!(
+        r#"
+            SELECT {TASK_REVISION_COLUMNS}
+            FROM task_revisions r
+            JOIN tasks_users tu ON r.task_id = tu.task_id
+            WHERE tu.user_id = $1 AND r.task_id = $2 AND r.version = $3
+            "#
+    );
+    let row = sqlx::query_as::<_, TaskRevisionRow>(&sql)
+        .bind(&input.user_id)
+        .bind(task_id)
+        .bind(input.version)
+        .fetch_optional(&repo.pool)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(row.map(TaskRevisionRow::into_revision))
+}
@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use domain::error::AppError;
+use domain::task::inputs::ListTaskChangesInput;
+use domain::task::sync::TaskChanges;
+use uuid::Uuid;
+
+use super::repository::{TaskRepositoryImpl, TaskRow, TASK_COLUMNS};
+
+#[derive(sqlx::FromRow)]
+struct ChangeRow {
+    task_id: Uuid,
+    deleted: bool,
+    seq: i64,
+}
+
+pub async fn list_task_changes(
+    repo: &TaskRepositoryImpl,
+    input: ListTaskChangesInput,
+) -> Result<TaskChanges, AppError> {
+    let mut tx = repo
+        .pool
+        .begin()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    // Number pending changes before reading. The lock keeps two syncs for the
+    // same user from interleaving their numbering.
+    sqlx::query("SELECT pg_advisory_xact_lock(hashtext('task_changes'), hashtext($1))")
+        .bind(&input.user_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+    sqlx::query(
+        r#"
+            UPDATE task_changes
+            SET seq = nextval('task_change_seq')
+            WHERE user_id = $1 AND seq IS NULL
+            "#,
+    )
+    .bind(&input.user_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    let high_water = sqlx::query_scalar::<_, Option<i64>>(
+        "SELECT MAX(seq) FROM task_changes WHERE user_id = $1",
+    )
+    .bind(&input.user_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?
+    .unwrap_or(0);
+
+    let since = input.since.unwrap_or(0);
+    if since > high_water {
+        return Err(AppError::validation(
+            "invalid_since",
+            "Since must be a token returned by a previous sync",
+            Some("since".to_string()),
+        ));
+    }
+
+    // A full sync has nothing to delete on the client, so it skips tombstones.
+    let changes = sqlx::query_as::<_, ChangeRow>(
+        r#"
+            SELECT task_id, deleted, seq
+            FROM task_changes
+            WHERE user_id = $1 AND seq > $2 AND seq <= $3 AND ($4 OR NOT deleted)
+            ORDER BY seq
+            LIMIT $5
+            "#,
+    )
+    .bind(&input.user_id)
+    .bind(since)
+    .bind(high_water)
+    .bind(input.since.is_some())
+    .bind(input.limit + 1)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    let has_more = changes.len() as i64 > input.limit;
+    let changes = &changes[..changes.len().min(input.limit as usize)];
+
+    let live: Vec<Uuid> = changes
+        .iter()
+        .filter(|change| !change.deleted)
+        .map(|change| change.task_id)
+        .collect();
+    let sql = format!(
+        r#"
+            SELECT {TASK_COLUMNS}
+            FROM tasks t
+            JOIN tasks_users tu ON t.task_id = tu.task_id
+            WHERE tu.user_id = $1 AND t.task_id = ANY($2)
+            "#
+    );
+    let mut rows: HashMap<Uuid, TaskRow> = sqlx::query_as::<_, TaskRow>(&sql)
+        .bind(&input.user_id)
+        .bind(&live)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?
+        .into_iter()
+        .map(|row| (row.task_id, row))
+        .collect();
+
+    tx.commit()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    let mut tasks = Vec::new();
+    let mut deleted = Vec::new();
+    for change in changes {
+        match rows.remove(&change.task_id) {
+            Some(row) => tasks.push(row.into_task(input.user_id.clone())),
+            None if input.since.is_some() => deleted.push(change.task_id.to_string()),
+            None => {}
+        }
+    }
+
+    let next_since = match changes.last() {
+        Some(last) if has_more => last.seq,
+        _ => high_water,
+    };
+
+    Ok(TaskChanges {
+        tasks,
+        deleted,
+        next_since,
+        has_more,
+    })
+}
@@ -0,0 +1,35 @@
+use domain::error::AppError;
+use domain::task::inputs::ListTaskRevisionsInput;
+use domain::task::revision::TaskRevision;
+
+use super::repository::{
+    parse_task_id, TaskRepositoryImpl, TaskRevisionRow, TASK_REVISION_COLUMNS,
+};
+
+pub async fn list_task_revisions(
+    repo: &TaskRepositoryImpl,
+    input: ListTaskRevisionsInput,
+) -> Result<Vec<TaskRevision>, AppError> {
+    let task_id = parse_task_id(&input.task_id)?;
+
+    let sql = format!(
+        r#"
+            SELECT {TASK_REVISION_COLUMNS}
+            FROM task_revisions r
+            JOIN tasks_users tu ON r.task_id = tu.task_id
+            WHERE tu.user_id = $1 AND r.task_id = $2
+            ORDER BY r.version DESC
+            "#
+    );
+    let rows = sqlx::query_as::<_, TaskRevisionRow>(&sql)
+        .bind(&input.user_id)
+        .bind(task_id)
+        .fetch_all(&repo.pool)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(rows
+        .into_iter()
+        .map(TaskRevisionRow::into_revision)
+        .collect())
+}
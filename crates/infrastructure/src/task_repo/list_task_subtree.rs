@@ -0,0 +1,43 @@
+use domain::error::AppError;
+use domain::task::entity::Task;
+use domain::task::inputs::ListTaskSubtreeInput;
+
+use super::repository::{parse_task_id, TaskRepositoryImpl, TaskRow, TASK_COLUMNS};
+
+pub async fn list_task_subtree(
+    repo: &TaskRepositoryImpl,
+    input: ListTaskSubtreeInput,
+) -> Result<Vec<Task>, AppError> {
+    let task_id = parse_task_id(&input.task_id)?;
+
+    let sql = format!(
+        r#"
+            WITH RECURSIVE subtree AS (
+                SELECT t.task_id, 0 AS depth
+                FROM tasks t
+                JOIN tasks_users tu ON t.task_id = tu.task_id
+                WHERE tu.user_id = $1 AND t.task_id = $2
+                UNION ALL
+                SELECT child.task_id, s.depth + 1
+                FROM tasks child
+                JOIN subtree s ON child.parent_task_id = s.task_id
+            )
+            SELECT {TASK_COLUMNS}
+            FROM subtree s
+            JOIN tasks t ON t.task_id = s.task_id
+            JOIN tasks_users tu ON t.task_id = tu.task_id AND tu.user_id = $1
+            ORDER BY s.depth, tu.rank
+            "#
+    );
+    let rows = sqlx::query_as::<_, TaskRow>(&sql)
+        .bind(&input.user_id)
+        .bind(task_id)
+        .fetch_all(&repo.pool)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| row.into_task(input.user_id.clone()))
+        .collect())
+}
@@ -1,32 +1,91 @@
 use domain::error::AppError;
 use domain::task::entity::Task;
-use domain::task::inputs::ListTasksInput;
+use domain::task::filter::DueFilter;
+use domain::task::inputs::{ArchiveFilter, ListTasksInput, SnoozeFilter};
+use domain::task::rank::TaskSort;
 
-use super::repository::{TaskRepositoryImpl, TaskRow};
+use super::repository::{TaskRepositoryImpl, TaskRow, TASK_COLUMNS};
 
 pub async fn list_tasks(
     repo: &TaskRepositoryImpl,
     input: ListTasksInput,
 ) -> Result<Vec<Task>, AppError> {
+    let query = &input.query;
     let offset = (input.page - 1).max(0) * input.limit;
+    let order_by = match (query.snoozed, query.sort) {
+        (SnoozeFilter::Only, _) => "t.snoozed_until ASC, t.created_at DESC",
+        (_, TaskSort::CreatedAt) => "t.created_at DESC",
+        (_, TaskSort::Manual) => "tu.rank ASC",
+    };
+    // Snoozed tasks wake up on their own once snoozed_until passes; there is
+    // no job clearing the column, so the comparison is always against NOW().
+    let snoozed = match query.snoozed {
+        SnoozeFilter::Awake => "(t.snoozed_until IS NULL OR t.snoozed_until <= NOW())",
+        SnoozeFilter::Include => "TRUE",
+        SnoozeFilter::Only => "t.snoozed_until > NOW()",
+    };
+    let archived = match query.archived {
+        ArchiveFilter::Active => "t.archived_at IS NULL",
+        ArchiveFilter::Include => "TRUE",
+        ArchiveFilter::Only => "t.archived_at IS NOT NULL",
+    };
+    let (due_mode, due_from, due_to) = match query.due {
+        Some(DueFilter::Between { from, to }) => (Some("between"), from, to),
+        Some(DueFilter::None) => (Some("none"), None, None),
+        None => (None, None, None),
+    };
+    let statuses: Vec<&str> = query
+        .statuses
+        .iter()
+        .map(|status| status.as_str())
+        .collect();
 
-    let rows = sqlx::query_as::<_, TaskRow>(
+    let sql = format!(
         r#"
-            SELECT t.task_id, t.content, t.completed_at, t.version, t.created_at, t.updated_at
+            SELECT {TASK_COLUMNS}
             FROM tasks t
             JOIN tasks_users tu ON t.task_id = tu.task_id
             WHERE tu.user_id = $1
-            ORDER BY t.created_at DESC
+              AND (
+                $4::text IS NULL
+                OR EXISTS (
+                    SELECT 1 FROM task_assignees a WHERE a.task_id = t.task_id AND a.user_id = $4
+                )
+              )
+              AND {snoozed}
+              AND {archived}
+              AND ($5::text IS NULL OR STRPOS(LOWER(t.content), LOWER($5)) > 0)
+              AND (CARDINALITY($6::text[]) = 0 OR t.status = ANY($6))
+              AND (
+                $7::text IS NULL
+                OR ($7 = 'none' AND t.due_at IS NULL)
+                OR (
+                    $7 = 'between'
+                    AND t.due_at IS NOT NULL
+                    AND ($8::timestamptz IS NULL OR t.due_at >= $8)
+                    AND ($9::timestamptz IS NULL OR t.due_at < $9)
+                )
+              )
+              AND t.labels @> $10::text[]
+            ORDER BY {order_by}
             OFFSET $2
             LIMIT $3
-            "#,
-    )
-    .bind(&input.user_id)
-    .bind(offset)
-    .bind(input.limit)
-    .fetch_all(&repo.pool)
-    .await
-    .map_err(|error| AppError::database(error.to_string()))?;
+            "#
+    );
+    let rows = sqlx::query_as::<_, TaskRow>(&sql)
+        .bind(&input.user_id)
+        .bind(offset)
+        .bind(input.limit)
+        .bind(&query.assigned_to)
+        .bind(&query.search)
+        .bind(&statuses)
+        .bind(due_mode)
+        .bind(due_from)
+        .bind(due_to)
+        .bind(&query.labels)
+        .fetch_all(&repo.pool)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
 
     Ok(rows
         .into_iter()
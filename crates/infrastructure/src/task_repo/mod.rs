@@ -1,6 +1,22 @@
+pub mod add_task_member;
+pub mod apply_sync_mutation;
+pub mod archive_completed_tasks;
 pub mod create_task;
+pub mod create_task_tree;
+pub mod create_tasks;
 pub mod delete_task;
 pub mod get_task;
+pub mod get_task_revision;
+pub mod list_task_changes;
+pub mod list_task_revisions;
+pub mod list_task_subtree;
 pub mod list_tasks;
+pub mod move_task;
+pub(crate) mod ranking;
+pub mod remove_task_member;
 pub mod repository;
+pub mod set_member_completion;
+pub mod set_task_archived;
+pub mod task_stats;
+pub mod transfer_tasks;
 pub mod update_task;
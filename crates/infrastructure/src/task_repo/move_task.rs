@@ -0,0 +1,136 @@
+use domain::error::AppError;
+use domain::task::entity::Task;
+use domain::task::inputs::MoveTaskInput;
+use domain::task::rank::rank_between;
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+use super::ranking::{lock_ordering, rank_of, rebalance};
+use super::repository::{fetch_task, parse_task_id, TaskRepositoryImpl};
+
+pub async fn move_task(repo: &TaskRepositoryImpl, input: MoveTaskInput) -> Result<Task, AppError> {
+    let mut tx = repo
+        .pool
+        .begin()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    let task = move_task_in(&mut tx, input).await?;
+
+    tx.commit()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(task)
+}
+
+pub(crate) async fn move_task_in(
+    conn: &mut PgConnection,
+    input: MoveTaskInput,
+) -> Result<Task, AppError> {
+    let task_id = parse_task_id(&input.task_id)?;
+    let before = input.before.as_deref().map(parse_task_id).transpose()?;
+    let after = input.after.as_deref().map(parse_task_id).transpose()?;
+
+    lock_ordering(&mut *conn, &input.user_id).await?;
+
+    rank_of(&mut *conn, &input.user_id, task_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("Task", "Task not found"))?;
+
+    let (lower, upper) = bounds(&mut *conn, &input.user_id, task_id, before, after).await?;
+    let rank = match rank_between(lower.as_deref(), upper.as_deref()) {
+        Some(rank) => rank,
+        None => {
+            rebalance(&mut *conn, &input.user_id).await?;
+            let (lower, upper) = bounds(&mut *conn, &input.user_id, task_id, before, after).await?;
+            rank_between(lower.as_deref(), upper.as_deref())
+                .ok_or_else(|| AppError::database("Unable to allocate task rank"))?
+        }
+    };
+
+    sqlx::query("UPDATE tasks_users SET rank = $3 WHERE user_id = $1 AND task_id = $2")
+        .bind(&input.user_id)
+        .bind(task_id)
+        .bind(&rank)
+        .execute(&mut *conn)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    // Rank is part of the task representation, so moving changes its ETag.
+    sqlx::query("UPDATE tasks SET version = version + 1, updated_at = NOW() WHERE task_id = $1")
+        .bind(task_id)
+        .execute(&mut *conn)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    fetch_task(conn, &input.user_id, task_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("Task", "Task not found"))
+}
+
+async fn bounds(
+    conn: &mut PgConnection,
+    user_id: &str,
+    task_id: Uuid,
+    before: Option<Uuid>,
+    after: Option<Uuid>,
+) -> Result<(Option<String>, Option<String>), AppError> {
+    let upper = match before {
+        Some(before) => Some(neighbor_rank(&mut *conn, user_id, before).await?),
+        None => None,
+    };
+    let lower = match after {
+        Some(after) => Some(neighbor_rank(&mut *conn, user_id, after).await?),
+        None => None,
+    };
+
+    match (lower, upper) {
+        (Some(lower), Some(upper)) if lower >= upper => Err(AppError::validation(
+            "invalid_move",
+            "The after task must come before the before task",
+            None,
+        )),
+        (Some(lower), None) => {
+            let upper = sqlx::query_scalar::<_, Option<String>>(
+                r#"
+                    SELECT MIN(rank) FROM tasks_users
+                    WHERE user_id = $1 AND rank > $2 AND task_id <> $3
+                    "#,
+            )
+            .bind(user_id)
+            .bind(&lower)
+            .bind(task_id)
+            .fetch_one(conn)
+            .await
+            .map_err(|error| AppError::database(error.to_string()))?;
+            Ok((Some(lower), upper))
+        }
+        (None, Some(upper)) => {
+            let lower = sqlx::query_scalar::<_, Option<String>>(
+                r#"
+                    SELECT MAX(rank) FROM tasks_users
+                    WHERE user_id = $1 AND rank < $2 AND task_id <> $3
+                    "#,
+            )
+            .bind(user_id)
+            .bind(&upper)
+            .bind(task_id)
+            .fetch_one(conn)
+            .await
+            .map_err(|error| AppError::database(error.to_string()))?;
+            Ok((lower, Some(upper)))
+        }
+        bounds => Ok(bounds),
+    }
+}
+
+async fn neighbor_rank(
+    conn: &mut PgConnection,
+    user_id: &str,
+    task_id: Uuid,
+) -> Result<String, AppError> {
+    rank_of(conn, user_id, task_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("Task", "Neighbor task not found"))
+}
@@ -0,0 +1,87 @@
+use domain::error::AppError;
+use domain::task::rank::{rank_between, spread_ranks};
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+pub(crate) async fn lock_ordering(conn: &mut PgConnection, user_id: &str) -> Result<(), AppError> {
+    sqlx::query("SELECT pg_advisory_xact_lock(hashtext($1))")
+        .bind(user_id)
+        .execute(conn)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(())
+}
+
+pub(crate) async fn rank_of(
+    conn: &mut PgConnection,
+    user_id: &str,
+    task_id: Uuid,
+) -> Result<Option<String>, AppError> {
+    sqlx::query_scalar::<_, String>(
+        "SELECT rank FROM tasks_users WHERE user_id = $1 AND task_id = $2",
+    )
+    .bind(user_id)
+    .bind(task_id)
+    .fetch_optional(conn)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))
+}
+
+pub(crate) async fn append_rank(
+    conn: &mut PgConnection,
+    user_id: &str,
+) -> Result<String, AppError> {
+    if let Some(rank) = rank_between(last_rank(&mut *conn, user_id).await?.as_deref(), None) {
+        return Ok(rank);
+    }
+
+    rebalance(&mut *conn, user_id).await?;
+    rank_between(last_rank(&mut *conn, user_id).await?.as_deref(), None)
+        .ok_or_else(|| AppError::database("Unable to allocate task rank"))
+}
+
+pub(crate) async fn rebalance(conn: &mut PgConnection, user_id: &str) -> Result<(), AppError> {
+    let task_ids = sqlx::query_scalar::<_, Uuid>(
+        "SELECT task_id FROM tasks_users WHERE user_id = $1 ORDER BY rank, created_at",
+    )
+    .bind(user_id)
+    .fetch_all(&mut *conn)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    let ranks = spread_ranks(task_ids.len());
+
+    sqlx::query(
+        r#"
+            UPDATE tasks_users tu
+            SET rank = ranked.rank
+            FROM UNNEST($2::uuid[], $3::text[]) AS ranked(task_id, rank)
+            WHERE tu.user_id = $1 AND tu.task_id = ranked.task_id
+            "#,
+    )
+    .bind(user_id)
+    .bind(&task_ids)
+    .bind(&ranks)
+    .execute(&mut *conn)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    sqlx::query(
+        "UPDATE tasks SET version = version + 1, updated_at = NOW() WHERE task_id = ANY($1)",
+    )
+    .bind(&task_ids)
+    .execute(conn)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(())
+}
+
+async fn last_rank(conn: &mut PgConnection, user_id: &str) -> Result<Option<String>, AppError> {
+    sqlx::query_scalar::<_, Option<String>>("SELECT MAX(rank) FROM tasks_users WHERE user_id = $1")
+        .bind(user_id)
+        .fetch_one(conn)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))
+}
@@ -0,0 +1,58 @@
+use domain::error::AppError;
+use domain::task::inputs::RemoveTaskMemberInput;
+
+use super::repository::{
+    lock_member_task, parse_task_id, sync_group_completion, unassign_user_in, TaskRepositoryImpl,
+};
+
+pub async fn remove_task_member(
+    repo: &TaskRepositoryImpl,
+    input: RemoveTaskMemberInput,
+) -> Result<(), AppError> {
+    let task_id = parse_task_id(&input.task_id)?;
+
+    let mut tx = repo
+        .pool
+        .begin()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    lock_member_task(&mut tx, &input.user_id, task_id).await?;
+
+    let members =
+        sqlx::query_scalar::<_, String>("SELECT user_id FROM tasks_users WHERE task_id = $1")
+            .bind(task_id)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|error| AppError::database(error.to_string()))?;
+
+    if !members.contains(&input.member_id) {
+        return Err(AppError::not_found("TaskMember", "Task member not found"));
+    }
+    if members.len() == 1 {
+        return Err(AppError::conflict(
+            "Task",
+            "A task must keep at least one member",
+        ));
+    }
+
+    unassign_user_in(&mut tx, &input.member_id, Some(task_id), &input.user_id).await?;
+
+    sqlx::query("DELETE FROM tasks_users WHERE task_id = $1 AND user_id = $2")
+        .bind(task_id)
+        .bind(&input.member_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    // The member who left may have been the last one the group was waiting on.
+    if let Some(viewer) = members.iter().find(|member| **member != input.member_id) {
+        sync_group_completion(&mut tx, viewer, task_id, &input.user_id).await?;
+    }
+
+    tx.commit()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(())
+}
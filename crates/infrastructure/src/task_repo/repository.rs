@@ -3,13 +3,45 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use domain::error::AppError;
 use domain::task::entity::Task;
+use domain::task::group::{group_status, GroupProgress};
 use domain::task::inputs::{
-    CreateTaskInput, DeleteTaskInput, GetTaskInput, ListTasksInput, UpdateTaskInput,
+    AddTaskMemberInput, ApplySyncMutationInput, ArchiveCompletedTasksInput, CreateTaskInput,
+    CreateTaskTreeInput, CreateTasksInput, DeleteTaskInput, GetTaskInput, GetTaskRevisionInput,
+    ListTaskChangesInput, ListTaskRevisionsInput, ListTaskSubtreeInput, ListTasksInput,
+    MoveTaskInput, RemoveTaskMemberInput, SetMemberCompletionInput, SetTaskArchivedInput,
+    TaskStatsQuery, TransferTaskLinksInput, UpdateTaskInput,
 };
-use sqlx::PgPool;
+use domain::task::priority::TaskPriority;
+use domain::task::revision::{TaskFieldChange, TaskRevision, TaskSnapshot};
+use domain::task::stats::TaskStats;
+use domain::task::status::TaskStatus;
+use domain::task::sync::TaskChanges;
+use domain::task::transfer::TaskTransfer;
+use sqlx::types::Json;
+use sqlx::{PgConnection, PgPool};
 use uuid::Uuid;
 
-use super::{create_task, delete_task, get_task, list_tasks, update_task};
+use super::{
+    add_task_member, apply_sync_mutation, archive_completed_tasks, create_task, create_task_tree,
+    create_tasks, delete_task, get_task, get_task_revision, list_task_changes, list_task_revisions,
+    list_task_subtree, list_tasks, move_task, remove_task_member, set_member_completion,
+    set_task_archived, task_stats, transfer_tasks, update_task,
+};
+
+pub(crate) const TASK_COLUMNS: &str =
+    "t.task_id, t.content, t.status, t.completed_at, t.parent_task_id, t.estimate_minutes, \
+     t.remaining_minutes, t.story_points, t.due_at, t.labels, t.priority, \
+     t.recurrence, t.snoozed_until, t.waiting_on, t.archived_at, \
+     ARRAY(SELECT a.user_id FROM task_assignees a WHERE a.task_id = t.task_id ORDER BY a.user_id) \
+     AS assignee_ids, t.is_group, tu.completed_at AS member_completed_at, \
+     CASE WHEN t.is_group THEN (SELECT COUNT(*) FROM tasks_users m \
+     WHERE m.task_id = t.task_id AND m.completed_at IS NOT NULL) ELSE 0 END AS members_completed, \
+     CASE WHEN t.is_group THEN (SELECT COUNT(*) FROM tasks_users m \
+     WHERE m.task_id = t.task_id) ELSE 0 END AS members_total, \
+     t.version, tu.rank, t.tracked_seconds, t.created_at, t.updated_at";
+
+pub(crate) const TASK_REVISION_COLUMNS: &str =
+    "r.task_id, r.version, r.changed_by, r.changes, r.snapshot, r.created_at";
 
 #[derive(Clone)]
 pub struct TaskRepositoryImpl {
@@ -26,8 +58,27 @@ impl TaskRepositoryImpl {
 pub(crate) struct TaskRow {
     pub task_id: Uuid,
     pub content: String,
+    pub status: String,
     pub completed_at: Option<DateTime<Utc>>,
+    pub parent_task_id: Option<Uuid>,
+    pub estimate_minutes: Option<i32>,
+    pub remaining_minutes: Option<i32>,
+    pub story_points: Option<i32>,
+    pub due_at: Option<DateTime<Utc>>,
+    pub labels: Vec<String>,
+    pub priority: Option<String>,
+    pub recurrence: Option<String>,
+    pub snoozed_until: Option<DateTime<Utc>>,
+    pub waiting_on: Option<String>,
+    pub archived_at: Option<DateTime<Utc>>,
+    pub assignee_ids: Vec<String>,
+    pub is_group: bool,
+    pub member_completed_at: Option<DateTime<Utc>>,
+    pub members_completed: i64,
+    pub members_total: i64,
     pub version: i32,
+    pub rank: String,
+    pub tracked_seconds: i64,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -38,25 +89,349 @@ impl TaskRow {
             user_id,
             task_id: self.task_id.to_string(),
             content: self.content,
+            status: TaskStatus::parse(&self.status).unwrap_or_default(),
             completed_at: self.completed_at,
+            parent_task_id: self.parent_task_id.map(|id| id.to_string()),
+            estimate_minutes: self.estimate_minutes,
+            remaining_minutes: self.remaining_minutes,
+            story_points: self.story_points,
+            due_at: self.due_at,
+            labels: self.labels,
+            priority: self.priority.as_deref().and_then(TaskPriority::parse),
+            recurrence: self.recurrence,
+            snoozed_until: self.snoozed_until,
+            waiting_on: self.waiting_on,
+            archived_at: self.archived_at,
+            assignee_ids: self.assignee_ids,
+            group: self.is_group.then_some(GroupProgress {
+                completed: self.members_completed,
+                total: self.members_total,
+                member_completed_at: self.member_completed_at,
+            }),
             version: self.version,
+            rank: self.rank,
+            tracked_seconds: self.tracked_seconds,
             created_at: self.created_at,
             updated_at: self.updated_at,
         }
     }
 }
 
+#[derive(sqlx::FromRow)]
+pub(crate) struct TaskRevisionRow {
+    pub task_id: Uuid,
+    pub version: i32,
+    pub changed_by: String,
+    pub changes: Json<Vec<TaskFieldChange>>,
+    pub snapshot: Json<TaskSnapshot>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TaskRevisionRow {
+    pub(crate) fn into_revision(self) -> TaskRevision {
+        TaskRevision {
+            task_id: self.task_id.to_string(),
+            version: self.version,
+            changed_by: self.changed_by,
+            changes: self.changes.0,
+            snapshot: self.snapshot.0,
+            created_at: self.created_at,
+        }
+    }
+}
+
+pub(crate) fn parse_task_id(task_id: &str) -> Result<Uuid, AppError> {
+    Uuid::parse_str(task_id)
+        .map_err(|_| AppError::validation("invalid_task_id", "Invalid task id", None))
+}
+
+pub(crate) async fn fetch_task(
+    conn: &mut PgConnection,
+    user_id: &str,
+    task_id: Uuid,
+) -> Result<Option<Task>, AppError> {
+    let sql = format!(
+        r#"
+            SELECT {TASK_COLUMNS}
+            FROM tasks t
+            JOIN tasks_users tu ON t.task_id = tu.task_id
+            WHERE tu.user_id = $1 AND t.task_id = $2
+            "#
+    );
+    let row = sqlx::query_as::<_, TaskRow>(&sql)
+        .bind(user_id)
+        .bind(task_id)
+        .fetch_optional(conn)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(row.map(|row| row.into_task(user_id.to_string())))
+}
+
+pub(crate) async fn lock_member_task(
+    conn: &mut PgConnection,
+    user_id: &str,
+    task_id: Uuid,
+) -> Result<(), AppError> {
+    sqlx::query_scalar::<_, i32>(
+        r#"
+            SELECT t.version
+            FROM tasks t
+            JOIN tasks_users tu ON t.task_id = tu.task_id
+            WHERE tu.user_id = $1 AND t.task_id = $2
+            FOR UPDATE OF t
+            "#,
+    )
+    .bind(user_id)
+    .bind(task_id)
+    .fetch_optional(conn)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?
+    .ok_or_else(|| AppError::not_found("Task", "Task not found"))?;
+
+    Ok(())
+}
+
+pub(crate) async fn check_parent(
+    conn: &mut PgConnection,
+    user_id: &str,
+    task_id: Option<Uuid>,
+    parent_task_id: Uuid,
+) -> Result<(), AppError> {
+    let invalid_parent = |message: &str| {
+        AppError::validation(
+            "invalid_parent",
+            message,
+            Some("parent_task_id".to_string()),
+        )
+    };
+
+    // Walk up from the new parent; meeting the task itself would form a cycle.
+    let ancestors = sqlx::query_scalar::<_, Uuid>(
+        r#"
+            WITH RECURSIVE ancestors AS (
+                SELECT t.task_id, t.parent_task_id
+                FROM tasks t
+                JOIN tasks_users tu ON t.task_id = tu.task_id
+                WHERE tu.user_id = $1 AND t.task_id = $2
+                UNION
+                SELECT t.task_id, t.parent_task_id
+                FROM tasks t
+                JOIN ancestors a ON t.task_id = a.parent_task_id
+            )
+            SELECT task_id FROM ancestors
+            "#,
+    )
+    .bind(user_id)
+    .bind(parent_task_id)
+    .fetch_all(conn)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    if ancestors.is_empty() {
+        return Err(invalid_parent("Parent task not found"));
+    }
+    if task_id.is_some_and(|task_id| ancestors.contains(&task_id)) {
+        return Err(invalid_parent(
+            "A task cannot be nested under itself or its subtasks",
+        ));
+    }
+
+    Ok(())
+}
+
+pub(crate) async fn insert_task_revision(
+    conn: &mut PgConnection,
+    task: &Task,
+    changed_by: &str,
+    changes: &[TaskFieldChange],
+) -> Result<(), AppError> {
+    let task_id = parse_task_id(&task.task_id)?;
+
+    sqlx::query(
+        r#"
+            INSERT INTO task_revisions (task_id, version, changed_by, changes, snapshot)
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+    )
+    .bind(task_id)
+    .bind(task.version)
+    .bind(changed_by)
+    .bind(Json(changes))
+    .bind(Json(TaskSnapshot::from(task)))
+    .execute(conn)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(())
+}
+
+pub(crate) async fn check_assignees(
+    conn: &mut PgConnection,
+    task_id: Uuid,
+    assignee_ids: &[String],
+) -> Result<(), AppError> {
+    let members = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM tasks_users WHERE task_id = $1 AND user_id = ANY($2)",
+    )
+    .bind(task_id)
+    .bind(assignee_ids)
+    .fetch_one(conn)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    if members != assignee_ids.len() as i64 {
+        return Err(AppError::validation(
+            "invalid_assignee",
+            "Assignees must be members of the task",
+            Some("assignee_ids".to_string()),
+        ));
+    }
+
+    Ok(())
+}
+
+pub(crate) async fn save_assignees(
+    conn: &mut PgConnection,
+    task_id: Uuid,
+    assignee_ids: &[String],
+    assigned_by: &str,
+) -> Result<(), AppError> {
+    sqlx::query("DELETE FROM task_assignees WHERE task_id = $1 AND user_id <> ALL($2)")
+        .bind(task_id)
+        .bind(assignee_ids)
+        .execute(&mut *conn)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    sqlx::query(
+        r#"
+            INSERT INTO task_assignees (task_id, user_id, assigned_by)
+            SELECT $1, assignee, $3 FROM UNNEST($2::text[]) AS assignee
+            ON CONFLICT (task_id, user_id) DO NOTHING
+            "#,
+    )
+    .bind(task_id)
+    .bind(assignee_ids)
+    .bind(assigned_by)
+    .execute(conn)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(())
+}
+
+pub(crate) async fn unassign_user_in(
+    conn: &mut PgConnection,
+    user_id: &str,
+    task_id: Option<Uuid>,
+    changed_by: &str,
+) -> Result<(), AppError> {
+    let task_ids = sqlx::query_scalar::<_, Uuid>(
+        r#"
+            SELECT t.task_id
+            FROM tasks t
+            JOIN task_assignees a ON a.task_id = t.task_id
+            WHERE a.user_id = $1 AND ($2::uuid IS NULL OR t.task_id = $2)
+            ORDER BY t.task_id
+            FOR UPDATE OF t
+            "#,
+    )
+    .bind(user_id)
+    .bind(task_id)
+    .fetch_all(&mut *conn)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    for task_id in task_ids {
+        let before = fetch_task(&mut *conn, user_id, task_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Task", "Task not found"))?;
+
+        sqlx::query("DELETE FROM task_assignees WHERE task_id = $1 AND user_id = $2")
+            .bind(task_id)
+            .bind(user_id)
+            .execute(&mut *conn)
+            .await
+            .map_err(|error| AppError::database(error.to_string()))?;
+        sqlx::query(
+            "UPDATE tasks SET version = version + 1, updated_at = NOW() WHERE task_id = $1",
+        )
+        .bind(task_id)
+        .execute(&mut *conn)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+        let after = fetch_task(&mut *conn, user_id, task_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Task", "Task not found"))?;
+        let changes = TaskSnapshot::from(&before).diff(&TaskSnapshot::from(&after));
+        insert_task_revision(&mut *conn, &after, changed_by, &changes).await?;
+    }
+
+    Ok(())
+}
+
+// Completes a group task once every member has, and reopens it when that stops
+// being true. `viewer` is any member the task can be read as; `changed_by` is
+// recorded on the revision.
+pub(crate) async fn sync_group_completion(
+    conn: &mut PgConnection,
+    viewer: &str,
+    task_id: Uuid,
+    changed_by: &str,
+) -> Result<(), AppError> {
+    let Some(before) = fetch_task(&mut *conn, viewer, task_id).await? else {
+        return Ok(());
+    };
+    let Some(status) = before
+        .group
+        .as_ref()
+        .and_then(|progress| group_status(before.status, progress))
+    else {
+        return Ok(());
+    };
+
+    sqlx::query(
+        r#"
+            UPDATE tasks
+            SET status = $2,
+                completed_at = CASE WHEN $2 = 'COMPLETED'
+                    THEN (SELECT MAX(completed_at) FROM tasks_users WHERE task_id = $1) END,
+                archived_at = CASE WHEN $2 = 'COMPLETED' THEN archived_at END,
+                version = version + 1,
+                updated_at = NOW()
+            WHERE task_id = $1
+            "#,
+    )
+    .bind(task_id)
+    .bind(status.as_str())
+    .execute(&mut *conn)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    let after = fetch_task(&mut *conn, viewer, task_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("Task", "Task not found"))?;
+    let changes = TaskSnapshot::from(&before).diff(&TaskSnapshot::from(&after));
+    insert_task_revision(conn, &after, changed_by, &changes).await
+}
+
 #[async_trait]
 impl TaskRepository for TaskRepositoryImpl {
     async fn create_task(&self, input: CreateTaskInput) -> Result<Task, AppError> {
         create_task::create_task(self, input).await
     }
 
-    async fn update_task(&self, input: UpdateTaskInput) -> Result<i64, AppError> {
+    async fn create_task_tree(&self, input: CreateTaskTreeInput) -> Result<Vec<Task>, AppError> {
+        create_task_tree::create_task_tree(self, input).await
+    }
+
+    async fn update_task(&self, input: UpdateTaskInput) -> Result<Task, AppError> {
         update_task::update_task(self, input).await
     }
 
-    async fn delete_task(&self, input: DeleteTaskInput) -> Result<i64, AppError> {
+    async fn delete_task(&self, input: DeleteTaskInput) -> Result<(), AppError> {
         delete_task::delete_task(self, input).await
     }
 
@@ -67,4 +442,81 @@ impl TaskRepository for TaskRepositoryImpl {
     async fn list_tasks(&self, input: ListTasksInput) -> Result<Vec<Task>, AppError> {
         list_tasks::list_tasks(self, input).await
     }
+
+    async fn move_task(&self, input: MoveTaskInput) -> Result<Task, AppError> {
+        move_task::move_task(self, input).await
+    }
+
+    async fn list_task_subtree(&self, input: ListTaskSubtreeInput) -> Result<Vec<Task>, AppError> {
+        list_task_subtree::list_task_subtree(self, input).await
+    }
+
+    async fn add_task_member(&self, input: AddTaskMemberInput) -> Result<(), AppError> {
+        add_task_member::add_task_member(self, input).await
+    }
+
+    async fn remove_task_member(&self, input: RemoveTaskMemberInput) -> Result<(), AppError> {
+        remove_task_member::remove_task_member(self, input).await
+    }
+
+    async fn list_task_revisions(
+        &self,
+        input: ListTaskRevisionsInput,
+    ) -> Result<Vec<TaskRevision>, AppError> {
+        list_task_revisions::list_task_revisions(self, input).await
+    }
+
+    async fn get_task_revision(
+        &self,
+        input: GetTaskRevisionInput,
+    ) -> Result<Option<TaskRevision>, AppError> {
+        get_task_revision::get_task_revision(self, input).await
+    }
+
+    async fn set_task_archived(&self, input: SetTaskArchivedInput) -> Result<Task, AppError> {
+        set_task_archived::set_task_archived(self, input).await
+    }
+
+    async fn set_member_completion(
+        &self,
+        input: SetMemberCompletionInput,
+    ) -> Result<Task, AppError> {
+        set_member_completion::set_member_completion(self, input).await
+    }
+
+    async fn archive_completed_tasks(
+        &self,
+        input: ArchiveCompletedTasksInput,
+    ) -> Result<u64, AppError> {
+        archive_completed_tasks::archive_completed_tasks(self, input).await
+    }
+
+    async fn task_stats(&self, input: TaskStatsQuery) -> Result<TaskStats, AppError> {
+        task_stats::task_stats(self, input).await
+    }
+
+    async fn list_task_changes(
+        &self,
+        input: ListTaskChangesInput,
+    ) -> Result<TaskChanges, AppError> {
+        list_task_changes::list_task_changes(self, input).await
+    }
+
+    async fn create_tasks(&self, input: CreateTasksInput) -> Result<Vec<Task>, AppError> {
+        create_tasks::create_tasks(self, input).await
+    }
+
+    async fn transfer_tasks(
+        &self,
+        input: TransferTaskLinksInput,
+    ) -> Result<TaskTransfer, AppError> {
+        transfer_tasks::transfer_tasks(self, input).await
+    }
+
+    async fn apply_sync_mutation(
+        &self,
+        input: ApplySyncMutationInput,
+    ) -> Result<Option<Task>, AppError> {
+        apply_sync_mutation::apply_sync_mutation(self, input).await
+    }
 }
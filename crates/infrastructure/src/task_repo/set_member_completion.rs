@@ -0,0 +1,80 @@
+use domain::error::AppError;
+use domain::task::entity::Task;
+use domain::task::inputs::SetMemberCompletionInput;
+
+use super::repository::{fetch_task, parse_task_id, sync_group_completion, TaskRepositoryImpl};
+
+pub async fn set_member_completion(
+    repo: &TaskRepositoryImpl,
+    input: SetMemberCompletionInput,
+) -> Result<Task, AppError> {
+    let task_id = parse_task_id(&input.task_id)?;
+
+    let mut tx = repo
+        .pool
+        .begin()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    // Locking the task serialises members finishing at the same moment, so
+    // exactly one of them sees the group become complete.
+    let is_group = sqlx::query_scalar::<_, bool>(
+        r#"
+            SELECT t.is_group
+            FROM tasks t
+            JOIN tasks_users tu ON t.task_id = tu.task_id
+            WHERE tu.user_id = $1 AND t.task_id = $2
+            FOR UPDATE OF t
+            "#,
+    )
+    .bind(&input.user_id)
+    .bind(task_id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?
+    .ok_or_else(|| AppError::not_found("Task", "Task not found"))?;
+
+    if !is_group {
+        return Err(AppError::conflict("Task", "Task is not a group task"));
+    }
+
+    let changed = sqlx::query(
+        r#"
+            UPDATE tasks_users
+            SET completed_at = CASE WHEN $3 THEN NOW() END
+            WHERE task_id = $1 AND user_id = $2 AND (completed_at IS NULL) = $3
+            "#,
+    )
+    .bind(task_id)
+    .bind(&input.user_id)
+    .bind(input.completed)
+    .execute(&mut *tx)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?
+    .rows_affected()
+        > 0;
+
+    // Member progress is part of the task representation even when the
+    // group as a whole does not change status.
+    if changed {
+        sqlx::query(
+            "UPDATE tasks SET version = version + 1, updated_at = NOW() WHERE task_id = $1",
+        )
+        .bind(task_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+    }
+
+    sync_group_completion(&mut tx, &input.user_id, task_id, &input.user_id).await?;
+
+    let task = fetch_task(&mut tx, &input.user_id, task_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("Task", "Task not found"))?;
+
+    tx.commit()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(task)
+}
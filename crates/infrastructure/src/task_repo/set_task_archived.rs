@@ -0,0 +1,49 @@
+use domain::error::AppError;
+use domain::task::entity::Task;
+use domain::task::inputs::SetTaskArchivedInput;
+
+use super::repository::{fetch_task, parse_task_id, TaskRepositoryImpl};
+
+pub async fn set_task_archived(
+    repo: &TaskRepositoryImpl,
+    input: SetTaskArchivedInput,
+) -> Result<Task, AppError> {
+    let task_id = parse_task_id(&input.task_id)?;
+
+    let mut tx = repo
+        .pool
+        .begin()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    let result = sqlx::query(
+        r#"
+            UPDATE tasks t
+            SET archived_at = CASE WHEN $3 THEN COALESCE(t.archived_at, NOW()) END,
+                updated_at = NOW(),
+                version = t.version + 1
+            FROM tasks_users tu
+            WHERE tu.task_id = t.task_id AND tu.user_id = $1 AND t.task_id = $2
+            "#,
+    )
+    .bind(&input.user_id)
+    .bind(task_id)
+    .bind(input.archived)
+    .execute(&mut *tx)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::not_found("Task", "Task not found"));
+    }
+
+    let task = fetch_task(&mut tx, &input.user_id, task_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("Task", "Task not found"))?;
+
+    tx.commit()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(task)
+}
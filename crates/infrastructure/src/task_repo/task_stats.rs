@@ -0,0 +1,171 @@
+use chrono::NaiveDate;
+use domain::error::AppError;
+use domain::task::inputs::TaskStatsQuery;
+use domain::task::stats::{StatusCount, TaskStats, ThroughputBucket};
+use domain::task::status::TaskStatus;
+
+use super::repository::TaskRepositoryImpl;
+
+// Range bounds are local midnights in the caller's timezone; Postgres resolves
+// the DST offset for each boundary.
+const BOUNDS: &str = r#"
+    bounds AS (
+        SELECT
+            ($2::date)::timestamp AT TIME ZONE $4 AS start_at,
+            ($3::date + 1)::timestamp AT TIME ZONE $4 AS end_at
+    ),
+    mine AS (
+        SELECT t.status, t.created_at, t.completed_at, t.due_at
+        FROM tasks t
+        JOIN tasks_users tu ON tu.task_id = t.task_id
+        WHERE tu.user_id = $1
+    )
+"#;
+
+#[derive(sqlx::FromRow)]
+struct StatusCountRow {
+    status: String,
+    count: i64,
+}
+
+#[derive(sqlx::FromRow)]
+struct ThroughputRow {
+    start: NaiveDate,
+    created: i64,
+    completed: i64,
+}
+
+#[derive(sqlx::FromRow)]
+struct SummaryRow {
+    created: i64,
+    completed: i64,
+    average_lead_time_seconds: Option<i64>,
+    overdue: i64,
+    completed_late: i64,
+}
+
+pub async fn task_stats(
+    repo: &TaskRepositoryImpl,
+    input: TaskStatsQuery,
+) -> Result<TaskStats, AppError> {
+    let by_status = sqlx::query_as::<_, StatusCountRow>(&format!(
+        r#"
+            WITH {BOUNDS}
+            SELECT m.status, COUNT(*) AS count
+            FROM mine m, bounds b
+            WHERE m.created_at >= b.start_at AND m.created_at < b.end_at
+            GROUP BY m.status
+            ORDER BY m.status
+            "#
+    ))
+    .bind(&input.user_id)
+    .bind(input.from)
+    .bind(input.to)
+    .bind(&input.timezone)
+    .fetch_all(&repo.pool)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    let throughput = sqlx::query_as::<_, ThroughputRow>(&format!(
+        r#"
+            WITH {BOUNDS},
+            created AS (
+                SELECT date_trunc($5, m.created_at AT TIME ZONE $4)::date AS bucket, COUNT(*) AS n
+                FROM mine m, bounds b
+                WHERE m.created_at >= b.start_at AND m.created_at < b.end_at
+                GROUP BY 1
+            ),
+            completed AS (
+                SELECT date_trunc($5, m.completed_at AT TIME ZONE $4)::date AS bucket, COUNT(*) AS n
+                FROM mine m, bounds b
+                WHERE m.completed_at >= b.start_at AND m.completed_at < b.end_at
+                GROUP BY 1
+            )
+            SELECT
+                s.bucket::date AS start,
+                COALESCE(c.n, 0) AS created,
+                COALESCE(d.n, 0) AS completed
+            FROM generate_series(
+                date_trunc($5, $2::date::timestamp),
+                $3::date::timestamp,
+                ('1 ' || $5)::interval
+            ) AS s(bucket)
+            LEFT JOIN created c ON c.bucket = s.bucket::date
+            LEFT JOIN completed d ON d.bucket = s.bucket::date
+            ORDER BY 1
+            "#
+    ))
+    .bind(&input.user_id)
+    .bind(input.from)
+    .bind(input.to)
+    .bind(&input.timezone)
+    .bind(input.bucket.as_str())
+    .fetch_all(&repo.pool)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    // Overdue counts open tasks whose due date fell inside the range and has
+    // already passed.
+    let summary = sqlx::query_as::<_, SummaryRow>(&format!(
+        r#"
+            WITH {BOUNDS}
+            SELECT
+                COUNT(*) FILTER (
+                    WHERE m.created_at >= b.start_at AND m.created_at < b.end_at
+                ) AS created,
+                COUNT(*) FILTER (
+                    WHERE m.completed_at >= b.start_at AND m.completed_at < b.end_at
+                ) AS completed,
+                (AVG(EXTRACT(EPOCH FROM m.completed_at - m.created_at)) FILTER (
+                    WHERE m.completed_at >= b.start_at AND m.completed_at < b.end_at
+                ))::bigint AS average_lead_time_seconds,
+                COUNT(*) FILTER (
+                    WHERE m.completed_at IS NULL
+                      AND m.due_at >= b.start_at
+                      AND m.due_at < LEAST(b.end_at, $5)
+                ) AS overdue,
+                COUNT(*) FILTER (
+                    WHERE m.completed_at >= b.start_at AND m.completed_at < b.end_at
+                      AND m.completed_at > m.due_at
+                ) AS completed_late
+            FROM mine m, bounds b
+            "#
+    ))
+    .bind(&input.user_id)
+    .bind(input.from)
+    .bind(input.to)
+    .bind(&input.timezone)
+    .bind(input.now)
+    .fetch_one(&repo.pool)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(TaskStats {
+        from: input.from,
+        to: input.to,
+        timezone: input.timezone,
+        bucket: input.bucket,
+        by_status: by_status
+            .into_iter()
+            .filter_map(|row| {
+                TaskStatus::parse(&row.status).map(|status| StatusCount {
+                    status,
+                    count: row.count,
+                })
+            })
+            .collect(),
+        throughput: throughput
+            .into_iter()
+            .map(|row| ThroughputBucket {
+                start: row.start,
+                created: row.created,
+                completed: row.completed,
+            })
+            .collect(),
+        created: summary.created,
+        completed: summary.completed,
+        average_lead_time_seconds: summary.average_lead_time_seconds,
+        overdue: summary.overdue,
+        completed_late: summary.completed_late,
+    })
+}
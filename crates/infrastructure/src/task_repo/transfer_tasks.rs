@@ -0,0 +1,156 @@
+use chrono::{DateTime, Utc};
+use domain::error::AppError;
+use domain::task::inputs::TransferTaskLinksInput;
+use domain::task::revision::TaskSnapshot;
+use domain::task::transfer::TaskTransfer;
+use uuid::Uuid;
+
+use super::ranking::{append_rank, lock_ordering};
+use super::repository::{
+    fetch_task, insert_task_revision, parse_task_id, sync_group_completion, TaskRepositoryImpl,
+};
+
+// Each task keeps its other members; only the departing user's link moves.
+// Assignments move with the link, and a receiver who is already a member
+// simply keeps their own link.
+pub async fn transfer_tasks(
+    repo: &TaskRepositoryImpl,
+    input: TransferTaskLinksInput,
+) -> Result<TaskTransfer, AppError> {
+    let requested = input
+        .task_ids
+        .iter()
+        .map(|task_id| parse_task_id(task_id))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut tx = repo
+        .pool
+        .begin()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    // Holding the receiver's row keeps them from being deleted mid-transfer.
+    sqlx::query_scalar::<_, String>("SELECT user_id FROM users WHERE user_id = $1 FOR SHARE")
+        .bind(&input.to_user_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?
+        .ok_or_else(|| AppError::not_found("User", "User not found"))?;
+
+    let task_ids = sqlx::query_scalar::<_, Uuid>(
+        r#"
+            SELECT t.task_id
+            FROM tasks t
+            JOIN tasks_users tu ON t.task_id = tu.task_id
+            WHERE tu.user_id = $1 AND t.task_id = ANY($2)
+            ORDER BY t.task_id
+            FOR UPDATE OF t
+            "#,
+    )
+    .bind(&input.from_user_id)
+    .bind(&requested)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    // A single named task that the user does not hold is an error; a filter
+    // that matched nothing is not.
+    if requested.len() == 1 && task_ids.is_empty() {
+        return Err(AppError::not_found("Task", "Task not found"));
+    }
+
+    lock_ordering(&mut tx, &input.to_user_id).await?;
+
+    for &task_id in &task_ids {
+        let before = fetch_task(&mut tx, &input.from_user_id, task_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Task", "Task not found"))?;
+
+        // Deleting the link cascades to the user's assignment on the task.
+        sqlx::query("DELETE FROM tasks_users WHERE task_id = $1 AND user_id = $2")
+            .bind(task_id)
+            .bind(&input.from_user_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|error| AppError::database(error.to_string()))?;
+
+        let rank = append_rank(&mut tx, &input.to_user_id).await?;
+        sqlx::query(
+            r#"
+                INSERT INTO tasks_users (task_id, user_id, rank)
+                VALUES ($1, $2, $3)
+                ON CONFLICT (task_id, user_id) DO NOTHING
+                "#,
+        )
+        .bind(task_id)
+        .bind(&input.to_user_id)
+        .bind(&rank)
+        .execute(&mut *tx)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+        if before.assignee_ids.contains(&input.from_user_id) {
+            sqlx::query(
+                r#"
+                    INSERT INTO task_assignees (task_id, user_id, assigned_by)
+                    VALUES ($1, $2, $3)
+                    ON CONFLICT (task_id, user_id) DO NOTHING
+                    "#,
+            )
+            .bind(task_id)
+            .bind(&input.to_user_id)
+            .bind(&input.actor_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|error| AppError::database(error.to_string()))?;
+        }
+
+        let after = fetch_task(&mut tx, &input.to_user_id, task_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Task", "Task not found"))?;
+        let changes = TaskSnapshot::from(&before).diff(&TaskSnapshot::from(&after));
+        if !changes.is_empty() {
+            sqlx::query(
+                "UPDATE tasks SET version = version + 1, updated_at = NOW() WHERE task_id = $1",
+            )
+            .bind(task_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|error| AppError::database(error.to_string()))?;
+            let after = fetch_task(&mut tx, &input.to_user_id, task_id)
+                .await?
+                .ok_or_else(|| AppError::not_found("Task", "Task not found"))?;
+            insert_task_revision(&mut tx, &after, &input.actor_id, &changes).await?;
+        }
+
+        sync_group_completion(&mut tx, &input.to_user_id, task_id, &input.actor_id).await?;
+    }
+
+    let (transfer_id, created_at) = sqlx::query_as::<_, (Uuid, DateTime<Utc>)>(
+        r#"
+            INSERT INTO task_transfers (actor_id, from_user_id, to_user_id, task_ids)
+            VALUES ($1, $2, $3, $4)
+            RETURNING transfer_id, created_at
+            "#,
+    )
+    .bind(&input.actor_id)
+    .bind(&input.from_user_id)
+    .bind(&input.to_user_id)
+    .bind(&task_ids)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    tx.commit()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(TaskTransfer {
+        transfer_id: transfer_id.to_string(),
+        actor_id: input.actor_id,
+        from_user_id: input.from_user_id,
+        to_user_id: input.to_user_id,
+        task_ids: task_ids.iter().map(Uuid::to_string).collect(),
+        created_at,
+    })
+}
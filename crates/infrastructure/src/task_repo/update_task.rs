@@ -1,42 +1,195 @@
+use chrono::Utc;
 use domain::error::AppError;
+use domain::task::entity::Task;
 use domain::task::inputs::UpdateTaskInput;
-use sqlx::{Postgres, QueryBuilder};
-use uuid::Uuid;
+use domain::task::revision::TaskSnapshot;
+use domain::task::status::resolve_completion;
+use sqlx::{PgConnection, Postgres, QueryBuilder};
 
-use super::repository::TaskRepositoryImpl;
+use super::repository::{
+    check_assignees, check_parent, fetch_task, insert_task_revision, parse_task_id, save_assignees,
+    sync_group_completion, TaskRepositoryImpl, TaskRow, TASK_COLUMNS,
+};
 
 pub async fn update_task(
     repo: &TaskRepositoryImpl,
     input: UpdateTaskInput,
-) -> Result<i64, AppError> {
-    let task_id = Uuid::parse_str(&input.task_id)
-        .map_err(|_| AppError::validation("invalid_task_id", "Invalid task id", None))?;
+) -> Result<Task, AppError> {
+    let mut tx = repo
+        .pool
+        .begin()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    let task = update_task_in(&mut tx, input).await?;
+
+    tx.commit()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(task)
+}
+
+pub(crate) async fn update_task_in(
+    conn: &mut PgConnection,
+    input: UpdateTaskInput,
+) -> Result<Task, AppError> {
+    let task_id = parse_task_id(&input.task_id)?;
+
+    let sql = format!(
+        r#"
+            SELECT {TASK_COLUMNS}
+            FROM tasks t
+            JOIN tasks_users tu ON t.task_id = tu.task_id
+            WHERE tu.user_id = $1 AND t.task_id = $2
+            FOR UPDATE OF t
+            "#
+    );
+    let current = sqlx::query_as::<_, TaskRow>(&sql)
+        .bind(&input.user_id)
+        .bind(task_id)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    let current = current
+        .ok_or_else(|| AppError::not_found("Task", "Task not found"))?
+        .into_task(input.user_id.clone());
+    if current.version != input.version {
+        return Err(AppError::version_conflict(
+            "Task",
+            i64::from(current.version),
+        ));
+    }
+
+    let parent_task_id = match &input.parent_task_id {
+        Some(Some(parent_task_id)) => {
+            let parent_task_id = parse_task_id(parent_task_id)?;
+            check_parent(&mut *conn, &input.user_id, Some(task_id), parent_task_id).await?;
+            Some(Some(parent_task_id))
+        }
+        Some(None) => Some(None),
+        None => None,
+    };
+
+    if let Some(assignee_ids) = &input.assignee_ids {
+        check_assignees(&mut *conn, task_id, assignee_ids).await?;
+        save_assignees(&mut *conn, task_id, assignee_ids, &input.user_id).await?;
+    }
 
-    let mut builder: QueryBuilder<Postgres> = QueryBuilder::new("UPDATE tasks SET ");
+    let mut builder: QueryBuilder<Postgres> = QueryBuilder::new("UPDATE tasks AS t SET ");
     let mut separated = builder.separated(", ");
 
     if let Some(content) = &input.content {
-        separated.push("content = ").push_bind(content);
+        separated.push("content = ").push_bind_unseparated(content);
     }
-    if let Some(completed_at) = &input.completed_at {
-        separated.push("completed_at = ").push_bind(completed_at);
+    if input.status.is_some() || input.completed_at.is_some() {
+        let (status, completed_at) = resolve_completion(
+            current.status,
+            current.completed_at,
+            input.status,
+            input.completed_at,
+            Utc::now(),
+        )?;
+        separated
+            .push("status = ")
+            .push_bind_unseparated(status.as_str());
+        separated
+            .push("completed_at = ")
+            .push_bind_unseparated(completed_at);
+        // Reopening an archived task brings it back into the active list.
+        if completed_at.is_none() {
+            separated.push("archived_at = NULL");
+        }
+    }
+
+    if let Some(parent_task_id) = parent_task_id {
+        separated
+            .push("parent_task_id = ")
+            .push_bind_unseparated(parent_task_id);
+    }
+    if let Some(estimate_minutes) = input.estimate_minutes {
+        separated
+            .push("estimate_minutes = ")
+            .push_bind_unseparated(estimate_minutes);
+    }
+    if let Some(remaining_minutes) = input.remaining_minutes {
+        separated
+            .push("remaining_minutes = ")
+            .push_bind_unseparated(remaining_minutes);
+    }
+    if let Some(story_points) = input.story_points {
+        separated
+            .push("story_points = ")
+            .push_bind_unseparated(story_points);
+    }
+    if let Some(due_at) = input.due_at {
+        separated.push("due_at = ").push_bind_unseparated(due_at);
+    }
+    if let Some(labels) = &input.labels {
+        separated.push("labels = ").push_bind_unseparated(labels);
+    }
+    if let Some(priority) = input.priority {
+        separated
+            .push("priority = ")
+            .push_bind_unseparated(priority.map(|priority| priority.as_str()));
+    }
+    if let Some(recurrence) = &input.recurrence {
+        separated
+            .push("recurrence = ")
+            .push_bind_unseparated(recurrence);
+    }
+    if let Some(snoozed_until) = input.snoozed_until {
+        separated
+            .push("snoozed_until = ")
+            .push_bind_unseparated(snoozed_until);
+    }
+    if let Some(group) = input.group {
+        separated.push("is_group = ").push_bind_unseparated(group);
+    }
+    if let Some(waiting_on) = &input.waiting_on {
+        separated
+            .push("waiting_on = ")
+            .push_bind_unseparated(waiting_on);
     }
 
     separated.push("version = version + 1");
     separated.push("updated_at = NOW()");
 
-    builder.push(" FROM tasks_users tu WHERE tasks.task_id = tu.task_id AND tu.user_id = ");
+    builder.push(" FROM tasks_users tu WHERE tu.task_id = t.task_id AND tu.user_id = ");
     builder.push_bind(&input.user_id);
-    builder.push(" AND tasks.task_id = ");
+    builder.push(" AND t.task_id = ");
     builder.push_bind(task_id);
-    builder.push(" AND tasks.version = ");
-    builder.push_bind(input.version);
+    builder.push(" RETURNING ");
+    builder.push(TASK_COLUMNS);
 
-    let result = builder
-        .build()
-        .execute(&repo.pool)
+    let updated = builder
+        .build_query_as::<TaskRow>()
+        .fetch_one(&mut *conn)
         .await
         .map_err(|error| AppError::database(error.to_string()))?;
 
-    Ok(result.rows_affected() as i64)
+    let after = updated.into_task(input.user_id);
+    let changes = TaskSnapshot::from(&current).diff(&TaskSnapshot::from(&after));
+    insert_task_revision(&mut *conn, &after, &after.user_id, &changes).await?;
+
+    if current.group.is_some() == after.group.is_some() {
+        return Ok(after);
+    }
+
+    // Leaving group mode forgets who had finished, so switching it back on
+    // starts the group over.
+    if after.group.is_none() {
+        sqlx::query("UPDATE tasks_users SET completed_at = NULL WHERE task_id = $1")
+            .bind(task_id)
+            .execute(&mut *conn)
+            .await
+            .map_err(|error| AppError::database(error.to_string()))?;
+        return Ok(after);
+    }
+
+    sync_group_completion(&mut *conn, &after.user_id, task_id, &after.user_id).await?;
+    fetch_task(conn, &after.user_id, task_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("Task", "Task not found"))
 }
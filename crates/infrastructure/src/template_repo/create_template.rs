@@ -0,0 +1,25 @@
+use domain::error::AppError;
+use domain::template::entity::TaskTemplate;
+use domain::template::inputs::CreateTemplateInput;
+use sqlx::types::Json;
+
+use super::repository::{TemplateRepositoryImpl, TemplateRow, TEMPLATE_COLUMNS};
+
+pub async fn create_template(
+    repo: &TemplateRepositoryImpl,
+    input: CreateTemplateInput,
+) -> Result<TaskTemplate, AppError> {
+    let sql = format!(
+        "INSERT INTO task_templates (user_id, name, tasks) VALUES ($1, $2, $3) \
+         RETURNING {TEMPLATE_COLUMNS}"
+    );
+    let row = sqlx::query_as::<_, TemplateRow>(&sql)
+        .bind(&input.user_id)
+        .bind(input.name.trim())
+        .bind(Json(&input.tasks))
+        .fetch_one(&repo.pool)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(row.into_template())
+}
@@ -0,0 +1,23 @@
+use domain::error::AppError;
+use domain::template::inputs::DeleteTemplateInput;
+
+use super::repository::{parse_template_id, TemplateRepositoryImpl};
+
+pub async fn delete_template(
+    repo: &TemplateRepositoryImpl,
+    input: DeleteTemplateInput,
+) -> Result<(), AppError> {
+    let template_id = parse_template_id(&input.template_id)?;
+
+    let deleted = sqlx::query("DELETE FROM task_templates WHERE user_id = $1 AND template_id = $2")
+        .bind(&input.user_id)
+        .bind(template_id)
+        .execute(&repo.pool)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+    if deleted.rows_affected() == 0 {
+        return Err(AppError::not_found("Template", "Template not found"));
+    }
+
+    Ok(())
+}
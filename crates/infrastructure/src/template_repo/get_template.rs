@@ -0,0 +1,24 @@
+use domain::error::AppError;
+use domain::template::entity::TaskTemplate;
+use domain::template::inputs::GetTemplateInput;
+
+use super::repository::{parse_template_id, TemplateRepositoryImpl, TemplateRow, TEMPLATE_COLUMNS};
+
+pub async fn get_template(
+    repo: &TemplateRepositoryImpl,
+    input: GetTemplateInput,
+) -> Result<Option<TaskTemplate>, AppError> {
+    let template_id = parse_template_id(&input.template_id)?;
+
+    let sql = format!(
+        "SELECT {TEMPLATE_COLUMNS} FROM task_templates WHERE user_id = $1 AND template_id = $2"
+    );
+    let row = sqlx::query_as::<_, TemplateRow>(&sql)
+        .bind(&input.user_id)
+        .bind(template_id)
+        .fetch_optional(&repo.pool)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(row.map(TemplateRow::into_template))
+}
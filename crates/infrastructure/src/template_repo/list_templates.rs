@@ -0,0 +1,21 @@
+use domain::error::AppError;
+use domain::template::entity::TaskTemplate;
+use domain::template::inputs::ListTemplatesInput;
+
+use super::repository::{TemplateRepositoryImpl, TemplateRow, TEMPLATE_COLUMNS};
+
+pub async fn list_templates(
+    repo: &TemplateRepositoryImpl,
+    input: ListTemplatesInput,
+) -> Result<Vec<TaskTemplate>, AppError> {
+    let sql = format!(
+        "SELECT {TEMPLATE_COLUMNS} FROM task_templates WHERE user_id = $1 ORDER BY name, created_at"
+    );
+    let rows = sqlx::query_as::<_, TemplateRow>(&sql)
+        .bind(&input.user_id)
+        .fetch_all(&repo.pool)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(rows.into_iter().map(TemplateRow::into_template).collect())
+}
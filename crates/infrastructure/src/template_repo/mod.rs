@@ -0,0 +1,6 @@
+pub mod create_template;
+pub mod delete_template;
+pub mod get_template;
+pub mod list_templates;
+pub mod repository;
+pub mod update_template;
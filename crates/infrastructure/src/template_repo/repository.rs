@@ -0,0 +1,85 @@
+use application::template_service::repository::TemplateRepository;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::error::AppError;
+use domain::template::entity::{TaskTemplate, TemplateTask};
+use domain::template::inputs::{
+    CreateTemplateInput, DeleteTemplateInput, GetTemplateInput, ListTemplatesInput,
+    UpdateTemplateInput,
+};
+use sqlx::types::Json;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::{create_template, delete_template, get_template, list_templates, update_template};
+
+pub(crate) const TEMPLATE_COLUMNS: &str =
+    "template_id, user_id, name, tasks, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct TemplateRepositoryImpl {
+    pub(crate) pool: PgPool,
+}
+
+impl TemplateRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+pub(crate) struct TemplateRow {
+    pub template_id: Uuid,
+    pub user_id: String,
+    pub name: String,
+    pub tasks: Json<Vec<TemplateTask>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TemplateRow {
+    pub(crate) fn into_template(self) -> TaskTemplate {
+        TaskTemplate {
+            template_id: self.template_id.to_string(),
+            user_id: self.user_id,
+            name: self.name,
+            tasks: self.tasks.0,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+pub(crate) fn parse_template_id(template_id: &str) -> Result<Uuid, AppError> {
+    Uuid::parse_str(template_id)
+        .map_err(|_| AppError::validation("invalid_template_id", "Invalid template id", None))
+}
+
+#[async_trait]
+impl TemplateRepository for TemplateRepositoryImpl {
+    async fn create_template(&self, input: CreateTemplateInput) -> Result<TaskTemplate, AppError> {
+        create_template::create_template(self, input).await
+    }
+
+    async fn update_template(&self, input: UpdateTemplateInput) -> Result<TaskTemplate, AppError> {
+        update_template::update_template(self, input).await
+    }
+
+    async fn delete_template(&self, input: DeleteTemplateInput) -> Result<(), AppError> {
+        delete_template::delete_template(self, input).await
+    }
+
+    async fn get_template(
+        &self,
+        input: GetTemplateInput,
+    ) -> Result<Option<TaskTemplate>, AppError> {
+        get_template::get_template(self, input).await
+    }
+
+    async fn list_templates(
+        &self,
+        input: ListTemplatesInput,
+    ) -> Result<Vec<TaskTemplate>, AppError> {
+        list_templates::list_templates(self, input).await
+    }
+}
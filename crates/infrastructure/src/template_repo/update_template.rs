@@ -0,0 +1,33 @@
+use domain::error::AppError;
+use domain::template::entity::TaskTemplate;
+use domain::template::inputs::UpdateTemplateInput;
+use sqlx::types::Json;
+
+use super::repository::{parse_template_id, TemplateRepositoryImpl, TemplateRow, TEMPLATE_COLUMNS};
+
+pub async fn update_template(
+    repo: &TemplateRepositoryImpl,
+    input: UpdateTemplateInput,
+) -> Result<TaskTemplate, AppError> {
+    let template_id = parse_template_id(&input.template_id)?;
+
+    let sql = format!(
+        r#"
+            UPDATE task_templates
+            SET name = $3, tasks = $4, updated_at = NOW()
+            WHERE user_id = $1 AND template_id = $2
+            RETURNING {TEMPLATE_COLUMNS}
+            "#
+    );
+    let row = sqlx::query_as::<_, TemplateRow>(&sql)
+        .bind(&input.user_id)
+        .bind(template_id)
+        .bind(input.name.trim())
+        .bind(Json(&input.tasks))
+        .fetch_optional(&repo.pool)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?
+        .ok_or_else(|| AppError::not_found("Template", "Template not found"))?;
+
+    Ok(row.into_template())
+}
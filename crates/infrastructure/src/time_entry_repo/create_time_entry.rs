@@ -0,0 +1,50 @@
+use domain::error::AppError;
+use domain::time_entry::entity::TimeEntry;
+use domain::time_entry::inputs::CreateTimeEntryInput;
+
+use super::repository::{
+    add_tracked_seconds, ensure_task_access, TimeEntryRepositoryImpl, TimeEntryRow,
+    TIME_ENTRY_COLUMNS,
+};
+use crate::task_repo::repository::parse_task_id;
+
+pub async fn create_time_entry(
+    repo: &TimeEntryRepositoryImpl,
+    input: CreateTimeEntryInput,
+) -> Result<TimeEntry, AppError> {
+    let task_id = parse_task_id(&input.task_id)?;
+
+    let mut tx = repo
+        .pool
+        .begin()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    ensure_task_access(&mut tx, &input.user_id, task_id).await?;
+
+    let sql = format!(
+        r#"
+            INSERT INTO time_entries AS e (task_id, user_id, started_at, ended_at, note)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING {TIME_ENTRY_COLUMNS}
+            "#
+    );
+    let entry = sqlx::query_as::<_, TimeEntryRow>(&sql)
+        .bind(task_id)
+        .bind(&input.user_id)
+        .bind(input.started_at)
+        .bind(input.ended_at)
+        .bind(&input.note)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?
+        .into_time_entry();
+
+    add_tracked_seconds(&mut tx, &entry, 1).await?;
+
+    tx.commit()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(entry)
+}
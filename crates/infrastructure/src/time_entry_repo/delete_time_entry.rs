@@ -0,0 +1,45 @@
+use domain::error::AppError;
+use domain::time_entry::inputs::DeleteTimeEntryInput;
+use uuid::Uuid;
+
+use super::repository::{
+    add_tracked_seconds, TimeEntryRepositoryImpl, TimeEntryRow, TIME_ENTRY_COLUMNS,
+};
+
+pub async fn delete_time_entry(
+    repo: &TimeEntryRepositoryImpl,
+    input: DeleteTimeEntryInput,
+) -> Result<(), AppError> {
+    let entry_id = Uuid::parse_str(&input.entry_id)
+        .map_err(|_| AppError::validation("invalid_entry_id", "Invalid time entry id", None))?;
+
+    let mut tx = repo
+        .pool
+        .begin()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    let sql = format!(
+        r#"
+            DELETE FROM time_entries AS e
+            WHERE e.entry_id = $1 AND e.user_id = $2
+            RETURNING {TIME_ENTRY_COLUMNS}
+            "#
+    );
+    let entry = sqlx::query_as::<_, TimeEntryRow>(&sql)
+        .bind(entry_id)
+        .bind(&input.user_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?
+        .ok_or_else(|| AppError::not_found("TimeEntry", "Time entry not found"))?
+        .into_time_entry();
+
+    add_tracked_seconds(&mut tx, &entry, -1).await?;
+
+    tx.commit()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use domain::error::AppError;
+use domain::time_entry::entity::TimeEntry;
+use domain::time_entry::inputs::ListTimeEntriesInput;
+
+use super::repository::{
+    ensure_task_access, TimeEntryRepositoryImpl, TimeEntryRow, TIME_ENTRY_COLUMNS,
+};
+use crate::task_repo::repository::parse_task_id;
+
+pub async fn list_time_entries(
+    repo: &TimeEntryRepositoryImpl,
+    input: ListTimeEntriesInput,
+) -> Result<Vec<TimeEntry>, AppError> {
+    let task_id = parse_task_id(&input.task_id)?;
+
+    let mut conn = repo
+        .pool
+        .acquire()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    ensure_task_access(&mut conn, &input.user_id, task_id).await?;
+
+    let sql = format!(
+        r#"
+            SELECT {TIME_ENTRY_COLUMNS}
+            FROM time_entries e
+            WHERE e.task_id = $1
+            ORDER BY e.started_at DESC
+            "#
+    );
+    let rows = sqlx::query_as::<_, TimeEntryRow>(&sql)
+        .bind(task_id)
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(rows
+        .into_iter()
+        .map(TimeEntryRow::into_time_entry)
+        .collect())
+}
@@ -0,0 +1,7 @@
+pub mod create_time_entry;
+pub mod delete_time_entry;
+pub mod list_time_entries;
+pub mod repository;
+pub mod start_timer;
+pub mod stop_timer;
+pub mod time_report;
@@ -0,0 +1,133 @@
+use application::time_entry_service::repository::TimeEntryRepository;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::error::AppError;
+use domain::time_entry::entity::TimeEntry;
+use domain::time_entry::inputs::{
+    CreateTimeEntryInput, DeleteTimeEntryInput, ListTimeEntriesInput, StartTimerInput,
+    StopTimerInput, TimeReportQuery,
+};
+use domain::time_entry::report::TimeReportRow;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use super::{
+    create_time_entry, delete_time_entry, list_time_entries, start_timer, stop_timer, time_report,
+};
+
+pub(crate) const TIME_ENTRY_COLUMNS: &str =
+    "e.entry_id, e.task_id, e.user_id, e.started_at, e.ended_at, e.note, e.created_at";
+
+#[derive(Clone)]
+pub struct TimeEntryRepositoryImpl {
+    pub(crate) pool: PgPool,
+}
+
+impl TimeEntryRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+pub(crate) struct TimeEntryRow {
+    pub entry_id: Uuid,
+    pub task_id: Uuid,
+    pub user_id: String,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TimeEntryRow {
+    pub(crate) fn into_time_entry(self) -> TimeEntry {
+        TimeEntry {
+            entry_id: self.entry_id.to_string(),
+            task_id: self.task_id.to_string(),
+            user_id: self.user_id,
+            started_at: self.started_at,
+            ended_at: self.ended_at,
+            note: self.note,
+            created_at: self.created_at,
+        }
+    }
+}
+
+pub(crate) fn map_db_error(error: sqlx::Error) -> AppError {
+    match &error {
+        sqlx::Error::Database(db_error) if db_error.code().as_deref() == Some("23505") => {
+            AppError::conflict("TimeEntry", "A timer is already running")
+        }
+        _ => AppError::database(error.to_string()),
+    }
+}
+
+pub(crate) async fn add_tracked_seconds(
+    conn: &mut PgConnection,
+    entry: &TimeEntry,
+    sign: i64,
+) -> Result<(), AppError> {
+    let Some(seconds) = entry.duration_seconds() else {
+        return Ok(());
+    };
+    let task_id =
+        Uuid::parse_str(&entry.task_id).map_err(|error| AppError::database(error.to_string()))?;
+
+    sqlx::query(
+        "UPDATE tasks SET tracked_seconds = tracked_seconds + $2, version = version + 1, updated_at = NOW() WHERE task_id = $1",
+    )
+    .bind(task_id)
+        .bind(seconds * sign)
+        .execute(conn)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(())
+}
+
+pub(crate) async fn ensure_task_access(
+    conn: &mut PgConnection,
+    user_id: &str,
+    task_id: Uuid,
+) -> Result<(), AppError> {
+    sqlx::query_scalar::<_, i32>("SELECT 1 FROM tasks_users WHERE user_id = $1 AND task_id = $2")
+        .bind(user_id)
+        .bind(task_id)
+        .fetch_optional(conn)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?
+        .ok_or_else(|| AppError::not_found("Task", "Task not found"))?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl TimeEntryRepository for TimeEntryRepositoryImpl {
+    async fn start_timer(&self, input: StartTimerInput) -> Result<TimeEntry, AppError> {
+        start_timer::start_timer(self, input).await
+    }
+
+    async fn stop_timer(&self, input: StopTimerInput) -> Result<TimeEntry, AppError> {
+        stop_timer::stop_timer(self, input).await
+    }
+
+    async fn create_time_entry(&self, input: CreateTimeEntryInput) -> Result<TimeEntry, AppError> {
+        create_time_entry::create_time_entry(self, input).await
+    }
+
+    async fn delete_time_entry(&self, input: DeleteTimeEntryInput) -> Result<(), AppError> {
+        delete_time_entry::delete_time_entry(self, input).await
+    }
+
+    async fn list_time_entries(
+        &self,
+        input: ListTimeEntriesInput,
+    ) -> Result<Vec<TimeEntry>, AppError> {
+        list_time_entries::list_time_entries(self, input).await
+    }
+
+    async fn time_report(&self, input: TimeReportQuery) -> Result<Vec<TimeReportRow>, AppError> {
+        time_report::time_report(self, input).await
+    }
+}
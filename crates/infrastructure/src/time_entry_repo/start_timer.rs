@@ -0,0 +1,40 @@
+use domain::error::AppError;
+use domain::time_entry::entity::TimeEntry;
+use domain::time_entry::inputs::StartTimerInput;
+
+use super::repository::{
+    ensure_task_access, map_db_error, TimeEntryRepositoryImpl, TimeEntryRow, TIME_ENTRY_COLUMNS,
+};
+use crate::task_repo::repository::parse_task_id;
+
+pub async fn start_timer(
+    repo: &TimeEntryRepositoryImpl,
+    input: StartTimerInput,
+) -> Result<TimeEntry, AppError> {
+    let task_id = parse_task_id(&input.task_id)?;
+
+    let mut conn = repo
+        .pool
+        .acquire()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    ensure_task_access(&mut conn, &input.user_id, task_id).await?;
+
+    let sql = format!(
+        r#"
+            INSERT INTO time_entries AS e (task_id, user_id, started_at, note)
+            VALUES ($1, $2, NOW(), $3)
+            RETURNING {TIME_ENTRY_COLUMNS}
+            "#
+    );
+    let row = sqlx::query_as::<_, TimeEntryRow>(&sql)
+        .bind(task_id)
+        .bind(&input.user_id)
+        .bind(&input.note)
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(map_db_error)?;
+
+    Ok(row.into_time_entry())
+}
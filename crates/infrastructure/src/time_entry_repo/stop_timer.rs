@@ -0,0 +1,42 @@
+use domain::error::AppError;
+use domain::time_entry::entity::TimeEntry;
+use domain::time_entry::inputs::StopTimerInput;
+
+use super::repository::{
+    add_tracked_seconds, TimeEntryRepositoryImpl, TimeEntryRow, TIME_ENTRY_COLUMNS,
+};
+
+pub async fn stop_timer(
+    repo: &TimeEntryRepositoryImpl,
+    input: StopTimerInput,
+) -> Result<TimeEntry, AppError> {
+    let mut tx = repo
+        .pool
+        .begin()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    let sql = format!(
+        r#"
+            UPDATE time_entries AS e
+            SET ended_at = NOW()
+            WHERE e.user_id = $1 AND e.ended_at IS NULL
+            RETURNING {TIME_ENTRY_COLUMNS}
+            "#
+    );
+    let entry = sqlx::query_as::<_, TimeEntryRow>(&sql)
+        .bind(&input.user_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?
+        .ok_or_else(|| AppError::not_found("TimeEntry", "No timer is running"))?
+        .into_time_entry();
+
+    add_tracked_seconds(&mut tx, &entry, 1).await?;
+
+    tx.commit()
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(entry)
+}
@@ -0,0 +1,57 @@
+use chrono::NaiveDate;
+use domain::error::AppError;
+use domain::time_entry::inputs::TimeReportQuery;
+use domain::time_entry::report::TimeReportRow;
+use uuid::Uuid;
+
+use super::repository::TimeEntryRepositoryImpl;
+
+#[derive(sqlx::FromRow)]
+struct TimeReportDbRow {
+    day: NaiveDate,
+    task_id: Uuid,
+    content: String,
+    seconds: i64,
+}
+
+pub async fn time_report(
+    repo: &TimeEntryRepositoryImpl,
+    input: TimeReportQuery,
+) -> Result<Vec<TimeReportRow>, AppError> {
+    // Days are calendar days in the user's timezone, the same as the range
+    // bounds; running timers are not billed until stopped.
+    let rows = sqlx::query_as::<_, TimeReportDbRow>(
+        r#"
+            SELECT
+                (e.started_at AT TIME ZONE $4)::date AS day,
+                e.task_id,
+                t.content,
+                SUM(EXTRACT(EPOCH FROM e.ended_at - e.started_at))::bigint AS seconds
+            FROM time_entries e
+            JOIN tasks t ON t.task_id = e.task_id
+            WHERE e.user_id = $1
+              AND e.ended_at IS NOT NULL
+              AND e.started_at >= $2::date::timestamp AT TIME ZONE $4
+              AND e.started_at < ($3::date + 1)::timestamp AT TIME ZONE $4
+            GROUP BY 1, 2, 3
+            ORDER BY 1, 4 DESC
+            "#,
+    )
+    .bind(&input.user_id)
+    .bind(input.from)
+    .bind(input.to)
+    .bind(&input.timezone)
+    .fetch_all(&repo.pool)
+    .await
+    .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| TimeReportRow {
+            day: row.day,
+            task_id: row.task_id.to_string(),
+            content: row.content,
+            seconds: row.seconds,
+        })
+        .collect())
+}
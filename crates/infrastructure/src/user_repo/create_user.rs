@@ -10,9 +10,9 @@ pub async fn create_user(
 ) -> Result<User, AppError> {
     let row = sqlx::query_as::<_, UserRow>(
         r#"
-            INSERT INTO users (user_id, client_id, username, email, name, picture, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, NOW(), NOW())
-            RETURNING user_id, client_id, username, email, name, picture, created_at, updated_at
+            INSERT INTO users (user_id, client_id, username, email, name, picture, timezone, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, COALESCE($7, 'UTC'), NOW(), NOW())
+            RETURNING user_id, client_id, username, email, name, picture, timezone, created_at, updated_at
             "#,
     )
     .bind(&input.user_id)
@@ -21,6 +21,7 @@ pub async fn create_user(
     .bind(&input.email)
     .bind(&input.name)
     .bind(&input.picture)
+    .bind(&input.timezone)
     .fetch_one(&repo.pool)
     .await
     .map_err(map_db_error)?;
@@ -1,21 +1,27 @@
 use domain::error::AppError;
 use domain::user::inputs::DeleteUserInput;
 
-use super::repository::{map_db_error, UserRepositoryImpl};
+use crate::task_repo::repository::unassign_user_in;
+
+use super::repository::{lock_user, map_db_error, UserRepositoryImpl};
 
 pub async fn delete_user(
     repo: &UserRepositoryImpl,
     input: DeleteUserInput,
 ) -> Result<i64, AppError> {
+    let mut tx = repo.pool.begin().await.map_err(map_db_error)?;
+
+    lock_user(&mut tx, &input.user_id, input.expected_updated_at).await?;
+
+    unassign_user_in(&mut tx, &input.user_id, None, "system").await?;
+
     let result = sqlx::query("DELETE FROM users WHERE user_id = $1")
         .bind(&input.user_id)
-        .execute(&repo.pool)
+        .execute(&mut *tx)
         .await
         .map_err(map_db_error)?;
 
-    if result.rows_affected() == 0 {
-        return Err(AppError::not_found("User", "User not found"));
-    }
+    tx.commit().await.map_err(map_db_error)?;
 
     Ok(result.rows_affected() as i64)
 }
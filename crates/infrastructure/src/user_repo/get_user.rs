@@ -10,7 +10,7 @@ pub async fn get_user(
 ) -> Result<Option<User>, AppError> {
     let row = sqlx::query_as::<_, UserRow>(
         r#"
-            SELECT user_id, client_id, username, email, name, picture, created_at, updated_at
+            SELECT user_id, client_id, username, email, name, picture, timezone, created_at, updated_at
             FROM users
             WHERE user_id = $1
             "#,
@@ -14,7 +14,7 @@ pub async fn list_users(
 
     let rows = sqlx::query_as::<_, UserRow>(
         r#"
-            SELECT user_id, client_id, username, email, name, picture, created_at, updated_at
+            SELECT user_id, client_id, username, email, name, picture, timezone, created_at, updated_at
             FROM users
             WHERE client_id = $1
             ORDER BY created_at DESC
@@ -7,7 +7,7 @@ use domain::user::inputs::{
     CreateUserInput, DeleteUserInput, GetUserByEmailInput, GetUserByUsernameInput, GetUserInput,
     ListUsersInput, UpdateUserInput,
 };
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 
 use super::{
     create_user, delete_user, get_user, get_user_by_email, get_user_by_username, list_users,
@@ -33,6 +33,7 @@ pub(crate) struct UserRow {
     pub email: String,
     pub name: Option<String>,
     pub picture: Option<String>,
+    pub timezone: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -46,6 +47,7 @@ impl UserRow {
             email: self.email,
             name: self.name,
             picture: self.picture,
+            timezone: self.timezone,
             created_at: self.created_at,
             updated_at: self.updated_at,
         }
@@ -63,6 +65,29 @@ pub(crate) fn map_db_error(error: sqlx::Error) -> AppError {
     }
 }
 
+pub(crate) async fn lock_user(
+    conn: &mut PgConnection,
+    user_id: &str,
+    expected_updated_at: Option<DateTime<Utc>>,
+) -> Result<(), AppError> {
+    let updated_at = sqlx::query_scalar::<_, DateTime<Utc>>(
+        "SELECT updated_at FROM users WHERE user_id = $1 FOR UPDATE",
+    )
+    .bind(user_id)
+    .fetch_optional(conn)
+    .await
+    .map_err(map_db_error)?
+    .ok_or_else(|| AppError::not_found("User", "User not found"))?;
+
+    match expected_updated_at {
+        Some(expected) if expected != updated_at => Err(AppError::version_conflict(
+            "User",
+            updated_at.timestamp_micros(),
+        )),
+        _ => Ok(()),
+    }
+}
+
 #[async_trait]
 impl UserRepository for UserRepositoryImpl {
     async fn create_user(&self, input: CreateUserInput) -> Result<User, AppError> {
@@ -3,26 +3,37 @@ use domain::user::entity::User;
 use domain::user::inputs::UpdateUserInput;
 use sqlx::{Postgres, QueryBuilder};
 
-use super::repository::{map_db_error, UserRepositoryImpl, UserRow};
+use super::repository::{lock_user, map_db_error, UserRepositoryImpl, UserRow};
 
 pub async fn update_user(
     repo: &UserRepositoryImpl,
     input: UpdateUserInput,
 ) -> Result<User, AppError> {
+    let mut tx = repo.pool.begin().await.map_err(map_db_error)?;
+
+    lock_user(&mut tx, &input.user_id, input.expected_updated_at).await?;
+
     let mut builder: QueryBuilder<Postgres> = QueryBuilder::new("UPDATE users SET ");
     let mut separated = builder.separated(", ");
 
     if let Some(username) = &input.username {
-        separated.push("username = ").push_bind(username);
+        separated
+            .push("username = ")
+            .push_bind_unseparated(username);
     }
     if let Some(email) = &input.email {
-        separated.push("email = ").push_bind(email);
+        separated.push("email = ").push_bind_unseparated(email);
     }
     if let Some(name) = &input.name {
-        separated.push("name = ").push_bind(name);
+        separated.push("name = ").push_bind_unseparated(name);
     }
     if let Some(picture) = &input.picture {
-        separated.push("picture = ").push_bind(picture);
+        separated.push("picture = ").push_bind_unseparated(picture);
+    }
+    if let Some(timezone) = &input.timezone {
+        separated
+            .push("timezone = ")
+            .push_bind_unseparated(timezone);
     }
 
     separated.push("updated_at = NOW()");
@@ -30,15 +41,17 @@ pub async fn update_user(
     builder.push(" WHERE user_id = ");
     builder.push_bind(&input.user_id);
     builder.push(
-        " RETURNING user_id, client_id, username, email, name, picture, created_at, updated_at",
+        " RETURNING user_id, client_id, username, email, name, picture, timezone, created_at, \
+         updated_at",
     );
 
     let row = builder
         .build_query_as::<UserRow>()
-        .fetch_optional(&repo.pool)
+        .fetch_one(&mut *tx)
         .await
         .map_err(map_db_error)?;
 
-    row.map(|row| row.into_user())
-        .ok_or_else(|| AppError::not_found("User", "User not found"))
+    tx.commit().await.map_err(map_db_error)?;
+
+    Ok(row.into_user())
 }
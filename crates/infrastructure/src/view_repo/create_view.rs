@@ -0,0 +1,25 @@
+use domain::error::AppError;
+use domain::view::entity::SavedView;
+use domain::view::inputs::CreateViewInput;
+use sqlx::types::Json;
+
+use super::repository::{ViewRepositoryImpl, ViewRow, VIEW_COLUMNS};
+
+pub async fn create_view(
+    repo: &ViewRepositoryImpl,
+    input: CreateViewInput,
+) -> Result<SavedView, AppError> {
+    let sql = format!(
+        "INSERT INTO saved_views (user_id, name, filter) VALUES ($1, $2, $3) \
+         RETURNING {VIEW_COLUMNS}"
+    );
+    let row = sqlx::query_as::<_, ViewRow>(&sql)
+        .bind(&input.user_id)
+        .bind(input.name.trim())
+        .bind(Json(&input.filter))
+        .fetch_one(&repo.pool)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(row.into_view())
+}
@@ -0,0 +1,23 @@
+use domain::error::AppError;
+use domain::view::inputs::DeleteViewInput;
+
+use super::repository::{parse_view_id, ViewRepositoryImpl};
+
+pub async fn delete_view(
+    repo: &ViewRepositoryImpl,
+    input: DeleteViewInput,
+) -> Result<(), AppError> {
+    let view_id = parse_view_id(&input.view_id)?;
+
+    let deleted = sqlx::query("DELETE FROM saved_views WHERE user_id = $1 AND view_id = $2")
+        .bind(&input.user_id)
+        .bind(view_id)
+        .execute(&repo.pool)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+    if deleted.rows_affected() == 0 {
+        return Err(AppError::not_found("View", "View not found"));
+    }
+
+    Ok(())
+}
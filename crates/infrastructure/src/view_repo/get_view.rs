@@ -0,0 +1,22 @@
+use domain::error::AppError;
+use domain::view::entity::SavedView;
+use domain::view::inputs::GetViewInput;
+
+use super::repository::{parse_view_id, ViewRepositoryImpl, ViewRow, VIEW_COLUMNS};
+
+pub async fn get_view(
+    repo: &ViewRepositoryImpl,
+    input: GetViewInput,
+) -> Result<Option<SavedView>, AppError> {
+    let view_id = parse_view_id(&input.view_id)?;
+
+    let sql = format!("SELECT {VIEW_COLUMNS} FROM saved_views WHERE user_id = $1 AND view_id = $2");
+    let row = sqlx::query_as::<_, ViewRow>(&sql)
+        .bind(&input.user_id)
+        .bind(view_id)
+        .fetch_optional(&repo.pool)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(row.map(ViewRow::into_view))
+}
@@ -0,0 +1,21 @@
+use domain::error::AppError;
+use domain::view::entity::SavedView;
+use domain::view::inputs::ListViewsInput;
+
+use super::repository::{ViewRepositoryImpl, ViewRow, VIEW_COLUMNS};
+
+pub async fn list_views(
+    repo: &ViewRepositoryImpl,
+    input: ListViewsInput,
+) -> Result<Vec<SavedView>, AppError> {
+    let sql = format!(
+        "SELECT {VIEW_COLUMNS} FROM saved_views WHERE user_id = $1 ORDER BY name, created_at"
+    );
+    let rows = sqlx::query_as::<_, ViewRow>(&sql)
+        .bind(&input.user_id)
+        .fetch_all(&repo.pool)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?;
+
+    Ok(rows.into_iter().map(ViewRow::into_view).collect())
+}
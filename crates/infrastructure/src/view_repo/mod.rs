@@ -0,0 +1,6 @@
+pub mod create_view;
+pub mod delete_view;
+pub mod get_view;
+pub mod list_views;
+pub mod repository;
+pub mod update_view;
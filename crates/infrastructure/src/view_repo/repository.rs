@@ -0,0 +1,78 @@
+use application::view_service::repository::ViewRepository;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::error::AppError;
+use domain::task::filter::TaskFilter;
+use domain::view::entity::SavedView;
+use domain::view::inputs::{
+    CreateViewInput, DeleteViewInput, GetViewInput, ListViewsInput, UpdateViewInput,
+};
+use sqlx::types::Json;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::{create_view, delete_view, get_view, list_views, update_view};
+
+pub(crate) const VIEW_COLUMNS: &str = "view_id, user_id, name, filter, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct ViewRepositoryImpl {
+    pub(crate) pool: PgPool,
+}
+
+impl ViewRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+pub(crate) struct ViewRow {
+    pub view_id: Uuid,
+    pub user_id: String,
+    pub name: String,
+    pub filter: Json<TaskFilter>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ViewRow {
+    pub(crate) fn into_view(self) -> SavedView {
+        SavedView {
+            view_id: self.view_id.to_string(),
+            user_id: self.user_id,
+            name: self.name,
+            filter: self.filter.0,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+pub(crate) fn parse_view_id(view_id: &str) -> Result<Uuid, AppError> {
+    Uuid::parse_str(view_id)
+        .map_err(|_| AppError::validation("invalid_view_id", "Invalid view id", None))
+}
+
+#[async_trait]
+impl ViewRepository for ViewRepositoryImpl {
+    async fn create_view(&self, input: CreateViewInput) -> Result<SavedView, AppError> {
+        create_view::create_view(self, input).await
+    }
+
+    async fn update_view(&self, input: UpdateViewInput) -> Result<SavedView, AppError> {
+        update_view::update_view(self, input).await
+    }
+
+    async fn delete_view(&self, input: DeleteViewInput) -> Result<(), AppError> {
+        delete_view::delete_view(self, input).await
+    }
+
+    async fn get_view(&self, input: GetViewInput) -> Result<Option<SavedView>, AppError> {
+        get_view::get_view(self, input).await
+    }
+
+    async fn list_views(&self, input: ListViewsInput) -> Result<Vec<SavedView>, AppError> {
+        list_views::list_views(self, input).await
+    }
+}
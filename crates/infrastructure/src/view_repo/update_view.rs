@@ -0,0 +1,33 @@
+use domain::error::AppError;
+use domain::view::entity::SavedView;
+use domain::view::inputs::UpdateViewInput;
+use sqlx::types::Json;
+
+use super::repository::{parse_view_id, ViewRepositoryImpl, ViewRow, VIEW_COLUMNS};
+
+pub async fn update_view(
+    repo: &ViewRepositoryImpl,
+    input: UpdateViewInput,
+) -> Result<SavedView, AppError> {
+    let view_id = parse_view_id(&input.view_id)?;
+
+    let sql = format!(
+        r#"
+            UPDATE saved_views
+            SET name = $3, filter = $4, updated_at = NOW()
+            WHERE user_id = $1 AND view_id = $2
+            RETURNING {VIEW_COLUMNS}
+            "#
+    );
+    let row = sqlx::query_as::<_, ViewRow>(&sql)
+        .bind(&input.user_id)
+        .bind(view_id)
+        .bind(input.name.trim())
+        .bind(Json(&input.filter))
+        .fetch_optional(&repo.pool)
+        .await
+        .map_err(|error| AppError::database(error.to_string()))?
+        .ok_or_else(|| AppError::not_found("View", "View not found"))?;
+
+    Ok(row.into_view())
+}
@@ -0,0 +1,232 @@
+//! Runs against a migrated database when `DATABASE_URL` is set; skipped otherwise.
+
+use application::task_service::repository::TaskRepository;
+use application::task_service::revert_task::revert_task;
+use application::time_entry_service::repository::TimeEntryRepository;
+use application::user_service::repository::UserRepository;
+use chrono::{Duration, Utc};
+use domain::task::entity::Task;
+use domain::task::inputs::{
+    AddTaskMemberInput, CreateTaskInput, GetTaskInput, MoveTaskInput, RevertTaskInput,
+    SetMemberCompletionInput, UpdateTaskInput,
+};
+use domain::task::status::TaskStatus;
+use domain::time_entry::inputs::{CreateTimeEntryInput, DeleteTimeEntryInput};
+use domain::user::inputs::CreateUserInput;
+use infrastructure::task_repo::repository::TaskRepositoryImpl;
+use infrastructure::time_entry_repo::repository::TimeEntryRepositoryImpl;
+use infrastructure::user_repo::repository::UserRepositoryImpl;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+async fn pool() -> Option<PgPool> {
+    let url = std::env::var("DATABASE_URL").ok()?;
+    Some(
+        PgPool::connect(&url)
+            .await
+            .expect("connect to DATABASE_URL"),
+    )
+}
+
+async fn create_user(pool: &PgPool) -> String {
+    let user_id = Uuid::new_v4().to_string();
+    UserRepositoryImpl::new(pool.clone())
+        .create_user(CreateUserInput {
+            user_id: user_id.clone(),
+            client_id: "test".to_string(),
+            username: user_id.clone(),
+            email: format!("{user_id}@example.com"),
+            name: None,
+            picture: None,
+            timezone: None,
+        })
+        .await
+        .unwrap();
+    user_id
+}
+
+async fn reload(tasks: &TaskRepositoryImpl, user_id: &str, task: &Task) -> Task {
+    tasks
+        .get_task(GetTaskInput {
+            user_id: user_id.to_string(),
+            task_id: task.task_id.clone(),
+        })
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn tracked_time_changes_bump_the_task_version() {
+    let Some(pool) = pool().await else {
+        return;
+    };
+    let user_id = create_user(&pool).await;
+    let tasks = TaskRepositoryImpl::new(pool.clone());
+    let entries = TimeEntryRepositoryImpl::new(pool.clone());
+    let task = tasks
+        .create_task(CreateTaskInput {
+            user_id: user_id.clone(),
+            content: "tracked".to_string(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let ended_at = Utc::now();
+    let entry = entries
+        .create_time_entry(CreateTimeEntryInput {
+            user_id: user_id.clone(),
+            task_id: task.task_id.clone(),
+            started_at: ended_at - Duration::minutes(30),
+            ended_at,
+            note: None,
+        })
+        .await
+        .unwrap();
+    let tracked = reload(&tasks, &user_id, &task).await;
+    assert_eq!(tracked.tracked_seconds, 1800);
+    assert_eq!(tracked.version, task.version + 1);
+
+    entries
+        .delete_time_entry(DeleteTimeEntryInput {
+            user_id: user_id.clone(),
+            entry_id: entry.entry_id.clone(),
+        })
+        .await
+        .unwrap();
+    let untracked = reload(&tasks, &user_id, &task).await;
+    assert_eq!(untracked.tracked_seconds, 0);
+    assert_eq!(untracked.version, tracked.version + 1);
+}
+
+#[tokio::test]
+async fn member_completion_bumps_the_group_version() {
+    let Some(pool) = pool().await else {
+        return;
+    };
+    let owner = create_user(&pool).await;
+    let member = create_user(&pool).await;
+    let tasks = TaskRepositoryImpl::new(pool.clone());
+    let task = tasks
+        .create_task(CreateTaskInput {
+            user_id: owner.clone(),
+            content: "group".to_string(),
+            group: true,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    tasks
+        .add_task_member(AddTaskMemberInput {
+            user_id: owner.clone(),
+            task_id: task.task_id.clone(),
+            member_id: member.clone(),
+        })
+        .await
+        .unwrap();
+    let before = reload(&tasks, &owner, &task).await;
+
+    let completion = SetMemberCompletionInput {
+        user_id: owner.clone(),
+        task_id: task.task_id.clone(),
+        completed: true,
+    };
+    tasks
+        .set_member_completion(completion.clone())
+        .await
+        .unwrap();
+    let after = reload(&tasks, &owner, &task).await;
+    assert_eq!(after.group.as_ref().unwrap().completed, 1);
+    assert_eq!(after.status, before.status);
+    assert_eq!(after.version, before.version + 1);
+
+    tasks.set_member_completion(completion).await.unwrap();
+    let repeated = reload(&tasks, &owner, &task).await;
+    assert_eq!(repeated.version, after.version);
+}
+
+#[tokio::test]
+async fn reverting_a_completed_task_to_in_progress_reopens_it() {
+    let Some(pool) = pool().await else {
+        return;
+    };
+    let user_id = create_user(&pool).await;
+    let tasks = TaskRepositoryImpl::new(pool.clone());
+    let task = tasks
+        .create_task(CreateTaskInput {
+            user_id: user_id.clone(),
+            content: "revert".to_string(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let started = tasks
+        .update_task(UpdateTaskInput {
+            user_id: user_id.clone(),
+            task_id: task.task_id.clone(),
+            status: Some(TaskStatus::InProgress),
+            version: task.version,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let completed = tasks
+        .update_task(UpdateTaskInput {
+            user_id: user_id.clone(),
+            task_id: task.task_id.clone(),
+            status: Some(TaskStatus::Completed),
+            version: started.version,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let reverted = revert_task(
+        &tasks,
+        RevertTaskInput {
+            user_id: user_id.clone(),
+            task_id: task.task_id.clone(),
+            target_version: started.version,
+            version: completed.version,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(reverted.task.status, TaskStatus::InProgress);
+    assert_eq!(reverted.task.completed_at, None);
+}
+
+#[tokio::test]
+async fn moving_a_task_bumps_its_version() {
+    let Some(pool) = pool().await else {
+        return;
+    };
+    let user_id = create_user(&pool).await;
+    let tasks = TaskRepositoryImpl::new(pool.clone());
+    let mut created = Vec::new();
+    for content in ["first", "second"] {
+        let task = tasks
+            .create_task(CreateTaskInput {
+                user_id: user_id.clone(),
+                content: content.to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        created.push(task);
+    }
+    let task = reload(&tasks, &user_id, &created[0]).await;
+
+    let moved = tasks
+        .move_task(MoveTaskInput {
+            user_id: user_id.clone(),
+            task_id: task.task_id.clone(),
+            before: None,
+            after: Some(created[1].task_id.clone()),
+        })
+        .await
+        .unwrap();
+    assert_ne!(moved.rank, task.rank);
+    assert_eq!(moved.version, task.version + 1);
+}
@@ -0,0 +1,23 @@
+CREATE TABLE IF NOT EXISTS task_revisions (
+    revision_id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    task_id UUID NOT NULL,
+    version INTEGER NOT NULL,
+    changed_by TEXT NOT NULL,
+    changes JSONB NOT NULL DEFAULT '[]'::jsonb,
+    snapshot JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT task_revisions_task_fk FOREIGN KEY (task_id) REFERENCES tasks(task_id) ON DELETE CASCADE,
+    CONSTRAINT task_revisions_task_version_unique UNIQUE (task_id, version)
+);
+
+-- Seed a baseline revision for existing tasks so their current state can be restored.
+INSERT INTO task_revisions (task_id, version, changed_by, changes, snapshot, created_at)
+SELECT
+    t.task_id,
+    t.version,
+    'system',
+    '[]'::jsonb,
+    jsonb_build_object('content', t.content, 'completed_at', t.completed_at),
+    t.updated_at
+FROM tasks t
+ON CONFLICT DO NOTHING;
@@ -0,0 +1,19 @@
+ALTER TABLE tasks_users ADD COLUMN IF NOT EXISTS rank TEXT COLLATE "C";
+
+-- Seed fixed-width ranks in creation order; the trailing 'i' keeps ranks free of trailing zeros.
+UPDATE tasks_users tu
+SET rank = ranked.rank
+FROM (
+    SELECT
+        tu.task_id,
+        tu.user_id,
+        lpad(to_hex(row_number() OVER (PARTITION BY tu.user_id ORDER BY t.created_at, t.task_id)), 8, '0') || 'i' AS rank
+    FROM tasks_users tu
+    JOIN tasks t ON t.task_id = tu.task_id
+) AS ranked
+WHERE tu.task_id = ranked.task_id AND tu.user_id = ranked.user_id AND tu.rank IS NULL;
+
+ALTER TABLE tasks_users ALTER COLUMN rank SET NOT NULL;
+
+ALTER TABLE tasks_users
+    ADD CONSTRAINT tasks_users_user_rank_unique UNIQUE (user_id, rank) DEFERRABLE INITIALLY DEFERRED;
@@ -0,0 +1,40 @@
+-- Status was never written by the API; derive it from completed_at before enforcing it.
+UPDATE tasks SET status = 'COMPLETED' WHERE completed_at IS NOT NULL AND status <> 'COMPLETED';
+UPDATE tasks SET completed_at = updated_at WHERE status = 'COMPLETED' AND completed_at IS NULL;
+
+ALTER TABLE tasks
+    ADD CONSTRAINT tasks_status_check CHECK (status IN ('PENDING', 'IN_PROGRESS', 'COMPLETED'));
+
+UPDATE task_revisions
+SET snapshot = snapshot || jsonb_build_object(
+    'status',
+    CASE WHEN snapshot->>'completed_at' IS NOT NULL THEN 'COMPLETED' ELSE 'PENDING' END
+)
+WHERE NOT snapshot ? 'status';
+
+CREATE TABLE IF NOT EXISTS boards (
+    board_id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT boards_user_fk FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS boards_user_id_idx ON boards (user_id);
+
+CREATE TABLE IF NOT EXISTS board_columns (
+    column_id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    board_id UUID NOT NULL,
+    name TEXT NOT NULL,
+    status TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    wip_limit INTEGER,
+    CONSTRAINT board_columns_board_fk FOREIGN KEY (board_id) REFERENCES boards(board_id) ON DELETE CASCADE,
+    CONSTRAINT board_columns_position_unique UNIQUE (board_id, position) DEFERRABLE INITIALLY DEFERRED,
+    CONSTRAINT board_columns_status_unique UNIQUE (board_id, status),
+    CONSTRAINT board_columns_status_check CHECK (status IN ('PENDING', 'IN_PROGRESS', 'COMPLETED')),
+    CONSTRAINT board_columns_wip_limit_check CHECK (wip_limit IS NULL OR wip_limit > 0)
+);
+
+CREATE INDEX IF NOT EXISTS tasks_status_idx ON tasks (status);
@@ -0,0 +1,21 @@
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS tracked_seconds BIGINT NOT NULL DEFAULT 0;
+
+CREATE TABLE IF NOT EXISTS time_entries (
+    entry_id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    task_id UUID NOT NULL,
+    user_id TEXT NOT NULL,
+    started_at TIMESTAMPTZ NOT NULL,
+    ended_at TIMESTAMPTZ,
+    note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT time_entries_task_fk FOREIGN KEY (task_id) REFERENCES tasks(task_id) ON DELETE CASCADE,
+    CONSTRAINT time_entries_user_fk FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE CASCADE,
+    CONSTRAINT time_entries_range_check CHECK (ended_at IS NULL OR ended_at >= started_at)
+);
+
+-- At most one running timer per user.
+CREATE UNIQUE INDEX IF NOT EXISTS time_entries_running_unique
+    ON time_entries (user_id) WHERE ended_at IS NULL;
+
+CREATE INDEX IF NOT EXISTS time_entries_user_started_idx ON time_entries (user_id, started_at);
+CREATE INDEX IF NOT EXISTS time_entries_task_idx ON time_entries (task_id);
@@ -0,0 +1,13 @@
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS parent_task_id UUID;
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS estimate_minutes INTEGER;
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS remaining_minutes INTEGER;
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS story_points INTEGER;
+
+ALTER TABLE tasks
+    ADD CONSTRAINT tasks_parent_fk FOREIGN KEY (parent_task_id) REFERENCES tasks(task_id) ON DELETE SET NULL,
+    ADD CONSTRAINT tasks_parent_not_self CHECK (parent_task_id IS NULL OR parent_task_id <> task_id),
+    ADD CONSTRAINT tasks_estimate_minutes_check CHECK (estimate_minutes IS NULL OR estimate_minutes >= 0),
+    ADD CONSTRAINT tasks_remaining_minutes_check CHECK (remaining_minutes IS NULL OR remaining_minutes >= 0),
+    ADD CONSTRAINT tasks_story_points_check CHECK (story_points IS NULL OR story_points >= 0);
+
+CREATE INDEX IF NOT EXISTS tasks_parent_task_id_idx ON tasks (parent_task_id);
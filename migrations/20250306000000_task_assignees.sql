@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS task_assignees (
+    task_id UUID NOT NULL,
+    user_id TEXT NOT NULL,
+    assigned_by TEXT,
+    assigned_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (task_id, user_id),
+    FOREIGN KEY (task_id, user_id) REFERENCES tasks_users(task_id, user_id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS task_assignees_user_id_idx ON task_assignees (user_id);
@@ -0,0 +1,7 @@
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS priority TEXT;
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS recurrence TEXT;
+
+ALTER TABLE tasks
+    ADD CONSTRAINT tasks_priority_check CHECK (priority IS NULL OR priority IN ('LOW', 'MEDIUM', 'HIGH', 'URGENT'));
+
+ALTER TABLE users ADD COLUMN IF NOT EXISTS timezone TEXT NOT NULL DEFAULT 'UTC';
@@ -0,0 +1,5 @@
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS snoozed_until TIMESTAMPTZ;
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS waiting_on TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_tasks_snoozed_until ON tasks (snoozed_until)
+    WHERE snoozed_until IS NOT NULL;
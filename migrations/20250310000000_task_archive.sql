@@ -0,0 +1,7 @@
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS archived_at TIMESTAMPTZ;
+
+-- Keep the hot path small: most list queries only touch unarchived rows.
+CREATE INDEX IF NOT EXISTS idx_tasks_active_created_at ON tasks (created_at DESC)
+    WHERE archived_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_tasks_archivable ON tasks (completed_at)
+    WHERE archived_at IS NULL AND status = 'COMPLETED';
@@ -0,0 +1,5 @@
+-- tasks_users is keyed on (task_id, user_id), so per-user scans need their own index.
+CREATE INDEX IF NOT EXISTS tasks_users_user_id_idx ON tasks_users (user_id);
+CREATE INDEX IF NOT EXISTS tasks_created_at_idx ON tasks (created_at);
+CREATE INDEX IF NOT EXISTS tasks_completed_at_idx ON tasks (completed_at)
+    WHERE completed_at IS NOT NULL;
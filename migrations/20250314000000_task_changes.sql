@@ -0,0 +1,86 @@
+-- One row per (user, task) that the user can or could see, holding the
+-- latest change. seq is NULL until a sync request numbers the row: numbering
+-- happens under a per-user lock and only sees committed rows, so a client that
+-- has read up to seq N can never later miss a change numbered below N.
+CREATE SEQUENCE IF NOT EXISTS task_change_seq;
+
+CREATE TABLE IF NOT EXISTS task_changes (
+    user_id TEXT NOT NULL,
+    task_id UUID NOT NULL,
+    deleted BOOLEAN NOT NULL DEFAULT FALSE,
+    seq BIGINT,
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, task_id),
+    CONSTRAINT task_changes_user_fk FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS task_changes_user_seq_idx ON task_changes (user_id, seq);
+CREATE INDEX IF NOT EXISTS task_changes_unnumbered_idx ON task_changes (user_id)
+    WHERE seq IS NULL;
+
+CREATE OR REPLACE FUNCTION record_task_change(p_user_id TEXT, p_task_id UUID, p_deleted BOOLEAN)
+RETURNS VOID AS $$
+BEGIN
+    -- Skip users that are being deleted in the same statement.
+    INSERT INTO task_changes (user_id, task_id, deleted, seq, changed_at)
+    SELECT p_user_id, p_task_id, p_deleted, NULL, NOW()
+    WHERE EXISTS (SELECT 1 FROM users WHERE user_id = p_user_id)
+    ON CONFLICT (user_id, task_id) DO UPDATE
+        SET deleted = EXCLUDED.deleted, seq = NULL, changed_at = EXCLUDED.changed_at;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION tasks_record_change() RETURNS TRIGGER AS $$
+BEGIN
+    PERFORM record_task_change(tu.user_id, NEW.task_id, FALSE)
+    FROM tasks_users tu
+    WHERE tu.task_id = NEW.task_id;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Deleting a task cascades to tasks_users, so deletions and lost access both
+-- arrive here as tombstones.
+CREATE OR REPLACE FUNCTION tasks_users_record_change() RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        PERFORM record_task_change(OLD.user_id, OLD.task_id, TRUE);
+    ELSE
+        PERFORM record_task_change(NEW.user_id, NEW.task_id, FALSE);
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION task_assignees_record_change() RETURNS TRIGGER AS $$
+BEGIN
+    PERFORM record_task_change(tu.user_id, tu.task_id, FALSE)
+    FROM tasks_users tu
+    WHERE tu.task_id = COALESCE(NEW.task_id, OLD.task_id);
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS tasks_record_change ON tasks;
+CREATE TRIGGER tasks_record_change
+    AFTER UPDATE ON tasks
+    FOR EACH ROW
+    WHEN (OLD.* IS DISTINCT FROM NEW.*)
+    EXECUTE FUNCTION tasks_record_change();
+
+DROP TRIGGER IF EXISTS tasks_users_record_change ON tasks_users;
+CREATE TRIGGER tasks_users_record_change
+    AFTER INSERT OR DELETE OR UPDATE OF rank ON tasks_users
+    FOR EACH ROW
+    EXECUTE FUNCTION tasks_users_record_change();
+
+DROP TRIGGER IF EXISTS task_assignees_record_change ON task_assignees;
+CREATE TRIGGER task_assignees_record_change
+    AFTER INSERT OR DELETE ON task_assignees
+    FOR EACH ROW
+    EXECUTE FUNCTION task_assignees_record_change();
+
+INSERT INTO task_changes (user_id, task_id)
+SELECT user_id, task_id
+FROM tasks_users
+ON CONFLICT DO NOTHING;
@@ -0,0 +1,28 @@
+CREATE TABLE IF NOT EXISTS share_links (
+    share_id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    task_id UUID NOT NULL,
+    created_by TEXT NOT NULL,
+    token_hash TEXT NOT NULL,
+    -- bcrypt via pgcrypto's crypt(); NULL when the link has no password.
+    password_hash TEXT,
+    expires_at TIMESTAMPTZ NOT NULL,
+    revoked_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT share_links_token_hash_unique UNIQUE (token_hash),
+    CONSTRAINT share_links_task_fk FOREIGN KEY (task_id) REFERENCES tasks(task_id) ON DELETE CASCADE,
+    CONSTRAINT share_links_user_fk FOREIGN KEY (created_by) REFERENCES users(user_id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS share_links_task_id_idx ON share_links (task_id);
+
+CREATE TABLE IF NOT EXISTS share_link_access (
+    access_id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    share_id UUID NOT NULL,
+    accessed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    granted BOOLEAN NOT NULL,
+    ip TEXT,
+    user_agent TEXT,
+    CONSTRAINT share_link_access_share_fk FOREIGN KEY (share_id) REFERENCES share_links(share_id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS share_link_access_share_idx ON share_link_access (share_id, accessed_at DESC);
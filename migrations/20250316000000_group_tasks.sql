@@ -0,0 +1,22 @@
+-- In a group task every member completes the task individually; the task
+-- itself completes once all of them have.
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS is_group BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE tasks_users ADD COLUMN IF NOT EXISTS completed_at TIMESTAMPTZ;
+
+-- Every member sees the group's progress, so one member finishing, joining or
+-- leaving changes the task for all of them.
+CREATE OR REPLACE FUNCTION tasks_users_group_record_change() RETURNS TRIGGER AS $$
+BEGIN
+    PERFORM record_task_change(tu.user_id, tu.task_id, FALSE)
+    FROM tasks_users tu
+    JOIN tasks t ON t.task_id = tu.task_id
+    WHERE tu.task_id = COALESCE(NEW.task_id, OLD.task_id) AND t.is_group;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS tasks_users_group_record_change ON tasks_users;
+CREATE TRIGGER tasks_users_group_record_change
+    AFTER INSERT OR DELETE OR UPDATE OF completed_at ON tasks_users
+    FOR EACH ROW
+    EXECUTE FUNCTION tasks_users_group_record_change();
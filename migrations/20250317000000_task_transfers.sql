@@ -0,0 +1,13 @@
+-- Audit trail of task ownership transfers. User ids are kept as plain text so
+-- the record outlives the users it mentions.
+CREATE TABLE IF NOT EXISTS task_transfers (
+    transfer_id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    actor_id TEXT NOT NULL,
+    from_user_id TEXT NOT NULL,
+    to_user_id TEXT NOT NULL,
+    task_ids UUID[] NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS task_transfers_from_user_idx ON task_transfers (from_user_id, created_at);
+CREATE INDEX IF NOT EXISTS task_transfers_to_user_idx ON task_transfers (to_user_id, created_at);
@@ -0,0 +1,11 @@
+-- Mutations a client has pushed and the server has applied, so a retried push
+-- gets the original result back instead of applying the mutation twice.
+-- `task` is the task as the mutation left it; NULL for deletes.
+CREATE TABLE IF NOT EXISTS sync_mutations (
+    user_id TEXT NOT NULL,
+    mutation_id TEXT NOT NULL,
+    task JSONB,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, mutation_id),
+    CONSTRAINT sync_mutations_user_fk FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE CASCADE
+);